@@ -0,0 +1,60 @@
+//! Listing of a group whose members are read from the in-memory keyvalue provider, each message
+//! answered after a delay as the provider would across the lattice. Compares reading the members
+//! one after the other with reading them concurrently : `cargo bench --bench list_customers`
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use customers::CustomersActor;
+use futures::executor::block_on;
+use shared_bucket::lattice::{KEYVALUE, NUMBERGEN};
+use shared_bucket::policy::OPERATOR_ROLE;
+use shared_bucket::{CreateCustomerGroupRequest, CreateCustomerRequest, Customer, CustomerGroup, CustomerGroups, Customers, ListCustomersRequest, Principal};
+use test_lattice::providers::{MemoryKeyValue, SequentialNumbers};
+use test_lattice::TestLattice;
+use wasmbus_rpc::actor::prelude::*;
+
+const MEMBERS: usize = 100;
+const READ_LATENCY: Duration = Duration::from_millis(1);
+
+fn operator() -> Option<Principal> {
+    Some(Principal { id: "ops".to_string(), kind: "user".to_string(), roles: Some(vec![OPERATOR_ROLE.to_string()]), tenant: None })
+}
+
+/// Actor holding a group of `MEMBERS` customers, and the id of the group
+fn actor(concurrency: usize) -> (CustomersActor, String) {
+    let lattice = TestLattice::default();
+    lattice.link_with_latency(KEYVALUE, MemoryKeyValue::default(), READ_LATENCY);
+    lattice.link(NUMBERGEN, SequentialNumbers::default());
+    let actor = CustomersActor::new(lattice).with_list_concurrency(concurrency);
+    let ctx = Context::default();
+
+    let customers = (0..MEMBERS).map(|i| {
+        let customer = Customer { first_name: format!("customer{}", i), email: format!("customer{}@example.com", i), ..Default::default() };
+        let request = CreateCustomerRequest { customer, principal: operator(), ..Default::default() };
+        block_on(actor.create_customer(&ctx, &request)).unwrap().id
+    }).collect();
+    let group = CustomerGroup { name: "family".to_string(), customers: Some(customers), ..Default::default() };
+    let request = CreateCustomerGroupRequest { group, principal: operator() };
+    let id = block_on(actor.create_customer_group(&ctx, &request)).unwrap().id.unwrap();
+
+    (actor, id)
+}
+
+fn list_customers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_customers");
+    group.sample_size(10);
+
+    for concurrency in [1, 4, 16] {
+        let (actor, id) = actor(concurrency);
+        let request = ListCustomersRequest { group: id, principal: operator(), ..Default::default() };
+        group.bench_with_input(BenchmarkId::new("concurrency", concurrency), &request, |b, request| {
+            b.iter(|| assert_eq!(block_on(actor.list_customers(&Context::default(), request)).unwrap().len(), MEMBERS))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, list_customers);
+criterion_main!(benches);
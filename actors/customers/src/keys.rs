@@ -0,0 +1,112 @@
+//! Storage keys of a tenant.
+//!
+//! Operators (MVNOs) sharing a deployment are tenants : their keys are prefixed with
+//! `tenant:{tenant}:`, so they may use the same group names. Principals without a tenant work
+//! with the platform wide data, under the unprefixed keys.
+
+use anyhow::bail;
+use shared_bucket::Principal;
+
+#[derive(Debug, Default)]
+pub struct Keys {
+    tenant: Option<String>,
+    prefix: String,
+}
+
+impl Keys {
+    /// Keys of the tenant of a principal. The tenant may not contain the `:` separator, a tenant
+    /// `a:customer` would read the customers of `a`.
+    pub fn of(principal: &Option<Principal>) -> anyhow::Result<Self> {
+        match principal.as_ref().and_then(|p| p.tenant.as_deref()) {
+            None => Ok(Keys::default()),
+            Some(tenant) if tenant.is_empty() || tenant.contains(':') => bail!("Invalid tenant '{}'", tenant),
+            Some(tenant) => Ok(Keys { tenant: Some(tenant.to_string()), prefix: format!("tenant:{}:", tenant) }),
+        }
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    pub fn customer(&self, id: &str) -> String {
+        format!("{}customer:{}", self.prefix, id)
+    }
+
+    /// Id of the first customer created with an email, emails differing by their case or
+    /// surrounding spaces being the same
+    pub fn customer_email(&self, email: &str) -> String {
+        format!("{}customer_email:{}", self.prefix, email.trim().to_lowercase())
+    }
+
+    pub fn group(&self, id: &str) -> String {
+        format!("{}customer_group:{}", self.prefix, id)
+    }
+
+    /// Id of the group holding a name, names differing by their case or surrounding spaces being
+    /// the same
+    pub fn group_name(&self, name: &str) -> String {
+        format!("{}customer_group_name:{}", self.prefix, name.trim().to_lowercase())
+    }
+
+    /// Set of the member ids of a group
+    pub fn members(&self, id: &str) -> String {
+        format!("{}customer_group_members:{}", self.prefix, id)
+    }
+
+    /// Present when the customer is a member of the group. Customer ids may not contain the `:`
+    /// separator, which would make the key of a customer of one group the key of another.
+    pub fn member(&self, id: &str, customer: &str) -> String {
+        format!("{}customer_group_member:{}:{}", self.prefix, id, customer)
+    }
+
+    /// Reply saved for the idempotency key a caller sent with an operation, so that callers
+    /// sending the same key do not get each other's replies
+    pub fn idempotency(&self, caller: &str, operation: &str, key: &str) -> String {
+        format!("{}idempotency:{}:{}:{}", self.prefix, caller, operation, key)
+    }
+
+    /// Set of the customer ids, walked by the bulk migration
+    pub fn customers_index(&self) -> String {
+        format!("{}index:customers", self.prefix)
+    }
+
+    /// Set of the group ids, walked by the bulk migration
+    pub fn groups_index(&self) -> String {
+        format!("{}index:customer_groups", self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn principal(tenant: Option<&str>) -> Option<Principal> {
+        Some(Principal { id: "ops".to_string(), kind: "user".to_string(), roles: None, tenant: tenant.map(String::from) })
+    }
+
+    #[test]
+    fn platform_keys_are_not_prefixed() {
+        let keys = Keys::of(&principal(None)).unwrap();
+        assert_eq!(keys.customer("42"), "customer:42");
+        assert_eq!(keys.groups_index(), "index:customer_groups");
+        assert_eq!(Keys::of(&None).unwrap().group("family"), "customer_group:family");
+    }
+
+    #[test]
+    fn tenant_keys_are_prefixed() {
+        let keys = Keys::of(&principal(Some("acme"))).unwrap();
+        assert_eq!(keys.tenant(), Some("acme"));
+        assert_eq!(keys.group("family"), "tenant:acme:customer_group:family");
+        assert_eq!(keys.idempotency("ops", "create_customer", "k"), "tenant:acme:idempotency:ops:create_customer:k");
+        assert_eq!(keys.customers_index(), "tenant:acme:index:customers");
+        assert_eq!(keys.group_name(" Family "), "tenant:acme:customer_group_name:family");
+        assert_eq!(keys.member("family", "42"), "tenant:acme:customer_group_member:family:42");
+        assert_eq!(keys.customer_email("Alice@Example.com "), "tenant:acme:customer_email:alice@example.com");
+    }
+
+    #[test]
+    fn tenants_are_validated() {
+        assert!(Keys::of(&principal(Some("acme:customer"))).is_err());
+        assert!(Keys::of(&principal(Some(""))).is_err());
+    }
+}
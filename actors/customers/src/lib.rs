@@ -1,87 +1,614 @@
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
 use anyhow::bail;
-use shared_bucket::{AddCustomerReply, AddCustomerRequest, CreateCustomerGroupReply, CreateCustomerReply, Customer, CustomerGroup, CustomerGroups, CustomerGroupsReceiver, Customers, CustomersReceiver, FindCustomerReply, HealthzReply, HealthzRequest, ListCustomersReply};
+use futures::future;
+use futures::stream::{self, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use shared_bucket::{AddCustomerReply, AddCustomerRequest, CreateCustomerGroupReply, CreateCustomerGroupRequest, CreateCustomerReply, CreateCustomerRequest, Customer, CustomerGroup, CustomerGroups, CustomerGroupsReceiver, FindCustomerGroupReply, FindCustomerGroupRequest, Customers, RemoveCustomerReply, RemoveCustomerRequest, RenameCustomerGroupReply, RenameCustomerGroupRequest, CustomersReceiver, ExportRecordsReply, ExportRecordsRequest, ExportedRecord, FindCustomerReply, FindCustomerRequest, HealthzReply, HealthzRequest, ImportCustomersReply, ImportCustomersRequest, ImportResult, ImportRow, ListCustomersReply, ListCustomersRequest, Membership, MigrateRecordsReply, MigrateRecordsRequest, Principal, RestoreRecordsReply, RestoreRecordsRequest, UsageEvent, REVISION_MISMATCH, USAGE_EVENT_SCHEMA_VERSION, USAGE_EVENT_SUBJECT_PREFIX};
+use shared_bucket::export;
+use shared_bucket::lattice::{Lattice, MESSAGING};
+use shared_bucket::policy::{can_manage_group, can_read_customer, can_read_group, is_operator, ACCESS_DENIED};
 
 use wasmbus_rpc::actor::prelude::*;
-use wasmcloud_interface_keyvalue::{GetResponse, KeyValue, KeyValueSender, SetRequest};
+#[cfg(not(target_arch = "wasm32"))]
+use log::{error, info};
+#[cfg(target_arch = "wasm32")]
 use wasmcloud_interface_logging::{error, info};
-use wasmcloud_interface_numbergen::generate_guid;
+use wasmcloud_interface_messaging::{Messaging, MessagingSender, PubMessage};
+
+pub mod keys;
+pub mod records;
+pub mod store;
+
+use keys::Keys;
+use records::{Record, Stored};
+use store::{KeyValueStore, Store};
+
+/// How long (in seconds) replies to idempotent requests are kept when `config:idempotency_window` is not set
+const DEFAULT_IDEMPOTENCY_WINDOW: u32 = 24 * 60 * 60;
+
+/// How many members of a group are read at once when it is listed
+const DEFAULT_LIST_CONCURRENCY: usize = 16;
+
+/// How many customers and groups an export page holds when the request sets no limit
+const DEFAULT_EXPORT_LIMIT: usize = 100;
 
-#[derive(Debug, Default, Actor, HealthResponder)]
+// only the message dispatch is derived natively, the wasm exports of the actors linked in a test would clash
+#[cfg_attr(target_arch = "wasm32", derive(Actor))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(wasmbus_rpc::provider::prelude::Provider))]
+#[derive(Debug, HealthResponder)]
 #[services(Actor, Customers, CustomerGroups)]
-struct CustomersActor {}
+pub struct CustomersActor {
+    lattice: Box<dyn Lattice>,
+    store: Option<Arc<dyn Store>>,
+    list_concurrency: usize,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for CustomersActor {
+    fn default() -> Self {
+        Self::new(shared_bucket::lattice::Host::default())
+    }
+}
 
 impl CustomersActor {
-    async fn create(ctx: &Context, customer: &Customer) -> anyhow::Result<String> {
+    pub fn new<L: Lattice + 'static>(lattice: L) -> Self {
+        CustomersActor { lattice: Box::new(lattice), store: None, list_concurrency: DEFAULT_LIST_CONCURRENCY }
+    }
 
-        let id = generate_guid().await?;
+    /// Keeps the records in `store` instead of the KeyValue provider and number generator of the lattice
+    pub fn with_store<S: Store + 'static>(self, store: S) -> Self {
+        CustomersActor { store: Some(Arc::new(store)), ..self }
+    }
+
+    /// Reads the members of a listed group `concurrency` at a time, one after the other with 1
+    pub fn with_list_concurrency(self, concurrency: usize) -> Self {
+        CustomersActor { list_concurrency: concurrency.max(1), ..self }
+    }
+
+    fn store(&self) -> RpcResult<Arc<dyn Store>> {
+        match &self.store {
+            Some(store) => Ok(store.clone()),
+            None => Ok(Arc::new(KeyValueStore::of(self.lattice.as_ref())?)),
+        }
+    }
+
+    async fn create(&self, ctx: &Context, keys: &Keys, customer: &Customer) -> anyhow::Result<String> {
+
+        let id = self.store()?.generate_id(ctx).await?;
         info!("Creating customer with id {}", id);
-        let request = SetRequest {
-            key: format!("customer:{}", &id),
-            value: serde_json::to_string(customer)?,
-            expires: 0,
-        };
+        self.store()?.set(ctx, &keys.customer(&id), records::encode(customer, 1)?, 0).await?;
+        // the imports skip the customers with the email of another one
+        if self.customer_id(ctx, keys, &customer.email).await?.is_none() {
+            self.store()?.set(ctx, &keys.customer_email(&customer.email), id.clone(), 0).await?;
+        }
+        self.store()?.set_add(ctx, &keys.customers_index(), &id).await?;
 
-        KeyValueSender::new().set(ctx, &request).await?;
+        let event = UsageEvent {
+            event_type: "customer.created".to_string(),
+            customer: Some(id.clone()),
+            ..Default::default()
+        };
+        if let Err(e) = self.publish(ctx, keys, event).await {
+            error!("Error publishing customer created event : {}", e);
+        }
 
         Ok(id)
     }
 
-    async fn find(ctx: &Context, id: String) -> anyhow::Result<Option<Customer>> {
-        match KeyValueSender::new().get(ctx, &format!("customer:{}", id)).await {
-            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
-            Ok(GetResponse { exists: false, .. }) => Ok(None),
+    async fn find(&self, ctx: &Context, keys: &Keys, id: String) -> anyhow::Result<Option<Stored<Customer>>> {
+        match self.read(ctx, &keys.customer(&id)).await {
+            Ok(customer) => Ok(customer),
             Err(e) => bail!("Error searching for customer '{}' : {:?}", id, e)
         }
     }
 
-    async fn create_group(ctx: &Context, group: &CustomerGroup) -> anyhow::Result<()> {
+    /// Id of the first customer created with an email
+    async fn customer_id(&self, ctx: &Context, keys: &Keys, email: &str) -> anyhow::Result<Option<String>> {
+        match self.store()?.get(ctx, &keys.customer_email(email)).await {
+            Ok(id) => Ok(id),
+            Err(e) => bail!("Error searching for customer with email '{}' : {:?}", email, e)
+        }
+    }
+
+    /// Why a customer may not be created : its required fields are blank, or its email is not
+    /// an address
+    fn invalid_customer(customer: &Customer) -> Option<String> {
+        let email = customer.email.trim();
+        let address = email.split_once('@').filter(|(local, domain)| !local.is_empty() && domain.contains('.') && !domain.contains('@'));
+        if customer.first_name.trim().is_empty() {
+            Some("The first name is blank".to_string())
+        } else if address.is_none() || email.contains(char::is_whitespace) {
+            Some(format!("The email '{}' is not an address", customer.email))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the customers of imported rows and their memberships, nothing being written in
+    /// a dry run. A row whose email is held by a customer or an earlier row is a duplicate, the
+    /// existing customer joining the group of the row.
+    async fn import(&self, ctx: &Context, keys: &Keys, rows: &[ImportRow], dry_run: bool) -> anyhow::Result<Vec<ImportResult>> {
+        let mut emails = HashSet::new();
+        let mut results = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let result = match self.import_row(ctx, keys, row, dry_run, &mut emails).await {
+                Ok(result) => result,
+                Err(e) => {
+                    error!("Error importing the customer of line {} : {}", row.line, e);
+                    ImportResult { line: row.line, status: "failed".to_string(), id: None, reason: Some(e.to_string()) }
+                },
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn import_row(&self, ctx: &Context, keys: &Keys, row: &ImportRow, dry_run: bool, emails: &mut HashSet<String>) -> anyhow::Result<ImportResult> {
+        let result = |status: &str, id: Option<String>, reason: Option<String>| ImportResult { line: row.line, status: status.to_string(), id, reason };
 
-        info!("Creating customer group '{}'", group.name);
-        let request = SetRequest {
-            key: format!("customer_group:{}", group.name),
-            value: serde_json::to_string(group)?,
-            expires: 0,
+        if let Some(reason) = Self::invalid_customer(&row.customer) {
+            return Ok(result("invalid", None, Some(reason)));
+        }
+        let group = match &row.group {
+            Some(id) => match self.find_group(ctx, keys, id).await? {
+                Some(group) if dry_run => Some((id, group)),
+                Some(group) => Some((id, self.upgrade_group(ctx, keys, id, group).await?)),
+                None => return Ok(result("invalid", None, Some(format!("The group '{}' does not exist", id)))),
+            },
+            None => None,
         };
 
-        KeyValueSender::new().set(ctx, &request).await?;
+        if let Some(id) = self.customer_id(ctx, keys, &row.customer.email).await? {
+            // the existing customer still joins the group of the row
+            let reason = match group {
+                Some((group_id, _)) if dry_run => Some(format!("The customer exists, they would join the group '{}'", group_id)),
+                Some((group_id, group)) => {
+                    self.add_customer_to_group(ctx, keys, group_id, group, &id).await?;
+                    Some(format!("The customer exists, they joined the group '{}'", group_id))
+                },
+                None => None,
+            };
+            return Ok(result("duplicate", Some(id), reason));
+        }
+        // a dry run creates nothing : the rows sharing an email are told apart here
+        if !emails.insert(keys.customer_email(&row.customer.email)) {
+            return Ok(result("duplicate", None, Some("The email is the one of an earlier row".to_string())));
+        }
+        if dry_run {
+            return Ok(result("created", None, None));
+        }
 
-        Ok(())
+        let id = self.create(ctx, keys, &Customer { id: None, ..row.customer.clone() }).await?;
+        if let Some((group_id, group)) = group {
+            self.add_customer_to_group(ctx, keys, group_id, group, &id).await?;
+        }
+
+        Ok(result("created", Some(id), None))
     }
 
-    async fn find_group(ctx: &Context, name: &String) -> anyhow::Result<Option<CustomerGroup>> {
-        match KeyValueSender::new().get(ctx, &&format!("customer_group:{}", name)).await {
-            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
-            Ok(GetResponse { exists: false, .. }) => Ok(None),
-            Err(e) => bail!("Error searching for customer group '{}' : {:?}", name, e)
+    async fn create_group(&self, ctx: &Context, keys: &Keys, group: &CustomerGroup) -> anyhow::Result<String> {
+
+        let id = self.store()?.generate_id(ctx).await?;
+        info!("Creating customer group '{}' with id {}", group.name, id);
+        let group_members = group.customers.clone().unwrap_or_default();
+        let group = CustomerGroup { id: Some(id.clone()), customers: None, ..group.clone() };
+        self.store()?.set(ctx, &keys.group(&id), records::encode(&group, 1)?, 0).await?;
+        self.store()?.set(ctx, &keys.group_name(&group.name), id.clone(), 0).await?;
+        for customer in group_members {
+            self.add_member(ctx, keys, &id, &customer).await?;
         }
+        self.store()?.set_add(ctx, &keys.groups_index(), &id).await?;
+
+        Ok(id)
     }
 
-    async fn add_customer_to_group(ctx: &Context, group: &String, customer: &String) -> anyhow::Result<()> {
+    async fn find_group(&self, ctx: &Context, keys: &Keys, id: &String) -> anyhow::Result<Option<Stored<CustomerGroup>>> {
+        match self.read_group(ctx, keys, id).await {
+            Ok(group) => Ok(group),
+            Err(e) => bail!("Error searching for customer group '{}' : {:?}", id, e)
+        }
+    }
 
-        let group = match Self::find_group(ctx, group).await {
-            Ok(Some(group)) => group,
-            Ok(None) => bail!("Group does not exists '{}'", group),
-            Err(e) => bail!("Error searching for group '{}' : {}", group, e),
+    /// Reads a group without writing anything : a group of the former versions is upgraded by
+    /// `upgrade_group`, once the caller is allowed to
+    async fn read_group(&self, ctx: &Context, keys: &Keys, id: &str) -> anyhow::Result<Option<Stored<CustomerGroup>>> {
+        let group = match self.decode::<CustomerGroup>(ctx, &keys.group(id)).await? {
+            Some(group) => group,
+            None => return Ok(None),
+        };
+
+        let upgraded = group.upgraded || group.record.customers.is_some();
+        Ok(Some(Stored { upgraded, ..group }))
+    }
+
+    /// Writes back a group read from an older version. The members embedded in the group by the
+    /// former versions are moved to the set of its members.
+    async fn upgrade_group(&self, ctx: &Context, keys: &Keys, id: &str, mut group: Stored<CustomerGroup>) -> anyhow::Result<Stored<CustomerGroup>> {
+        if !group.upgraded {
+            return Ok(group);
+        }
+
+        if let Some(customers) = group.record.customers.take() {
+            info!("Moving the {} members of group {} to their set", customers.len(), id);
+            for customer in &customers {
+                self.add_member(ctx, keys, id, customer).await?;
+            }
+        }
+        info!("Upgrading group '{}' to version {}", id, CustomerGroup::version());
+        self.store()?.set(ctx, &keys.group(id), records::encode(&group.record, group.revision)?, 0).await?;
+        Ok(group)
+    }
+
+    /// Writes a changed group at the revision following the one it was read at, returns the new revision
+    async fn write_group(&self, ctx: &Context, keys: &Keys, id: &str, group: &CustomerGroup, revision: u64) -> anyhow::Result<u64> {
+        self.store()?.set(ctx, &keys.group(id), records::encode(group, revision + 1)?, 0).await?;
+        Ok(revision + 1)
+    }
+
+    /// Adds a customer to the members of a group, returns whether it was not a member
+    async fn add_member(&self, ctx: &Context, keys: &Keys, id: &str, customer: &str) -> anyhow::Result<bool> {
+        if !Self::valid_member(customer) {
+            bail!("Invalid customer id '{}'", customer);
+        }
+        // the key telling the membership is written first, a failure is then fixed by a retry
+        self.store()?.set(ctx, &keys.member(id, customer), String::new(), 0).await?;
+        self.store()?.set_add(ctx, &keys.members(id), customer).await
+    }
+
+    async fn is_member(&self, ctx: &Context, keys: &Keys, id: &str, customer: &str) -> anyhow::Result<bool> {
+        Ok(Self::valid_member(customer) && self.store()?.get(ctx, &keys.member(id, customer)).await?.is_some())
+    }
+
+    /// Customer ids are generated, without the separator of the keys
+    fn valid_member(customer: &str) -> bool {
+        !customer.is_empty() && !customer.contains(':')
+    }
+
+    /// Ids of the members of a group following `after`, by increasing id. All the ids are read,
+    /// the customers only for the page.
+    async fn members(&self, ctx: &Context, keys: &Keys, id: &str, after: Option<&str>, limit: Option<u32>) -> anyhow::Result<Vec<String>> {
+        let mut members = self.store()?.set_members(ctx, &keys.members(id)).await?;
+        members.sort_unstable();
+
+        let start = after.map_or(0, |after| members.partition_point(|member| member.as_str() <= after));
+        let end = limit.map_or(members.len(), |limit| members.len().min(start.saturating_add(limit as usize)));
+        Ok(members.drain(start..end).collect())
+    }
+
+    /// Whether the principal reads a group, as one of its members or admins
+    async fn readable(&self, ctx: &Context, keys: &Keys, principal: &Option<Principal>, id: &str, group: &CustomerGroup) -> anyhow::Result<bool> {
+        match principal {
+            Some(principal) => {
+                // the members of a group of the former versions are embedded until it is upgraded
+                let legacy_member = group.customers.as_ref().is_some_and(|customers| customers.contains(&principal.id));
+                Ok(can_read_group(principal, group, legacy_member || self.is_member(ctx, keys, id, &principal.id).await?))
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Id of the group holding a name
+    async fn group_id(&self, ctx: &Context, keys: &Keys, name: &str) -> anyhow::Result<Option<String>> {
+        match self.store()?.get(ctx, &keys.group_name(name)).await {
+            Ok(id) => Ok(id),
+            Err(e) => bail!("Error searching for customer group named '{}' : {:?}", name, e)
+        }
+    }
+
+    /// Gives a group a name nobody else holds, the group keeping its id. Returns the new revision.
+    async fn rename_group(&self, ctx: &Context, keys: &Keys, group: Stored<CustomerGroup>, id: &str, name: &str) -> anyhow::Result<u64> {
+        let Stored { record: group, revision, .. } = group;
+        if let Some(other) = self.group_id(ctx, keys, name).await? {
+            if other != id {
+                bail!("The group '{}' already exists", name);
+            }
+        }
+
+        info!("Renaming customer group {} from '{}' to '{}'", id, group.name, name);
+        self.store()?.set(ctx, &keys.group_name(name), id.to_string(), 0).await?;
+        if keys.group_name(name) != keys.group_name(&group.name) && self.group_id(ctx, keys, &group.name).await?.as_deref() == Some(id) {
+            self.store()?.delete(ctx, &keys.group_name(&group.name)).await?;
+        }
+        let group = CustomerGroup { name: name.to_string(), ..group };
+        self.write_group(ctx, keys, id, &group, revision).await
+    }
+
+    /// Adds a customer to the members of a group, returns the revision of the group which changes
+    /// when the customer was not a member
+    async fn add_customer_to_group(&self, ctx: &Context, keys: &Keys, id: &str, group: Stored<CustomerGroup>, customer: &str) -> anyhow::Result<u64> {
+        if !self.add_member(ctx, keys, id, customer).await? {
+            return Ok(group.revision);
+        }
+
+        let revision = self.write_group(ctx, keys, id, &group.record, group.revision).await?;
+        let event = UsageEvent {
+            event_type: "group.member_added".to_string(),
+            customer: Some(customer.to_string()),
+            group: Some(id.to_string()),
+            ..Default::default()
+        };
+        if let Err(e) = self.publish(ctx, keys, event).await {
+            error!("Error publishing member added event : {}", e);
+        }
+
+        Ok(revision)
+    }
+
+    /// Removes a customer from the members of a group, returns the new revision of the group when
+    /// the customer was a member
+    async fn remove_customer_from_group(&self, ctx: &Context, keys: &Keys, id: &str, group: Stored<CustomerGroup>, customer: &str) -> anyhow::Result<Option<u64>> {
+        if !Self::valid_member(customer) {
+            return Ok(None);
+        }
+
+        self.store()?.delete(ctx, &keys.member(id, customer)).await?;
+        if !self.store()?.set_remove(ctx, &keys.members(id), customer).await? {
+            return Ok(None);
+        }
+
+        let revision = self.write_group(ctx, keys, id, &group.record, group.revision).await?;
+        let event = UsageEvent {
+            event_type: "group.member_removed".to_string(),
+            customer: Some(customer.to_string()),
+            group: Some(id.to_string()),
+            ..Default::default()
+        };
+        if let Err(e) = self.publish(ctx, keys, event).await {
+            error!("Error publishing member removed event : {}", e);
+        }
+
+        Ok(Some(revision))
+    }
+
+    /// Reads a record without writing it back
+    async fn decode<R: Record>(&self, ctx: &Context, key: &str) -> anyhow::Result<Option<Stored<R>>> {
+        match self.store()?.get(ctx, key).await? {
+            Some(value) => Ok(Some(records::decode::<R>(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads a record. A record upgraded from an older version is written back, at the same revision.
+    async fn read<R: Record>(&self, ctx: &Context, key: &str) -> anyhow::Result<Option<Stored<R>>> {
+        let stored = match self.decode::<R>(ctx, key).await? {
+            Some(stored) => stored,
+            None => return Ok(None),
         };
 
-        let mut group = group;
-        if group.customers.is_none() {
-            group.customers = Some(Vec::new());
+        if stored.upgraded {
+            info!("Upgrading record '{}' to version {}", key, R::version());
+            self.store()?.set(ctx, key, records::encode(&stored.record, stored.revision)?, 0).await?;
+        }
+
+        Ok(Some(stored))
+    }
+
+    /// Upgrades the indexed groups of a tenant, their members and the indexed customers. Records
+    /// written before the indexes and out of the groups are only upgraded when they are read.
+    /// Groups created before the name index get their name, unless another group holds it, and
+    /// customers created before the email index their email.
+    async fn migrate_all(&self, ctx: &Context, keys: &Keys) -> anyhow::Result<MigrateRecordsReply> {
+        let mut reply = MigrateRecordsReply::default();
+        let mut customers: BTreeSet<String> = self.store()?.set_members(ctx, &keys.customers_index()).await?.into_iter().collect();
+
+        for id in self.store()?.set_members(ctx, &keys.groups_index()).await? {
+            let key = keys.group(&id);
+            let result = match self.read_group(ctx, keys, &id).await {
+                Ok(Some(group)) => self.upgrade_group(ctx, keys, &id, group).await.map(Some),
+                result => result,
+            };
+            if let Ok(Some(Stored { record: group, .. })) = &result {
+                customers.extend(self.store()?.set_members(ctx, &keys.members(&id)).await?);
+                if self.group_id(ctx, keys, &group.name).await?.is_none() {
+                    self.store()?.set(ctx, &keys.group_name(&group.name), id.clone(), 0).await?;
+                }
+            }
+            Self::tally(&mut reply, &key, result).await?;
+        }
+
+        for id in customers {
+            let key = keys.customer(&id);
+            let result = self.read::<Customer>(ctx, &key).await;
+            if let Ok(Some(Stored { record: customer, .. })) = &result {
+                if self.customer_id(ctx, keys, &customer.email).await?.is_none() {
+                    self.store()?.set(ctx, &keys.customer_email(&customer.email), id.clone(), 0).await?;
+                }
+            }
+            Self::tally(&mut reply, &key, result).await?;
+        }
+
+        info!("Records migrated : {:?}", reply);
+        Ok(reply)
+    }
+
+    /// Counts a record read by the migration
+    async fn tally<R: Send>(reply: &mut MigrateRecordsReply, key: &str, result: anyhow::Result<Option<Stored<R>>>) -> anyhow::Result<()> {
+        match result {
+            Ok(Some(Stored { upgraded: true, .. })) => reply.migrated += 1,
+            Ok(Some(Stored { upgraded: false, .. })) => reply.current += 1,
+            Ok(None) => {},
+            Err(e) => {
+                error!("Error migrating record '{}' : {}", key, e);
+                reply.failed += 1;
+            },
         }
 
-        let customers = group.customers.as_mut().unwrap();
+        Ok(())
+    }
 
-        if !customers.contains(customer) {
-            customers.push(customer.clone());
+    /// Page of the indexed records of a tenant : the customers by increasing id, then the groups
+    /// each followed by its members by increasing id. The cursor of a page is its last record (see
+    /// `export::cursor`) : the members of a large group span several pages.
+    async fn export(&self, ctx: &Context, keys: &Keys, after: Option<&str>, limit: usize) -> anyhow::Result<ExportRecordsReply> {
+        let mut customers = self.store()?.set_members(ctx, &keys.customers_index()).await?;
+        customers.sort_unstable();
+        let mut groups = self.store()?.set_members(ctx, &keys.groups_index()).await?;
+        groups.sort_unstable();
 
-            let request = SetRequest {
-                key: format!("customer_group:{}", group.name),
-                value: serde_json::to_string(&group)?,
-                expires: 0,
+        // the group of the cursor, with the member its members resume after, is not exported again
+        let (customers_start, groups_start, resumed) = match after.map(|after| (after, after.split_once(':'))) {
+            None => (0, 0, None),
+            Some((_, Some(("customer", id)))) => (customers.partition_point(|customer| customer.as_str() <= id), 0, None),
+            Some((_, Some(("group", id)))) => (customers.len(), groups.partition_point(|group| group.as_str() < id), Some((id, None))),
+            Some((after, Some(("member", member)))) => match member.split_once(':') {
+                Some((id, customer)) => (customers.len(), groups.partition_point(|group| group.as_str() < id), Some((id, Some(customer)))),
+                None => bail!("Invalid cursor '{}'", after),
+            },
+            Some((after, _)) => bail!("Invalid cursor '{}'", after),
+        };
+
+        // a record past the page tells whether there is a next one
+        let mut records = Vec::new();
+        for id in customers.into_iter().skip(customers_start) {
+            if records.len() > limit {
+                break;
+            }
+            if let Some(Stored { record: customer, .. }) = self.find(ctx, keys, id.clone()).await? {
+                let customer = Customer { id: Some(id), ..customer };
+                records.push(ExportedRecord { kind: "customer".to_string(), customer: Some(customer), ..Default::default() });
+            }
+        }
+        for id in groups.into_iter().skip(groups_start) {
+            if records.len() > limit {
+                break;
+            }
+            let after_member = match resumed {
+                Some((group, after_member)) if group == id => after_member,
+                _ => match self.find_group(ctx, keys, &id).await? {
+                    Some(group) => {
+                        let Stored { record: group, .. } = self.upgrade_group(ctx, keys, &id, group).await?;
+                        let group = CustomerGroup { id: Some(id.clone()), ..group };
+                        records.push(ExportedRecord { kind: "group".to_string(), group: Some(group), ..Default::default() });
+                        None
+                    },
+                    None => continue,
+                },
             };
+            let remaining = (limit + 1).saturating_sub(records.len()) as u32;
+            for customer in self.members(ctx, keys, &id, after_member, Some(remaining)).await? {
+                let member = Membership { group: id.clone(), customer };
+                records.push(ExportedRecord { kind: "member".to_string(), member: Some(member), ..Default::default() });
+            }
+        }
+
+        let next = (records.len() > limit).then(|| export::cursor(&records[limit - 1]));
+        records.truncate(limit);
+
+        Ok(ExportRecordsReply { records, next })
+    }
+
+    /// Writes exported customers, groups and memberships at their first revision, with the
+    /// indexes their creation writes. No event is published, the records were created before.
+    async fn restore(&self, ctx: &Context, keys: &Keys, records: &[ExportedRecord]) -> anyhow::Result<u32> {
+        for record in records {
+            match (record.kind.as_str(), record) {
+                ("customer", ExportedRecord { customer: Some(customer), .. }) => {
+                    let id = match customer.id.as_deref() {
+                        Some(id) if Self::valid_member(id) => id,
+                        _ => bail!("Invalid customer id {:?}", customer.id),
+                    };
+                    self.store()?.set(ctx, &keys.customer(id), records::encode(&Customer { id: None, ..customer.clone() }, 1)?, 0).await?;
+                    if self.customer_id(ctx, keys, &customer.email).await?.is_none() {
+                        self.store()?.set(ctx, &keys.customer_email(&customer.email), id.to_string(), 0).await?;
+                    }
+                    self.store()?.set_add(ctx, &keys.customers_index(), id).await?;
+                },
+                ("group", ExportedRecord { group: Some(group), .. }) => {
+                    let id = match group.id.as_deref() {
+                        Some(id) if Self::valid_member(id) => id,
+                        _ => bail!("Invalid group id {:?}", group.id),
+                    };
+                    self.store()?.set(ctx, &keys.group(id), records::encode(&CustomerGroup { customers: None, ..group.clone() }, 1)?, 0).await?;
+                    if self.group_id(ctx, keys, &group.name).await?.is_none() {
+                        self.store()?.set(ctx, &keys.group_name(&group.name), id.to_string(), 0).await?;
+                    }
+                    self.store()?.set_add(ctx, &keys.groups_index(), id).await?;
+                },
+                ("member", ExportedRecord { member: Some(member), .. }) => {
+                    self.add_member(ctx, keys, &member.group, &member.customer).await?;
+                },
+                (kind, _) => bail!("A {} record may not be restored by the customers actor", kind),
+            }
+        }
+
+        Ok(records.len() as u32)
+    }
+
+    async fn publish(&self, ctx: &Context, keys: &Keys, event: UsageEvent) -> anyhow::Result<()> {
+        let event = UsageEvent {
+            schema_version: USAGE_EVENT_SCHEMA_VERSION.to_string(),
+            id: self.store()?.generate_id(ctx).await?,
+            tenant: keys.tenant().map(String::from),
+            ..event
+        };
+
+        MessagingSender::via(self.lattice.provider(MESSAGING)?).publish(ctx, &PubMessage {
+            subject: format!("{}.{}", USAGE_EVENT_SUBJECT_PREFIX, event.event_type),
+            reply_to: None,
+            body: serde_json::to_vec(&event)?,
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Caller identity for the logs
+    fn caller(principal: &Option<Principal>) -> &str {
+        principal.as_ref().map_or("anonymous", |p| p.id.as_str())
+    }
+
+    /// Calls without principal are never allowed
+    fn allowed<F: FnOnce(&Principal) -> bool>(principal: &Option<Principal>, rule: F) -> bool {
+        principal.as_ref().is_some_and(rule)
+    }
+
+    fn access_denied(principal: &Option<Principal>, action: &str) -> RpcError {
+        RpcError::Other(format!("{} : {} may not {}", ACCESS_DENIED, Self::caller(principal), action))
+    }
+
+    /// Group names are displayed, they may hold anything but blanks
+    fn check_name(name: &str) -> RpcResult<()> {
+        match name.trim().is_empty() {
+            true => Err(RpcError::Other("The group name may not be blank".to_string())),
+            false => Ok(()),
+        }
+    }
+
+    /// Refuses to change a group at another revision than the one the caller read
+    fn check_revision(id: &str, group: &Stored<CustomerGroup>, expected: Option<u64>) -> RpcResult<()> {
+        match expected {
+            Some(expected) if expected != group.revision => Err(RpcError::Other(format!(
+                "{} : group '{}' is at revision {}, not {}", REVISION_MISMATCH, id, group.revision, expected
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Keys of the tenant the call is made for
+    fn keys(principal: &Option<Principal>) -> RpcResult<Keys> {
+        Keys::of(principal).map_err(|e| RpcError::Other(e.to_string()))
+    }
+
+    async fn idempotency_window(&self, ctx: &Context) -> anyhow::Result<u32> {
+        match self.store()?.get(ctx, "config:idempotency_window").await {
+            Ok(Some(value)) => Ok(value.parse().unwrap_or(DEFAULT_IDEMPOTENCY_WINDOW)),
+            _ => Ok(DEFAULT_IDEMPOTENCY_WINDOW),
+        }
+    }
 
-            KeyValueSender::new().set(ctx, &request).await?;
+    async fn find_reply<T: DeserializeOwned>(&self, ctx: &Context, keys: &Keys, caller: &str, operation: &str, key: &str) -> anyhow::Result<Option<T>> {
+        match self.store()?.get(ctx, &keys.idempotency(caller, operation, key)).await {
+            Ok(Some(value)) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(None) => Ok(None),
+            Err(e) => bail!("Error searching for idempotency key '{}' : {:?}", key, e)
         }
+    }
+
+    async fn save_reply<T: Serialize>(&self, ctx: &Context, keys: &Keys, caller: &str, operation: &str, key: &str, reply: &T) -> anyhow::Result<()> {
+        self.store()?.set(ctx, &keys.idempotency(caller, operation, key), serde_json::to_string(reply)?, self.idempotency_window(ctx).await?).await?;
 
         Ok(())
     }
@@ -93,14 +620,31 @@ impl Customers for CustomersActor {
     async fn create_customer(
         &self,
         ctx: &Context,
-        arg: &Customer,
+        arg: &CreateCustomerRequest,
     ) -> RpcResult<CreateCustomerReply> {
-        info!("create customer");
+        info!("create customer by {}", Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "create customers"));
+        }
+        let keys = Self::keys(&arg.principal)?;
 
-        let reply = match Self::create(ctx, arg).await {
+        if let Some(key) = &arg.idempotency_key {
+            if let Ok(Some(reply)) = self.find_reply(ctx, &keys, Self::caller(&arg.principal), "create_customer", key).await {
+                info!("Replaying create customer for idempotency key '{}'", key);
+                return Ok(reply);
+            }
+        }
+
+        let reply = match self.create(ctx, &keys, &arg.customer).await {
             Ok(id) => {
                 info!("Customer created : {}", id);
-                CreateCustomerReply { id, success: true }
+                let reply = CreateCustomerReply { id, success: true };
+                if let Some(key) = &arg.idempotency_key {
+                    if let Err(e) = self.save_reply(ctx, &keys, Self::caller(&arg.principal), "create_customer", key, &reply).await {
+                        error!("Error saving reply for idempotency key '{}' : {}", key, e);
+                    }
+                }
+                reply
             },
             Err(e) => {
                 error!("Error creating customer : {}", e);
@@ -114,20 +658,63 @@ impl Customers for CustomersActor {
         Ok(reply)
     }
 
-    async fn find_customer<TS: ToString + ?Sized + Sync>(
+    async fn find_customer(
         &self,
         ctx: &Context,
-        arg: &TS,
+        arg: &FindCustomerRequest,
     ) -> RpcResult<FindCustomerReply> {
-        match Self::find(ctx, arg.to_string()).await {
-            Ok(customer) => Ok(FindCustomerReply { customer }),
-            Err(_) => Ok(FindCustomerReply { customer: None }),
+        info!("find customer by {}", Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, |p| can_read_customer(p, &arg.id)) {
+            return Err(Self::access_denied(&arg.principal, &format!("read customer '{}'", arg.id)));
+        }
+
+        match self.find(ctx, &Self::keys(&arg.principal)?, arg.id.clone()).await {
+            Ok(Some(customer)) => Ok(FindCustomerReply { customer: Some(customer.record), revision: Some(customer.revision) }),
+            _ => Ok(FindCustomerReply { customer: None, revision: None }),
+        }
+    }
+
+    async fn import_customers(&self, ctx: &Context, arg: &ImportCustomersRequest) -> RpcResult<ImportCustomersReply> {
+        info!("import {} customers by {}", arg.rows.len(), Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "import customers"));
         }
+
+        self.import(ctx, &Self::keys(&arg.principal)?, &arg.rows, arg.dry_run).await.map_err(|e| RpcError::Other(format!("Error importing customers : {}", e)))
     }
 
     async fn healthz(&self, ctx: &Context, arg: &HealthzRequest) -> RpcResult<HealthzReply> {
         Ok(HealthzReply { success: true })
     }
+
+    async fn migrate_records(&self, ctx: &Context, arg: &MigrateRecordsRequest) -> RpcResult<MigrateRecordsReply> {
+        info!("migrate records by {}", Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "migrate records"));
+        }
+
+        self.migrate_all(ctx, &Self::keys(&arg.principal)?).await.map_err(|e| RpcError::Other(format!("Error migrating records : {}", e)))
+    }
+
+    async fn export_records(&self, ctx: &Context, arg: &ExportRecordsRequest) -> RpcResult<ExportRecordsReply> {
+        info!("export records after {:?} by {}", arg.after, Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "export records"));
+        }
+
+        let limit = arg.limit.map_or(DEFAULT_EXPORT_LIMIT, |limit| limit.max(1) as usize);
+        self.export(ctx, &Self::keys(&arg.principal)?, arg.after.as_deref(), limit).await.map_err(|e| RpcError::Other(format!("Error exporting records : {}", e)))
+    }
+
+    async fn restore_records(&self, ctx: &Context, arg: &RestoreRecordsRequest) -> RpcResult<RestoreRecordsReply> {
+        info!("restore {} records by {}", arg.records.len(), Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "restore records"));
+        }
+
+        let restored = self.restore(ctx, &Self::keys(&arg.principal)?, &arg.records).await.map_err(|e| RpcError::Other(format!("Error restoring records : {}", e)))?;
+        Ok(RestoreRecordsReply { restored })
+    }
 }
 
 #[async_trait]
@@ -135,71 +722,672 @@ impl CustomerGroups for CustomersActor {
     async fn create_customer_group(
         &self,
         ctx: &Context,
-        arg: &CustomerGroup,
+        arg: &CreateCustomerGroupRequest,
     ) -> RpcResult<CreateCustomerGroupReply> {
-        info!("create customer group");
+        info!("create customer group by {}", Self::caller(&arg.principal));
+        if !Self::allowed(&arg.principal, is_operator) {
+            return Err(Self::access_denied(&arg.principal, "create customer groups"));
+        }
+        let keys = Self::keys(&arg.principal)?;
+        let group = &arg.group;
+        Self::check_name(&group.name)?;
 
-        match Self::find_group(ctx, &arg.name).await {
+        match self.group_id(ctx, &keys, &group.name).await {
             Ok(Some(_)) => {
-                error!("The group '{}' already exists", arg.name);
-                return Err(RpcError::Other(format!("The group '{}' already exists", arg.name)))
+                error!("The group '{}' already exists", group.name);
+                return Err(RpcError::Other(format!("The group '{}' already exists", group.name)))
             },
             _ => {}
         }
 
-        let reply = match Self::create_group(ctx, arg).await {
-            Ok(_) => {
-                info!("Group created : {}", arg.name);
-                CreateCustomerGroupReply { success: true }
+        let reply = match self.create_group(ctx, &keys, group).await {
+            Ok(id) => {
+                info!("Group created : {} ({})", group.name, id);
+                CreateCustomerGroupReply { success: true, id: Some(id) }
             },
             Err(e) => {
                 error!("Error creating customer : {}", e);
-                CreateCustomerGroupReply { success: false }
+                CreateCustomerGroupReply { success: false, id: None }
             },
         };
 
         Ok(reply)
     }
 
+    async fn find_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &FindCustomerGroupRequest,
+    ) -> RpcResult<FindCustomerGroupReply> {
+        info!("find customer group by {}", Self::caller(&arg.principal));
+        let keys = Self::keys(&arg.principal)?;
+
+        match self.find_group(ctx, &keys, &arg.id).await {
+            Ok(Some(group)) => {
+                if !self.readable(ctx, &keys, &arg.principal, &arg.id, &group.record).await.unwrap_or(false) {
+                    return Err(Self::access_denied(&arg.principal, &format!("read group '{}'", arg.id)));
+                }
+                let group = self.upgrade_group(ctx, &keys, &arg.id, group).await.map_err(|e| RpcError::Other(format!("{}", e)))?;
+                Ok(FindCustomerGroupReply { group: Some(group.record), revision: Some(group.revision) })
+            },
+            Ok(None) => Ok(FindCustomerGroupReply { group: None, revision: None }),
+            Err(e) => Err(RpcError::Other(format!("{}", e))),
+        }
+    }
+
+    async fn rename_customer_group(&self, ctx: &Context, arg: &RenameCustomerGroupRequest) -> RpcResult<RenameCustomerGroupReply> {
+        info!("rename customer group by {}", Self::caller(&arg.principal));
+        let keys = Self::keys(&arg.principal)?;
+        Self::check_name(&arg.name)?;
+
+        let group = match self.find_group(ctx, &keys, &arg.id).await {
+            Ok(Some(group)) => group,
+            Ok(None) => return Ok(RenameCustomerGroupReply { success: false, revision: None }),
+            Err(e) => return Err(RpcError::Other(format!("{}", e))),
+        };
+        if !Self::allowed(&arg.principal, |p| can_manage_group(p, &group.record)) {
+            return Err(Self::access_denied(&arg.principal, &format!("manage group '{}'", arg.id)));
+        }
+        let group = self.upgrade_group(ctx, &keys, &arg.id, group).await.map_err(|e| RpcError::Other(format!("{}", e)))?;
+        Self::check_revision(&arg.id, &group, arg.revision)?;
+
+        match self.rename_group(ctx, &keys, group, &arg.id, &arg.name).await {
+            Ok(revision) => Ok(RenameCustomerGroupReply { success: true, revision: Some(revision) }),
+            Err(e) => {
+                error!("Error renaming group '{}' : {}", arg.id, e);
+                Err(RpcError::Other(format!("{}", e)))
+            },
+        }
+    }
+
     async fn add_customer(&self, ctx: &Context, arg: &AddCustomerRequest) -> RpcResult<AddCustomerReply> {
-        let success = match Self::add_customer_to_group(ctx, &arg.group, &arg.customer).await {
-            Ok(()) => true,
-            Err(_) => false
+        info!("add customer by {}", Self::caller(&arg.principal));
+        let keys = Self::keys(&arg.principal)?;
+        let group = match self.find_group(ctx, &keys, &arg.group).await {
+            Ok(Some(group)) => group,
+            Ok(None) => {
+                error!("Group does not exists '{}'", arg.group);
+                return Ok(AddCustomerReply { success: false, revision: None });
+            },
+            Err(e) => {
+                error!("{}", e);
+                return Ok(AddCustomerReply { success: false, revision: None });
+            },
         };
+        if !Self::allowed(&arg.principal, |p| can_manage_group(p, &group.record)) {
+            return Err(Self::access_denied(&arg.principal, &format!("manage group '{}'", arg.group)));
+        }
+        let group = self.upgrade_group(ctx, &keys, &arg.group, group).await.map_err(|e| RpcError::Other(format!("{}", e)))?;
+        Self::check_revision(&arg.group, &group, arg.revision)?;
 
-        Ok(AddCustomerReply {
-            success
-        })
+        match self.add_customer_to_group(ctx, &keys, &arg.group, group, &arg.customer).await {
+            Ok(revision) => Ok(AddCustomerReply { success: true, revision: Some(revision) }),
+            Err(e) => {
+                error!("Error adding customer '{}' to group '{}' : {}", arg.customer, arg.group, e);
+                Ok(AddCustomerReply { success: false, revision: None })
+            },
+        }
     }
 
+    async fn remove_customer(&self, ctx: &Context, arg: &RemoveCustomerRequest) -> RpcResult<RemoveCustomerReply> {
+        info!("remove customer by {}", Self::caller(&arg.principal));
+        let keys = Self::keys(&arg.principal)?;
+        let group = match self.find_group(ctx, &keys, &arg.group).await {
+            Ok(Some(group)) => group,
+            Ok(None) => return Ok(RemoveCustomerReply { success: false, revision: None }),
+            Err(e) => return Err(RpcError::Other(format!("{}", e))),
+        };
+        if !Self::allowed(&arg.principal, |p| can_manage_group(p, &group.record)) {
+            return Err(Self::access_denied(&arg.principal, &format!("manage group '{}'", arg.group)));
+        }
+        let group = self.upgrade_group(ctx, &keys, &arg.group, group).await.map_err(|e| RpcError::Other(format!("{}", e)))?;
+        Self::check_revision(&arg.group, &group, arg.revision)?;
 
-    async fn list_customers<TS: ToString + ?Sized + Sync>(
+        match self.remove_customer_from_group(ctx, &keys, &arg.group, group, &arg.customer).await {
+            Ok(revision) => Ok(RemoveCustomerReply { success: revision.is_some(), revision }),
+            Err(e) => {
+                error!("Error removing customer '{}' from group '{}' : {}", arg.customer, arg.group, e);
+                Err(RpcError::Other(format!("{}", e)))
+            },
+        }
+    }
+
+    async fn list_customers(
         &self,
         ctx: &Context,
-        arg: &TS,
+        arg: &ListCustomersRequest,
     ) -> RpcResult<ListCustomersReply> {
+        info!("list customers by {}", Self::caller(&arg.principal));
+        let keys = Self::keys(&arg.principal)?;
 
-        match Self::find_group(ctx, &arg.to_string()).await {
+        match self.find_group(ctx, &keys, &arg.group).await {
             Ok(Some(group)) => {
-
-                let mut res = Vec::new();
-                match group.customers {
-                    Some(customers) => {
-                        for customer_id in customers {
-                            match Self::find(ctx, customer_id).await {
-                                Ok(Some(customer)) => res.push(customer),
-                                _ => {}
-                            }
-                        }
-                    },
-                    None => {}
+                if !self.readable(ctx, &keys, &arg.principal, &arg.group, &group.record).await.unwrap_or(false) {
+                    return Err(Self::access_denied(&arg.principal, &format!("read group '{}'", arg.group)));
                 }
+                self.upgrade_group(ctx, &keys, &arg.group, group).await
+                    .map_err(|e| RpcError::Other(format!("Error upgrading group '{}' : {}", arg.group, e)))?;
+                let members = self.members(ctx, &keys, &arg.group, arg.after.as_deref(), arg.limit).await
+                    .map_err(|e| RpcError::Other(format!("Error listing the members of group '{}' : {}", arg.group, e)))?;
+
+                // the members are read concurrently, in the order of their ids
+                let keys = &keys;
+                let customers = stream::iter(members)
+                    .map(|customer_id| async move {
+                        let customer = self.find(ctx, keys, customer_id.clone()).await.ok().flatten();
+                        customer.map(|customer| Customer { id: Some(customer_id), ..customer.record })
+                    })
+                    .buffered(self.list_concurrency)
+                    .filter_map(future::ready)
+                    .map(|customer| match &arg.fields {
+                        Some(fields) => project(customer, fields),
+                        None => customer,
+                    })
+                    .collect()
+                    .await;
 
-                Ok(res)
+                Ok(customers)
             },
             _ => {
-                Err(RpcError::Other(format!("The group '{}' doesn't exists", arg.to_string())))
+                Err(RpcError::Other(format!("The group '{}' doesn't exists", arg.group)))
+            }
+        }
+    }
+}
+
+/// Customer with only the listed fields, besides the id and the required ones
+fn project(customer: Customer, fields: &[String]) -> Customer {
+    let keep = |name: &str| fields.iter().any(|field| field == name);
+    Customer {
+        id: customer.id,
+        first_name: customer.first_name,
+        last_name: customer.last_name.filter(|_| keep("lastName")),
+        address: customer.address.filter(|_| keep("address")),
+        city: customer.city.filter(|_| keep("city")),
+        country: customer.country.filter(|_| keep("country")),
+        region: customer.region.filter(|_| keep("region")),
+        telephone: customer.telephone.filter(|_| keep("telephone")),
+        email: customer.email,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use shared_bucket::policy::OPERATOR_ROLE;
+
+    use test_lattice::TestLattice;
+
+    use super::store::MemoryStore;
+    use super::*;
+
+    /// Actor with an empty store
+    fn actor() -> CustomersActor {
+        CustomersActor::new(TestLattice::default()).with_store(MemoryStore::default())
+    }
+
+    fn operator() -> Option<Principal> {
+        Some(Principal { id: "ops".to_string(), kind: "user".to_string(), roles: Some(vec![OPERATOR_ROLE.to_string()]), tenant: None })
+    }
+
+    fn tenant_operator(tenant: &str) -> Option<Principal> {
+        operator().map(|p| Principal { tenant: Some(tenant.to_string()), ..p })
+    }
+
+    fn user(id: &str) -> Option<Principal> {
+        Some(Principal { id: id.to_string(), kind: "user".to_string(), roles: None, tenant: None })
+    }
+
+    fn customer(first_name: &str) -> Customer {
+        Customer { first_name: first_name.to_string(), email: format!("{}@example.com", first_name), ..Default::default() }
+    }
+
+    fn create_customer(actor: &CustomersActor, first_name: &str) -> String {
+        let request = CreateCustomerRequest { customer: customer(first_name), principal: operator(), ..Default::default() };
+        let reply = block_on(actor.create_customer(&Context::default(), &request)).unwrap();
+        assert!(reply.success);
+        reply.id
+    }
+
+    fn create_group(actor: &CustomersActor, name: &str, admins: Option<Vec<String>>) -> String {
+        let group = CustomerGroup { name: name.to_string(), admins, ..Default::default() };
+        let request = CreateCustomerGroupRequest { group, principal: operator() };
+        let reply = block_on(actor.create_customer_group(&Context::default(), &request)).unwrap();
+        assert!(reply.success);
+        reply.id.unwrap()
+    }
+
+    fn find_group(actor: &CustomersActor, id: &str) -> Option<CustomerGroup> {
+        let request = FindCustomerGroupRequest { id: id.to_string(), principal: operator() };
+        block_on(actor.find_customer_group(&Context::default(), &request)).unwrap().group
+    }
+
+    #[test]
+    fn created_customers_are_found() {
+        let actor = actor();
+        let id = create_customer(&actor, "alice");
+
+        let request = FindCustomerRequest { id: id.clone(), principal: user(&id) };
+        let reply = block_on(actor.find_customer(&Context::default(), &request)).unwrap();
+        assert_eq!(reply.customer, Some(customer("alice")));
+
+        let request = FindCustomerRequest { id: "unknown".to_string(), principal: operator() };
+        assert_eq!(block_on(actor.find_customer(&Context::default(), &request)).unwrap().customer, None);
+    }
+
+    #[test]
+    fn idempotent_creation_is_replayed() {
+        let actor = actor();
+        let request = CreateCustomerRequest {
+            customer: customer("alice"),
+            idempotency_key: Some("key".to_string()),
+            principal: operator(),
+        };
+
+        let first = block_on(actor.create_customer(&Context::default(), &request)).unwrap();
+        let second = block_on(actor.create_customer(&Context::default(), &request)).unwrap();
+        assert_eq!(first, second);
+        assert_ne!(create_customer(&actor, "alice"), first.id);
+    }
+
+    #[test]
+    fn imported_rows_are_reported() {
+        let actor = actor();
+        let alice = create_customer(&actor, "alice");
+        let family = create_group(&actor, "family", None);
+        let row = |line: u32, first_name: &str, email: &str, group: Option<&str>| ImportRow {
+            line,
+            customer: Customer { first_name: first_name.to_string(), email: email.to_string(), ..Default::default() },
+            group: group.map(String::from),
+        };
+        let rows = vec![
+            row(2, "bob", "bob@example.com", Some(&family)),
+            row(3, "alice", " ALICE@example.com", Some(&family)),
+            row(4, "bobby", "Bob@Example.com", None),
+            row(5, "", "carol@example.com", None),
+            row(6, "carol", "carol.example.com", None),
+            row(7, "carol", "carol@example.com", Some("unknown")),
+        ];
+        let import = |dry_run| {
+            let request = ImportCustomersRequest { rows: rows.clone(), dry_run, principal: operator() };
+            block_on(actor.import_customers(&Context::default(), &request)).unwrap()
+        };
+        let statuses = |results: &ImportCustomersReply| results.iter().map(|result| (result.line, result.status.clone())).collect::<Vec<_>>();
+        let expected: Vec<_> = [(2, "created"), (3, "duplicate"), (4, "duplicate"), (5, "invalid"), (6, "invalid"), (7, "invalid")]
+            .into_iter().map(|(line, status)| (line, status.to_string())).collect();
+
+        let results = import(true);
+        assert_eq!(statuses(&results), expected);
+        assert_eq!((results[0].id.as_deref(), results[1].id.as_deref()), (None, Some(alice.as_str())));
+        let request = ListCustomersRequest { group: family.clone(), principal: operator(), ..Default::default() };
+        assert!(block_on(actor.list_customers(&Context::default(), &request)).unwrap().is_empty());
+        assert_eq!(results[1].reason, Some(format!("The customer exists, they would join the group '{}'", family)));
+
+        let results = import(false);
+        assert_eq!(statuses(&results), expected);
+        let mut members: Vec<_> = block_on(actor.list_customers(&Context::default(), &request)).unwrap().into_iter().map(|customer| customer.id).collect();
+        members.sort();
+        let mut expected_members = vec![results[0].id.clone(), Some(alice.clone())];
+        expected_members.sort();
+        assert_eq!(members, expected_members);
+        assert_eq!(results[1].reason, Some(format!("The customer exists, they joined the group '{}'", family)));
+        assert_eq!(results[2].id, results[0].id);
+
+        let request = ImportCustomersRequest { rows, dry_run: true, principal: user(&alice) };
+        assert!(block_on(actor.import_customers(&Context::default(), &request)).is_err());
+    }
+
+    #[test]
+    fn group_names_are_unique() {
+        let actor = actor();
+        let id = create_group(&actor, "family", None);
+
+        for name in ["family", " Family", ""] {
+            let request = CreateCustomerGroupRequest { group: CustomerGroup { name: name.to_string(), ..Default::default() }, principal: operator() };
+            assert!(block_on(actor.create_customer_group(&Context::default(), &request)).is_err());
+        }
+
+        let group = find_group(&actor, &id).unwrap();
+        assert_eq!((group.id.as_deref(), group.name.as_str()), (Some(id.as_str()), "family"));
+        assert_eq!(find_group(&actor, "family"), None);
+    }
+
+    #[test]
+    fn groups_are_renamed() {
+        let actor = actor();
+        let alice = create_customer(&actor, "alice");
+        let family = create_group(&actor, "family", Some(vec![alice.clone()]));
+        let friends = create_group(&actor, "friends", None);
+        let rename = |id: &str, name: &str, principal| {
+            let request = RenameCustomerGroupRequest { id: id.to_string(), name: name.to_string(), principal, ..Default::default() };
+            block_on(actor.rename_customer_group(&Context::default(), &request))
+        };
+
+        assert!(rename(&family, "Friends", user(&alice)).is_err());
+        assert!(rename(&family, "home/sweet:home", user(&alice)).unwrap().success);
+        assert!(rename(&family, "Home/Sweet:Home", user(&alice)).unwrap().success);
+        assert_eq!(find_group(&actor, &family).unwrap().name, "Home/Sweet:Home");
+        assert!(!rename("unknown", "family", operator()).unwrap().success);
+        let err = rename(&friends, "family", user(&alice)).unwrap_err();
+        assert!(err.to_string().contains(ACCESS_DENIED));
+
+        // the former name is free again
+        create_group(&actor, "family", None);
+    }
+
+    #[test]
+    fn changes_at_a_former_revision_are_refused() {
+        let actor = actor();
+        let alice = create_customer(&actor, "alice");
+        let family = create_group(&actor, "family", None);
+        let revision = |actor: &CustomersActor| {
+            let request = FindCustomerGroupRequest { id: family.clone(), principal: operator() };
+            block_on(actor.find_customer_group(&Context::default(), &request)).unwrap().revision
+        };
+        assert_eq!(revision(&actor), Some(1));
+
+        let request = RenameCustomerGroupRequest { id: family.clone(), name: "home".to_string(), principal: operator(), revision: Some(1) };
+        assert_eq!(block_on(actor.rename_customer_group(&Context::default(), &request)).unwrap().revision, Some(2));
+        let err = block_on(actor.rename_customer_group(&Context::default(), &request)).unwrap_err();
+        assert!(err.to_string().starts_with(REVISION_MISMATCH));
+
+        // the members are part of the group
+        let request = AddCustomerRequest { customer: alice.clone(), group: family.clone(), principal: operator(), revision: Some(2) };
+        assert_eq!(block_on(actor.add_customer(&Context::default(), &request)).unwrap().revision, Some(3));
+        assert_eq!(block_on(actor.add_customer(&Context::default(), &AddCustomerRequest { revision: None, ..request })).unwrap().revision, Some(3));
+        let request = RemoveCustomerRequest { customer: alice.clone(), group: family.clone(), principal: operator(), revision: Some(2) };
+        assert!(block_on(actor.remove_customer(&Context::default(), &request)).unwrap_err().to_string().starts_with(REVISION_MISMATCH));
+        assert_eq!(revision(&actor), Some(3));
+
+        let request = FindCustomerRequest { id: alice, principal: operator() };
+        assert_eq!(block_on(actor.find_customer(&Context::default(), &request)).unwrap().revision, Some(1));
+    }
+
+    #[test]
+    fn members_are_listed() {
+        let actor = actor();
+        let alice = create_customer(&actor, "alice");
+        let bob = create_customer(&actor, "bob");
+        let family = create_group(&actor, "family", Some(vec![alice.clone()]));
+
+        for member in [&alice, &bob, &bob] {
+            let request = AddCustomerRequest { customer: member.clone(), group: family.clone(), principal: user(&alice), ..Default::default() };
+            assert!(block_on(actor.add_customer(&Context::default(), &request)).unwrap().success);
+        }
+
+        let request = ListCustomersRequest { group: family.clone(), principal: user(&bob), ..Default::default() };
+        let customers = block_on(actor.list_customers(&Context::default(), &request)).unwrap();
+        assert_eq!(customers, vec![Customer { id: Some(alice.clone()), ..customer("alice") }, Customer { id: Some(bob.clone()), ..customer("bob") }]);
+
+        let request = AddCustomerRequest { customer: bob.clone(), group: "unknown".to_string(), principal: operator(), ..Default::default() };
+        assert!(!block_on(actor.add_customer(&Context::default(), &request)).unwrap().success);
+        let request = AddCustomerRequest { customer: "a:b".to_string(), group: family.clone(), principal: operator(), ..Default::default() };
+        assert!(!block_on(actor.add_customer(&Context::default(), &request)).unwrap().success);
+
+        let remove = |customer: &str, principal| {
+            let request = RemoveCustomerRequest { customer: customer.to_string(), group: family.clone(), principal, ..Default::default() };
+            block_on(actor.remove_customer(&Context::default(), &request))
+        };
+        assert!(remove(&alice, user(&bob)).unwrap_err().to_string().contains(ACCESS_DENIED));
+        assert!(remove(&bob, user(&alice)).unwrap().success);
+        assert!(!remove(&bob, user(&alice)).unwrap().success);
+
+        // bob is no longer a member
+        let request = ListCustomersRequest { group: family, principal: user(&bob), ..Default::default() };
+        assert!(block_on(actor.list_customers(&Context::default(), &request)).is_err());
+    }
+
+    #[test]
+    fn members_are_listed_by_pages() {
+        let actor = actor().with_list_concurrency(4);
+        let names: Vec<String> = (0..40).map(|i| format!("customer{:02}", i)).collect();
+        let ids: Vec<String> = names.iter().map(|name| create_customer(&actor, name)).collect();
+        let group = CustomerGroup { name: "family".to_string(), customers: Some(ids.into_iter().rev().collect()), ..Default::default() };
+        let request = CreateCustomerGroupRequest { group, principal: operator() };
+        let family = block_on(actor.create_customer_group(&Context::default(), &request)).unwrap().id.unwrap();
+
+        let mut listed = Vec::new();
+        let mut after = None;
+        loop {
+            let request = ListCustomersRequest { group: family.clone(), principal: operator(), after, limit: Some(15), ..Default::default() };
+            let page = block_on(actor.list_customers(&Context::default(), &request)).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 15);
+            after = page.last().and_then(|customer| customer.id.clone());
+            listed.extend(page.into_iter().map(|customer| customer.first_name));
+        }
+        // the sequential ids of the number generator follow the creation order
+        assert_eq!(listed, names);
+    }
+
+    #[test]
+    fn embedded_members_are_moved() {
+        let actor = actor();
+        let ctx = Context::default();
+        let alice = create_customer(&actor, "alice");
+        let value = format!(r#"{{"version":1,"data":{{"name":"family","customers":["{}"]}}}}"#, alice);
+        block_on(actor.store().unwrap().set(&ctx, "customer_group:family", value, 0)).unwrap();
+
+        let request = ListCustomersRequest { group: "family".to_string(), principal: user(&alice), ..Default::default() };
+        let customers = block_on(actor.list_customers(&ctx, &request)).unwrap();
+        assert_eq!(customers.len(), 1);
+
+        let stored = block_on(actor.store().unwrap().get(&ctx, "customer_group:family")).unwrap().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&stored).unwrap()["data"].get("customers"), None);
+        assert_eq!(block_on(actor.store().unwrap().set_members(&ctx, "customer_group_members:family")).unwrap(), vec![alice]);
+    }
+
+    #[test]
+    fn listed_members_are_projected() {
+        let actor = actor();
+        let request = CreateCustomerRequest {
+            customer: Customer { city: Some("Paris".to_string()), telephone: Some("+33100000000".to_string()), ..customer("alice") },
+            principal: operator(),
+            ..Default::default()
+        };
+        let alice = block_on(actor.create_customer(&Context::default(), &request)).unwrap().id;
+        let family = create_group(&actor, "family", None);
+        let request = AddCustomerRequest { customer: alice.clone(), group: family.clone(), principal: operator(), ..Default::default() };
+        assert!(block_on(actor.add_customer(&Context::default(), &request)).unwrap().success);
+
+        let request = ListCustomersRequest { group: family, principal: operator(), fields: Some(vec!["city".to_string(), "unknown".to_string()]), ..Default::default() };
+        let customers = block_on(actor.list_customers(&Context::default(), &request)).unwrap();
+        assert_eq!(customers, vec![Customer { id: Some(alice), city: Some("Paris".to_string()), ..customer("alice") }]);
+    }
+
+    #[test]
+    fn legacy_records_are_upgraded_on_read() {
+        let actor = actor();
+        let ctx = Context::default();
+        block_on(actor.store().unwrap().set(&ctx, "customer:legacy", r#"{"firstName":"alice","email":"alice@example.com"}"#.to_string(), 0)).unwrap();
+
+        let request = FindCustomerRequest { id: "legacy".to_string(), principal: operator() };
+        assert_eq!(block_on(actor.find_customer(&ctx, &request)).unwrap().customer, Some(customer("alice")));
+
+        let stored = block_on(actor.store().unwrap().get(&ctx, "customer:legacy")).unwrap().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&stored).unwrap()["version"], 1);
+    }
+
+    #[test]
+    fn legacy_groups_are_only_upgraded_for_allowed_callers() {
+        let actor = actor();
+        let ctx = Context::default();
+        let legacy = r#"{"name":"family","customers":["bob"]}"#;
+        block_on(actor.store().unwrap().set(&ctx, "customer_group:family", legacy.to_string(), 0)).unwrap();
+        let stored = || block_on(actor.store().unwrap().get(&ctx, "customer_group:family")).unwrap().unwrap();
+
+        let request = FindCustomerGroupRequest { id: "family".to_string(), principal: user("mallory") };
+        assert!(block_on(actor.find_customer_group(&ctx, &request)).is_err());
+        assert_eq!(stored(), legacy);
+
+        // an embedded member reads the group, which is then upgraded
+        let request = FindCustomerGroupRequest { id: "family".to_string(), principal: user("bob") };
+        let reply = block_on(actor.find_customer_group(&ctx, &request)).unwrap();
+        assert_eq!(reply.group.and_then(|group| group.customers), None);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&stored()).unwrap()["version"], CustomerGroup::version());
+        assert!(block_on(actor.is_member(&ctx, &Keys::default(), "family", "bob")).unwrap());
+    }
+
+    #[test]
+    fn records_are_migrated_in_bulk() {
+        let actor = actor();
+        let ctx = Context::default();
+        let alice = create_customer(&actor, "alice");
+        // a group and its member written before the envelope and the indexes, a corrupted customer
+        block_on(actor.store().unwrap().set(&ctx, "customer:legacy", r#"{"firstName":"bob","email":"bob@example.com"}"#.to_string(), 0)).unwrap();
+        block_on(actor.store().unwrap().set(&ctx, "customer_group:family", r#"{"name":"family","customers":["legacy"]}"#.to_string(), 0)).unwrap();
+        block_on(actor.store().unwrap().set_add(&ctx, &Keys::default().groups_index(), "family")).unwrap();
+        block_on(actor.store().unwrap().set(&ctx, "customer:corrupted", "{".to_string(), 0)).unwrap();
+        block_on(actor.store().unwrap().set_add(&ctx, &Keys::default().customers_index(), "corrupted")).unwrap();
+
+        let request = MigrateRecordsRequest { principal: operator() };
+        let reply = block_on(actor.migrate_records(&ctx, &request)).unwrap();
+        assert_eq!(reply, MigrateRecordsReply { migrated: 2, current: 1, failed: 1 });
+        let reply = block_on(actor.migrate_records(&ctx, &request)).unwrap();
+        assert_eq!(reply, MigrateRecordsReply { migrated: 0, current: 3, failed: 1 });
+
+        let request = MigrateRecordsRequest { principal: user(&alice) };
+        assert!(block_on(actor.migrate_records(&ctx, &request)).is_err());
+
+        // the group keyed by its name kept it as its id, and holds the name
+        assert_eq!(find_group(&actor, "family").and_then(|group| group.id), Some("family".to_string()));
+        let request = CreateCustomerGroupRequest { group: CustomerGroup { name: "Family".to_string(), ..Default::default() }, principal: operator() };
+        assert!(block_on(actor.create_customer_group(&ctx, &request)).is_err());
+    }
+
+    #[test]
+    fn exports_are_paged_and_restored() {
+        let actor = actor();
+        let ctx = Context::default();
+        let customers: Vec<String> = ["alice", "bob", "carol"].iter().map(|name| create_customer(&actor, name)).collect();
+        let family = create_group(&actor, "family", None);
+        for customer in &customers {
+            let request = AddCustomerRequest { customer: customer.clone(), group: family.clone(), principal: operator(), ..Default::default() };
+            block_on(actor.add_customer(&ctx, &request)).unwrap();
+        }
+
+        let export = |actor: &CustomersActor| {
+            let mut pages = Vec::new();
+            let mut after = None;
+            loop {
+                let request = ExportRecordsRequest { after: after.clone(), limit: Some(2), principal: operator() };
+                let reply = block_on(actor.export_records(&ctx, &request)).unwrap();
+                pages.push(reply.records);
+                match reply.next {
+                    Some(next) => after = Some(next),
+                    None => return pages,
+                }
             }
+        };
+        let pages = export(&actor);
+        let kinds: Vec<Vec<&str>> = pages.iter().map(|page| page.iter().map(|record| record.kind.as_str()).collect()).collect();
+        // the members of the group span the pages
+        assert_eq!(kinds, vec![vec!["customer", "customer"], vec!["customer", "group"], vec!["member", "member"], vec!["member"]]);
+
+        let restored = self::actor();
+        let records: Vec<ExportedRecord> = pages.concat();
+        let request = RestoreRecordsRequest { records: records.clone(), principal: operator() };
+        assert_eq!(block_on(restored.restore_records(&ctx, &request)).unwrap().restored, 7);
+        assert_eq!(export(&restored).concat(), records);
+        assert_eq!(find_group(&restored, &family).map(|group| group.name), Some("family".to_string()));
+        let request = CreateCustomerGroupRequest { group: CustomerGroup { name: "Family".to_string(), ..Default::default() }, principal: operator() };
+        assert!(block_on(restored.create_customer_group(&ctx, &request)).is_err());
+
+        let request = ExportRecordsRequest { principal: user(&customers[0]), ..Default::default() };
+        assert!(block_on(actor.export_records(&ctx, &request)).is_err());
+        let bucket = ExportedRecord { kind: "bucket".to_string(), ..Default::default() };
+        let request = RestoreRecordsRequest { records: vec![bucket], principal: operator() };
+        assert!(block_on(restored.restore_records(&ctx, &request)).is_err());
+    }
+
+    #[test]
+    fn tenants_are_isolated() {
+        let actor = actor();
+        let ctx = Context::default();
+        let (acme, globex) = (tenant_operator("acme"), tenant_operator("globex"));
+
+        let mut families = Vec::new();
+        for principal in [&acme, &globex] {
+            let request = CreateCustomerGroupRequest { group: CustomerGroup { name: "family".to_string(), ..Default::default() }, principal: principal.clone() };
+            families.push(block_on(actor.create_customer_group(&ctx, &request)).unwrap().id.unwrap());
+        }
+        let request = CreateCustomerRequest { customer: customer("alice"), principal: acme.clone(), ..Default::default() };
+        let alice = block_on(actor.create_customer(&ctx, &request)).unwrap().id;
+        let request = AddCustomerRequest { customer: alice.clone(), group: families[0].clone(), principal: acme.clone(), ..Default::default() };
+        assert!(block_on(actor.add_customer(&ctx, &request)).unwrap().success);
+
+        let request = ListCustomersRequest { group: families[0].clone(), principal: acme.clone(), ..Default::default() };
+        assert_eq!(block_on(actor.list_customers(&ctx, &request)).unwrap(), vec![Customer { id: Some(alice.clone()), ..customer("alice") }]);
+        let request = ListCustomersRequest { group: families[1].clone(), principal: globex.clone(), ..Default::default() };
+        assert_eq!(block_on(actor.list_customers(&ctx, &request)).unwrap(), vec![]);
+        let request = ListCustomersRequest { group: families[0].clone(), principal: globex.clone(), ..Default::default() };
+        assert!(block_on(actor.list_customers(&ctx, &request)).is_err());
+
+        for principal in [globex.clone(), operator()] {
+            let request = FindCustomerRequest { id: alice.clone(), principal };
+            assert_eq!(block_on(actor.find_customer(&ctx, &request)).unwrap().customer, None);
         }
+        assert_eq!(find_group(&actor, &families[0]), None);
+
+        let request = MigrateRecordsRequest { principal: globex };
+        assert_eq!(block_on(actor.migrate_records(&ctx, &request)).unwrap(), MigrateRecordsReply { migrated: 0, current: 1, failed: 0 });
+    }
+
+    #[test]
+    fn idempotency_keys_are_scoped_to_the_tenant() {
+        let actor = actor();
+        let ctx = Context::default();
+        let request = |principal| CreateCustomerRequest { customer: customer("alice"), idempotency_key: Some("key".to_string()), principal };
+
+        let acme = block_on(actor.create_customer(&ctx, &request(tenant_operator("acme")))).unwrap();
+        let globex = block_on(actor.create_customer(&ctx, &request(tenant_operator("globex")))).unwrap();
+        assert_ne!(acme.id, globex.id);
+        assert_eq!(block_on(actor.create_customer(&ctx, &request(tenant_operator("acme")))).unwrap(), acme);
+    }
+
+    #[test]
+    fn idempotency_keys_are_scoped_to_the_caller() {
+        let actor = actor();
+        let ctx = Context::default();
+        let request = |principal| CreateCustomerRequest { customer: customer("alice"), idempotency_key: Some("key".to_string()), principal };
+        let other = operator().map(|p| Principal { id: "other-ops".to_string(), ..p });
+
+        let ops = block_on(actor.create_customer(&ctx, &request(operator()))).unwrap();
+        let other = block_on(actor.create_customer(&ctx, &request(other))).unwrap();
+        assert!(ops.success && other.success);
+        assert_ne!(ops.id, other.id);
+        assert_eq!(block_on(actor.create_customer(&ctx, &request(operator()))).unwrap(), ops);
+    }
+
+    #[test]
+    fn invalid_tenants_are_rejected() {
+        let actor = actor();
+        let request = CreateCustomerRequest { customer: customer("alice"), principal: tenant_operator("acme:customer"), ..Default::default() };
+        assert!(block_on(actor.create_customer(&Context::default(), &request)).is_err());
+    }
+
+    #[test]
+    fn access_is_denied_outside_the_policy() {
+        let actor = actor();
+        let alice = create_customer(&actor, "alice");
+        let family = create_group(&actor, "family", None);
+
+        let request = CreateCustomerRequest { customer: customer("mallory"), principal: user(&alice), ..Default::default() };
+        assert!(block_on(actor.create_customer(&Context::default(), &request)).is_err());
+
+        let request = FindCustomerRequest { id: alice.clone(), principal: None };
+        assert!(block_on(actor.find_customer(&Context::default(), &request)).is_err());
+
+        let request = FindCustomerRequest { id: alice.clone(), principal: user("mallory") };
+        assert!(block_on(actor.find_customer(&Context::default(), &request)).is_err());
+
+        let request = AddCustomerRequest { customer: alice.clone(), group: family.clone(), principal: user(&alice), ..Default::default() };
+        let err = block_on(actor.add_customer(&Context::default(), &request)).unwrap_err();
+        assert!(err.to_string().contains(ACCESS_DENIED));
+
+        let request = ListCustomersRequest { group: family, principal: user(&alice), ..Default::default() };
+        assert!(block_on(actor.list_customers(&Context::default(), &request)).is_err());
     }
 }
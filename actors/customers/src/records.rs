@@ -0,0 +1,144 @@
+//! Versioned envelope of the stored customers and groups.
+//!
+//! Records are stored as `{"version": 1, "revision": 3, "data": {...}}`. When a record type changes,
+//! a migration upgrading the JSON of the previous version is appended to its `MIGRATIONS` : older
+//! records are upgraded when they are read, or all at once by the `MigrateRecords` operation.
+//!
+//! The revision counts the changes of the data, the upgrades keep it : callers tell the revision
+//! they read to change a record only if nobody changed it since.
+
+use anyhow::bail;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use shared_bucket::{Customer, CustomerGroup};
+
+/// Upgrades the data of a record from a version to the next one
+pub type Migration = fn(Value) -> anyhow::Result<Value>;
+
+/// Type stored in an envelope
+pub trait Record: Serialize + DeserializeOwned + Send {
+    /// The migration at index `i` upgrades version `i` to `i + 1` : the current version is
+    /// the number of migrations
+    const MIGRATIONS: &'static [Migration];
+
+    fn version() -> u32 {
+        Self::MIGRATIONS.len() as u32
+    }
+}
+
+impl Record for Customer {
+    const MIGRATIONS: &'static [Migration] = &[unversioned];
+}
+
+impl Record for CustomerGroup {
+    const MIGRATIONS: &'static [Migration] = &[unversioned, group_ids];
+}
+
+/// Records written before the envelope, version 0, hold the data as it is
+fn unversioned(data: Value) -> anyhow::Result<Value> {
+    Ok(data)
+}
+
+/// Version 1 groups were keyed by their name, which becomes their id
+fn group_ids(mut data: Value) -> anyhow::Result<Value> {
+    match data.as_object_mut() {
+        Some(group) => {
+            let name = group.get("name").cloned().unwrap_or_default();
+            group.entry("id").or_insert(name);
+        },
+        None => bail!("Group record is not an object"),
+    }
+    Ok(data)
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    /// Absent from the records written before the revisions, which are at revision 0
+    #[serde(default)]
+    revision: u64,
+    data: Value,
+}
+
+/// Record read from its envelope
+#[derive(Debug, PartialEq)]
+pub struct Stored<R> {
+    pub record: R,
+    pub revision: u64,
+    /// Whether the record was upgraded from an older version
+    pub upgraded: bool,
+}
+
+/// Stored value of a record at a revision, at the current version
+pub fn encode<R: Record>(record: &R, revision: u64) -> anyhow::Result<String> {
+    Ok(serde_json::to_string(&Envelope { version: R::version(), revision, data: serde_json::to_value(record)? })?)
+}
+
+/// Record of a stored value
+pub fn decode<R: Record>(value: &str) -> anyhow::Result<Stored<R>> {
+    let value: Value = serde_json::from_str(value)?;
+    let Envelope { version, revision, mut data } = match value.get("version").zip(value.get("data")) {
+        Some(_) => serde_json::from_value(value)?,
+        None => Envelope { version: 0, revision: 0, data: value },
+    };
+    if version > R::version() {
+        bail!("Record version {} is newer than the supported version {}", version, R::version());
+    }
+
+    for migration in &R::MIGRATIONS[version as usize..] {
+        data = migration(data)?;
+    }
+
+    Ok(Stored { record: serde_json::from_value(data)?, revision, upgraded: version < R::version() })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Renamed {
+        name: String,
+    }
+
+    impl Record for Renamed {
+        const MIGRATIONS: &'static [Migration] = &[unversioned, rename_label];
+    }
+
+    /// Version 1 called the field `label`
+    fn rename_label(mut data: Value) -> anyhow::Result<Value> {
+        Ok(json!({ "name": data["label"].take() }))
+    }
+
+    #[test]
+    fn records_are_stored_at_the_current_version() {
+        let record = Renamed { name: "family".to_string() };
+        let value = encode(&record, 3).unwrap();
+
+        assert_eq!(serde_json::from_str::<Value>(&value).unwrap(), json!({ "version": 2, "revision": 3, "data": { "name": "family" } }));
+        assert_eq!(decode::<Renamed>(&value).unwrap(), Stored { record, revision: 3, upgraded: false });
+    }
+
+    #[test]
+    fn older_records_are_upgraded() {
+        let expected = Renamed { name: "family".to_string() };
+
+        let value = json!({ "version": 1, "revision": 2, "data": { "label": "family" } }).to_string();
+        assert_eq!(decode::<Renamed>(&value).unwrap(), Stored { record: expected, revision: 2, upgraded: true });
+
+        let customer = decode::<Customer>(r#"{"firstName":"alice","email":"alice@example.com"}"#).unwrap();
+        assert_eq!((customer.record.first_name.as_str(), customer.revision), ("alice", 0));
+        assert!(customer.upgraded);
+
+        let group = decode::<CustomerGroup>(r#"{"version":1,"data":{"name":"family"}}"#).unwrap();
+        assert_eq!((group.record.id.as_deref(), group.revision), (Some("family"), 0));
+    }
+
+    #[test]
+    fn newer_records_are_not_read() {
+        let value = json!({ "version": 3, "data": { "name": "family" } }).to_string();
+        assert!(decode::<Renamed>(&value).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Storage of the customers actor : the KeyValue capability provider and the number generator
+//! the actor is linked to in its lattice, or an in-memory map for the tests
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+use shared_bucket::lattice::{Lattice, Link, KEYVALUE, NUMBERGEN};
+use wasmbus_rpc::actor::prelude::*;
+use wasmcloud_interface_keyvalue::{GetResponse, KeyValue, KeyValueSender, SetAddRequest, SetDelRequest, SetRequest};
+use wasmcloud_interface_numbergen::{NumberGen, NumberGenSender};
+
+/// Key/value storage of customers, groups, their indexes and idempotent replies
+#[async_trait]
+pub trait Store: Debug + Send + Sync {
+    /// Value of a key, `None` when the key does not exist
+    async fn get(&self, ctx: &Context, key: &str) -> anyhow::Result<Option<String>>;
+
+    /// Sets the value of a key, expiring after `expires` seconds (0 never expires)
+    async fn set(&self, ctx: &Context, key: &str, value: String, expires: u32) -> anyhow::Result<()>;
+
+    /// Removes a key, if it exists
+    async fn delete(&self, ctx: &Context, key: &str) -> anyhow::Result<()>;
+
+    /// Adds a member to a set, returns whether it was not in the set
+    async fn set_add(&self, ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool>;
+
+    /// Removes a member from a set, returns whether it was in the set
+    async fn set_remove(&self, ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool>;
+
+    /// Members of a set, none when the set does not exist
+    async fn set_members(&self, ctx: &Context, set: &str) -> anyhow::Result<Vec<String>>;
+
+    /// New unique identifier
+    async fn generate_id(&self, ctx: &Context) -> anyhow::Result<String>;
+}
+
+/// Store backed by the KeyValue capability provider and the number generator of the lattice
+#[derive(Debug)]
+pub struct KeyValueStore {
+    keyvalue: KeyValueSender<Link>,
+    numbergen: NumberGenSender<Link>,
+}
+
+impl KeyValueStore {
+    pub fn of(lattice: &dyn Lattice) -> RpcResult<Self> {
+        Ok(KeyValueStore {
+            keyvalue: KeyValueSender::via(lattice.provider(KEYVALUE)?),
+            numbergen: NumberGenSender::via(lattice.provider(NUMBERGEN)?),
+        })
+    }
+}
+
+#[async_trait]
+impl Store for KeyValueStore {
+    async fn get(&self, ctx: &Context, key: &str) -> anyhow::Result<Option<String>> {
+        match self.keyvalue.get(ctx, key).await? {
+            GetResponse { exists: true, value } => Ok(Some(value)),
+            GetResponse { exists: false, .. } => Ok(None),
+        }
+    }
+
+    async fn set(&self, ctx: &Context, key: &str, value: String, expires: u32) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: key.to_string(),
+            value,
+            expires,
+        };
+
+        self.keyvalue.set(ctx, &request).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, ctx: &Context, key: &str) -> anyhow::Result<()> {
+        self.keyvalue.del(ctx, key).await?;
+
+        Ok(())
+    }
+
+    async fn set_add(&self, ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool> {
+        let request = SetAddRequest {
+            set_name: set.to_string(),
+            value: member.to_string(),
+        };
+
+        Ok(self.keyvalue.set_add(ctx, &request).await? > 0)
+    }
+
+    async fn set_remove(&self, ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool> {
+        let request = SetDelRequest {
+            set_name: set.to_string(),
+            value: member.to_string(),
+        };
+
+        Ok(self.keyvalue.set_del(ctx, &request).await? > 0)
+    }
+
+    async fn set_members(&self, ctx: &Context, set: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self.keyvalue.set_query(ctx, set).await?)
+    }
+
+    async fn generate_id(&self, ctx: &Context) -> anyhow::Result<String> {
+        Ok(self.numbergen.generate_guid(ctx).await?)
+    }
+}
+
+/// Store keeping the values and sets in memory, ids are sequential.
+/// Expiration is ignored : values are kept as long as the store lives.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    values: Mutex<HashMap<String, String>>,
+    sets: Mutex<HashMap<String, BTreeSet<String>>>,
+    sequence: Mutex<u64>,
+}
+
+#[async_trait]
+impl Store for MemoryStore {
+    async fn get(&self, _ctx: &Context, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self.values.lock().unwrap().get(key).cloned())
+    }
+
+    async fn set(&self, _ctx: &Context, key: &str, value: String, _expires: u32) -> anyhow::Result<()> {
+        self.values.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, _ctx: &Context, key: &str) -> anyhow::Result<()> {
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn set_add(&self, _ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool> {
+        Ok(self.sets.lock().unwrap().entry(set.to_string()).or_default().insert(member.to_string()))
+    }
+
+    async fn set_remove(&self, _ctx: &Context, set: &str, member: &str) -> anyhow::Result<bool> {
+        Ok(self.sets.lock().unwrap().get_mut(set).is_some_and(|members| members.remove(member)))
+    }
+
+    async fn set_members(&self, _ctx: &Context, set: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self.sets.lock().unwrap().get(set).map(|members| members.iter().cloned().collect()).unwrap_or_default())
+    }
+
+    async fn generate_id(&self, _ctx: &Context) -> anyhow::Result<String> {
+        let mut sequence = self.sequence.lock().unwrap();
+        *sequence += 1;
+        Ok(format!("{:08}", sequence))
+    }
+}
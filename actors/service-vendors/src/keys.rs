@@ -0,0 +1,91 @@
+//! Storage keys of a tenant.
+//!
+//! Group ids are only unique in a tenant : the buckets of a tenant, their ledgers, invoices,
+//! thresholds and transfers, the orders and the idempotent replies are prefixed with
+//! `tenant:{tenant}:` as the customers actor prefixes its groups. The vendors, the catalog and
+//! the configuration are platform wide.
+
+use anyhow::bail;
+use shared_bucket::Principal;
+
+#[derive(Debug, Default)]
+pub struct Keys {
+    tenant: Option<String>,
+    prefix: String,
+}
+
+impl Keys {
+    /// Keys of a tenant, the platform wide ones without tenant. The tenant may not contain the
+    /// `:` separator.
+    pub fn of(tenant: Option<&str>) -> anyhow::Result<Self> {
+        match tenant {
+            None => Ok(Keys::default()),
+            Some(tenant) if tenant.is_empty() || tenant.contains(':') => bail!("Invalid tenant '{}'", tenant),
+            Some(tenant) => Ok(Keys { tenant: Some(tenant.to_string()), prefix: format!("tenant:{}:", tenant) }),
+        }
+    }
+
+    /// Keys of the tenant of a principal
+    pub fn of_principal(principal: &Option<Principal>) -> anyhow::Result<Self> {
+        Self::of(principal.as_ref().and_then(|p| p.tenant.as_deref()))
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    pub fn bucket(&self, group: &str) -> String {
+        format!("{}bucket:{}", self.prefix, group)
+    }
+
+    /// Units of a service consumed by a group in a period
+    pub fn service_usage(&self, group: &str, service: &str, period: &str) -> String {
+        format!("{}service_usage:{}:{}:{}", self.prefix, group, service, period)
+    }
+
+    pub fn order(&self, id: &str) -> String {
+        format!("{}order:{}", self.prefix, id)
+    }
+
+    /// Bucket units refunded of an order
+    pub fn order_refunded(&self, id: &str) -> String {
+        format!("{}order_refunded:{}", self.prefix, id)
+    }
+
+    pub fn transfer(&self, id: &str) -> String {
+        format!("{}transfer:{}", self.prefix, id)
+    }
+
+    /// List of the transfers from and to a group
+    pub fn transfers(&self, group: &str) -> String {
+        format!("{}transfers:{}", self.prefix, group)
+    }
+
+    /// List of the ledger entries of a group in a period
+    pub fn ledger(&self, group: &str, period: &str) -> String {
+        format!("{}ledger:{}:{}", self.prefix, group, period)
+    }
+
+    /// Set of the periods of the ledgers of a group
+    pub fn ledger_periods(&self, group: &str) -> String {
+        format!("{}ledger_periods:{}", self.prefix, group)
+    }
+
+    pub fn invoice(&self, group: &str, period: &str) -> String {
+        format!("{}invoice:{}:{}", self.prefix, group, period)
+    }
+
+    /// Last invoice number of a period, invoices being numbered by tenant
+    pub fn invoice_sequence(&self, period: &str) -> String {
+        format!("{}invoice_sequence:{}", self.prefix, period)
+    }
+
+    pub fn thresholds(&self, group: &str) -> String {
+        format!("{}thresholds:{}", self.prefix, group)
+    }
+
+    /// Reply saved for the idempotency key a caller sent with an operation
+    pub fn idempotency(&self, caller: &str, operation: &str, key: &str) -> String {
+        format!("{}idempotency:{}:{}:{}", self.prefix, caller, operation, key)
+    }
+}
@@ -1,11 +1,1366 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared_bucket::policy::{is_operator, ACCESS_DENIED, BILLING_ROLE};
+use shared_bucket::lattice::{Lattice, Link, KEYVALUE, MESSAGING, NUMBERGEN};
+use shared_bucket::{invoice, rating, tax};
+use shared_bucket::{AuthorizeServiceUsageReply, Bucket, BucketRef, BucketThresholds, BuyServiceReply, CatalogService, CatalogServiceReply, CatalogServiceRequest, CatalogServices, CustomerGroups, CustomerGroupsSender, Customers, CustomersSender, ExportBucketReply, ExportBucketRequest, ExportedRecord, FindCustomerGroupRequest, FindCustomerRequest, GetInvoiceReply, GetServiceReply, GroupLedgerEntry, Invoice, InvoiceRequest, Invoices, InvoicesReceiver, LedgerEntry, MigrateLedgersRequest, MigrateRecordsReply, Principal, RefundReply, RefundRequest, TaxRule, Transfer, TransferReply, TransferRequest, Transfers, RegisterVendorReply, RestoreBucketsRequest, Service, ServiceOrder, ServiceRef, ServiceUsage, ServiceVendors, ServiceVendorsReceiver, SetBucketThresholdsReply, SetBucketThresholdsRequest, UsageEvent, Vendor, VendorCredentials, USAGE_EVENT_SCHEMA_VERSION, USAGE_EVENT_SUBJECT_PREFIX};
+
 use wasmbus_rpc::actor::prelude::*;
-use shared_bucket::{Customers};
-use wasmcloud_interface_logging::{info};
+use wasmbus_rpc::Timestamp;
+use wasmcloud_interface_keyvalue::{GetResponse, IncrementRequest, KeyValue, KeyValueSender, ListAddRequest, ListDelRequest, ListRangeRequest, SetAddRequest, SetDelRequest, SetRequest};
+#[cfg(not(target_arch = "wasm32"))]
+use log::{error, info};
+#[cfg(target_arch = "wasm32")]
+use wasmcloud_interface_logging::{error, info};
+use wasmcloud_interface_messaging::{Messaging, MessagingSender, PubMessage};
+use wasmcloud_interface_numbergen::{NumberGen, NumberGenSender};
+
+pub mod keys;
+
+use keys::Keys;
+
+/// How long (in seconds) replies to idempotent requests are kept when `config:idempotency_window` is not set
+const DEFAULT_IDEMPOTENCY_WINDOW: u32 = 24 * 60 * 60;
+
+/// A bucket is low once its balance falls under this share of the units credited
+const LOW_BALANCE_RATIO: f64 = 0.2;
+
+/// Most units a non operator may transfer at once when `config:transfer_max_units` is not set
+const DEFAULT_TRANSFER_MAX_UNITS: f64 = 1000.0;
+
+/// First period looked at for the ledgers written before their periods were kept, when `config:ledger_start` is not set
+const DEFAULT_LEDGER_START: &str = "2020-01";
+
+const CUSTOMERS_ACTOR: &str = "customers";
+
+// natively only the message dispatch is derived : the wasm exports would clash once linked with the other actors
+#[cfg_attr(target_arch = "wasm32", derive(Actor))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(wasmbus_rpc::provider::prelude::Provider))]
+#[derive(Debug, HealthResponder)]
+#[services(Actor, ServiceVendors, Invoices)]
+pub struct ServiceVendorsActor {
+    lattice: Box<dyn Lattice>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for ServiceVendorsActor {
+    fn default() -> Self {
+        Self::new(shared_bucket::lattice::Host::default())
+    }
+}
+
+/// Vendor as stored, its secret is only kept hashed
+#[derive(Debug, Deserialize, Serialize)]
+struct VendorRecord {
+    vendor: Vendor,
+    secret_hash: String,
+}
+
+fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+/// Caller of a vendor operation, owning the idempotency keys it sends
+fn vendor_caller(credentials: &VendorCredentials) -> String {
+    format!("vendor:{}", credentials.vendor)
+}
+
+/// Principal the customers actor is called with on behalf of the platform, only allowed to read the
+/// customers and groups of a tenant
+fn billing(tenant: Option<&str>) -> Principal {
+    Principal {
+        id: "service-vendors".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![BILLING_ROLE.to_string()]),
+        tenant: tenant.map(String::from),
+    }
+}
+
+/// Caller of a transfer, owning the idempotency keys it sends in its tenant
+fn principal_caller(principal: &Option<Principal>) -> String {
+    match principal {
+        Some(Principal { id, .. }) => format!("principal:{}", id),
+        None => "anonymous".to_string(),
+    }
+}
+
+/// Billing period (YYYY-MM, UTC) a timestamp falls in,
+/// see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn period(timestamp: &Timestamp) -> String {
+    let z = timestamp.sec.div_euclid(86_400) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}", year, month)
+}
+
+/// Whether a period is written YYYY-MM
+fn valid_period(period: &str) -> bool {
+    period.len() == 7 && period.char_indices().all(|(i, c)| if i == 4 { c == '-' } else { c.is_ascii_digit() })
+}
+
+/// Period following a valid one
+fn next_period(period: &str) -> String {
+    let (year, month) = (period[..4].parse::<u32>().unwrap_or(0), period[5..].parse::<u32>().unwrap_or(0));
+    if month >= 12 {
+        format!("{:04}-01", year + 1)
+    } else {
+        format!("{:04}-{:02}", year, month + 1)
+    }
+}
+
+impl ServiceVendorsActor {
+    pub fn new<L: Lattice + 'static>(lattice: L) -> Self {
+        ServiceVendorsActor { lattice: Box::new(lattice) }
+    }
+
+    fn keys(principal: &Option<Principal>) -> RpcResult<Keys> {
+        Keys::of_principal(principal).map_err(|e| RpcError::Other(e.to_string()))
+    }
+
+    /// Keys of the tenant the vendor serves
+    fn vendor_keys(record: &VendorRecord) -> RpcResult<Keys> {
+        Keys::of(record.vendor.tenant.as_deref()).map_err(|e| RpcError::Other(e.to_string()))
+    }
+
+    fn kv(&self) -> RpcResult<KeyValueSender<Link>> {
+        Ok(KeyValueSender::via(self.lattice.provider(KEYVALUE)?))
+    }
+
+    async fn generate_guid(&self, ctx: &Context) -> RpcResult<String> {
+        NumberGenSender::via(self.lattice.provider(NUMBERGEN)?).generate_guid(ctx).await
+    }
+
+    async fn find_vendor(&self, ctx: &Context, id: &str) -> anyhow::Result<Option<VendorRecord>> {
+        match self.kv()?.get(ctx, &format!("vendor:{}", id)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for vendor '{}' : {:?}", id, e)
+        }
+    }
+
+    async fn service_owner(&self, ctx: &Context, service: &str) -> anyhow::Result<Option<String>> {
+        match self.kv()?.get(ctx, &format!("service_owner:{}", service)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(value)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for owner of service '{}' : {:?}", service, e)
+        }
+    }
+
+    async fn register_vendor_services(&self, ctx: &Context, vendor: &Vendor) -> anyhow::Result<RegisterVendorReply> {
+        let id = match &vendor.id {
+            Some(id) if !id.is_empty() => id.clone(),
+            _ => self.generate_guid(ctx).await?,
+        };
+        if self.find_vendor(ctx, &id).await?.is_some() {
+            bail!("Vendor '{}' already exists", id);
+        }
+        for service in &vendor.services {
+            if let Some(owner) = self.service_owner(ctx, service).await? {
+                bail!("Service '{}' is already owned by vendor '{}'", service, owner);
+            }
+        }
+
+        let secret = self.generate_guid(ctx).await?;
+        info!("Registering vendor {} ({})", id, vendor.name);
+        let record = VendorRecord {
+            vendor: Vendor { id: Some(id.clone()), ..vendor.clone() },
+            secret_hash: hash_secret(&secret),
+        };
+        let request = SetRequest {
+            key: format!("vendor:{}", id),
+            value: serde_json::to_string(&record)?,
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request).await?;
+
+        for service in &vendor.services {
+            let request = SetRequest {
+                key: format!("service_owner:{}", service),
+                value: id.clone(),
+                expires: 0,
+            };
+            self.kv()?.set(ctx, &request).await?;
+        }
+
+        Ok(RegisterVendorReply { success: true, id, secret: Some(secret) })
+    }
+
+    async fn save_vendor(&self, ctx: &Context, record: &VendorRecord) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: format!("vendor:{}", record.vendor.id.as_deref().unwrap_or_default()),
+            value: serde_json::to_string(record)?,
+            expires: 0,
+        };
+
+        self.kv()?.set(ctx, &request).await?;
+
+        Ok(())
+    }
+
+    fn access_denied(credentials: &VendorCredentials, reason: &str) -> RpcError {
+        RpcError::Other(format!("{} : vendor '{}' {}", ACCESS_DENIED, credentials.vendor, reason))
+    }
+
+    /// Checks the vendor credentials
+    async fn authenticate_vendor(&self, ctx: &Context, credentials: &VendorCredentials) -> RpcResult<VendorRecord> {
+        let record = match self.find_vendor(ctx, &credentials.vendor).await {
+            Ok(Some(record)) => record,
+            Ok(None) => return Err(Self::access_denied(credentials, "is unknown")),
+            Err(e) => return Err(RpcError::Other(format!("{}", e))),
+        };
+        if record.secret_hash != hash_secret(&credentials.secret) {
+            return Err(Self::access_denied(credentials, "sent invalid credentials"));
+        }
+
+        Ok(record)
+    }
+
+    /// Checks the credentials and that the vendor owns the service
+    async fn check_vendor(&self, ctx: &Context, credentials: &VendorCredentials, service: &Service) -> RpcResult<VendorRecord> {
+        let record = self.authenticate_vendor(ctx, credentials).await?;
+        Self::check_owner(credentials, &record, service)?;
+
+        Ok(record)
+    }
+
+    fn check_owner(credentials: &VendorCredentials, record: &VendorRecord, service: &Service) -> RpcResult<()> {
+        if !record.vendor.services.contains(&service.id) {
+            return Err(Self::access_denied(credentials, &format!("does not own service '{}'", service.id)));
+        }
+
+        Ok(())
+    }
+
+    async fn find_service(&self, ctx: &Context, id: &str) -> anyhow::Result<Option<CatalogService>> {
+        match self.kv()?.get(ctx, &format!("service:{}", id)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for service '{}' : {:?}", id, e)
+        }
+    }
+
+    async fn save_service(&self, ctx: &Context, service: &CatalogService) -> anyhow::Result<()> {
+        if service.unit_price < 0.0 || service.conversion_rate <= 0.0 {
+            bail!("Invalid price {} or conversion rate {} for service '{}'", service.unit_price, service.conversion_rate, service.id);
+        }
+
+        let request = SetRequest {
+            key: format!("service:{}", service.id),
+            value: serde_json::to_string(service)?,
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request).await?;
+        self.kv()?.set_add(ctx, &SetAddRequest {
+            set_name: "services".to_string(),
+            value: service.id.clone(),
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Adds a service to the catalog, claiming the service id for the vendor when it is unowned
+    async fn register_catalog_service(&self, ctx: &Context, mut record: VendorRecord, service: &CatalogService) -> anyhow::Result<()> {
+        if self.find_service(ctx, &service.id).await?.is_some() {
+            bail!("Service '{}' is already in the catalog", service.id);
+        }
+
+        let vendor = record.vendor.id.clone().unwrap_or_default();
+        if !record.vendor.services.contains(&service.id) {
+            if let Some(owner) = self.service_owner(ctx, &service.id).await? {
+                bail!("Service '{}' is already owned by vendor '{}'", service.id, owner);
+            }
+            record.vendor.services.push(service.id.clone());
+            self.save_vendor(ctx, &record).await?;
+            let request = SetRequest {
+                key: format!("service_owner:{}", service.id),
+                value: vendor.clone(),
+                expires: 0,
+            };
+            self.kv()?.set(ctx, &request).await?;
+        }
+
+        info!("Registering service '{}' of vendor {}", service.id, vendor);
+        self.save_service(ctx, &CatalogService { vendor: Some(vendor), ..service.clone() }).await
+    }
+
+    async fn delete_catalog_service(&self, ctx: &Context, id: &str) -> anyhow::Result<bool> {
+        if self.find_service(ctx, id).await?.is_none() {
+            return Ok(false);
+        }
+
+        self.kv()?.del(ctx, &format!("service:{}", id)).await?;
+        self.kv()?.set_del(ctx, &SetDelRequest {
+            set_name: "services".to_string(),
+            value: id.to_string(),
+        }).await?;
+
+        Ok(true)
+    }
+
+    async fn list_catalog_services(&self, ctx: &Context) -> anyhow::Result<CatalogServices> {
+        let mut services = Vec::new();
+        for id in self.kv()?.set_query(ctx, "services").await? {
+            if let Some(service) = self.find_service(ctx, &id).await? {
+                services.push(service);
+            }
+        }
+        services.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(services)
+    }
+
+    async fn find_bucket(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<Option<Bucket>> {
+        match self.kv()?.get(ctx, &keys.bucket(group)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for bucket '{}' : {:?}", group, e)
+        }
+    }
+
+    async fn save_bucket(&self, ctx: &Context, keys: &Keys, bucket: &Bucket) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: keys.bucket(&bucket.group),
+            value: serde_json::to_string(bucket)?,
+            expires: 0,
+        };
+
+        self.kv()?.set(ctx, &request).await?;
+
+        Ok(())
+    }
+
+    async fn debit(&self, ctx: &Context, keys: &Keys, usage: &ServiceUsage) -> anyhow::Result<AuthorizeServiceUsageReply> {
+        let service = match self.find_service(ctx, &usage.service.id).await? {
+            Some(service) => service,
+            None => bail!("Service '{}' is not in the catalog", usage.service.id),
+        };
+        let mut bucket = match self.find_bucket(ctx, keys, &usage.group).await? {
+            Some(bucket) => bucket,
+            None => return Ok(AuthorizeServiceUsageReply { authorized: false, balance: 0.0 }),
+        };
+        if bucket.unit.as_ref().is_some_and(|unit| *unit != service.bucket_unit) {
+            info!("Service '{}' draws from {} buckets, bucket '{}' is in {:?}", service.id, service.bucket_unit, usage.group, bucket.unit);
+            return Ok(AuthorizeServiceUsageReply { authorized: false, balance: bucket.balance });
+        }
+
+        let period_key = keys.service_usage(&usage.group, &service.id, &period(&usage.timestamp));
+        let period_units = match self.kv()?.get(ctx, &period_key).await {
+            Ok(GetResponse { exists: true, value }) => value.parse().unwrap_or(0.0),
+            _ => 0.0,
+        };
+        let units = match rating::rate(usage, &service, period_units) {
+            Ok(units) => units,
+            Err(e) => bail!("Error rating usage of service '{}' : {}", service.id, e),
+        };
+        if bucket.balance < units {
+            info!("Usage of {} units refused for group '{}', balance is {}", units, usage.group, bucket.balance);
+            return Ok(AuthorizeServiceUsageReply { authorized: false, balance: bucket.balance });
+        }
+
+        let previous = bucket.balance;
+        bucket.balance -= units;
+        self.save_bucket(ctx, keys, &bucket).await?;
+
+        // units of service consumed in the period, for the tiered and volume tariffs
+        let request = SetRequest {
+            key: period_key,
+            value: (period_units + usage.units).to_string(),
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request).await?;
+
+        self.record(ctx, keys, &usage.group, &LedgerEntry {
+            kind: invoice::USAGE.to_string(),
+            service: service.id.clone(),
+            client: Some(usage.client.clone()),
+            unit: Some(service.unit.clone()),
+            units: Some(usage.units),
+            unit_price: Some(service.unit_price),
+            bucket_units: units,
+            timestamp: usage.timestamp,
+            ..Default::default()
+        }).await?;
+
+        let event = UsageEvent {
+            customer: Some(usage.client.clone()),
+            group: Some(usage.group.clone()),
+            service: Some(usage.service.id.clone()),
+            units: Some(units),
+            balance: Some(bucket.balance),
+            ..Default::default()
+        };
+
+        let low = bucket.credited * LOW_BALANCE_RATIO;
+        let mut event_types = vec!["bucket.debited"];
+        if previous >= low && bucket.balance < low {
+            event_types.push("bucket.low");
+        }
+        if previous > 0.0 && bucket.balance <= 0.0 {
+            event_types.push("bucket.exhausted");
+        }
+        for event_type in event_types {
+            let event = UsageEvent { event_type: event_type.to_string(), ..event.clone() };
+            if let Err(e) = self.publish(ctx, keys, event).await {
+                error!("Error publishing {} event : {}", event_type, e);
+            }
+        }
+
+        if let Err(e) = self.check_thresholds(ctx, keys, &bucket, &usage.timestamp).await {
+            error!("Error checking thresholds of bucket '{}' : {}", usage.group, e);
+        }
+
+        Ok(AuthorizeServiceUsageReply { authorized: true, balance: bucket.balance })
+    }
+
+    async fn credit(&self, ctx: &Context, keys: &Keys, order: &ServiceOrder) -> anyhow::Result<BuyServiceReply> {
+        if order.amount <= 0.0 {
+            bail!("Invalid order amount {}", order.amount);
+        }
+        let service = match self.find_service(ctx, &order.service.id).await? {
+            Some(service) => service,
+            None => bail!("Service '{}' is not in the catalog", order.service.id),
+        };
+
+        let mut bucket = self.find_bucket(ctx, keys, &order.group).await?.unwrap_or_else(|| Bucket {
+            group: order.group.clone(),
+            balance: 0.0,
+            credited: 0.0,
+            unit: None,
+        });
+        match &bucket.unit {
+            Some(unit) if *unit != service.bucket_unit => {
+                bail!("Service '{}' sells {}, bucket '{}' is in {}", service.id, service.bucket_unit, order.group, unit)
+            },
+            Some(_) => {},
+            None => bucket.unit = Some(service.bucket_unit.clone()),
+        }
+
+        // price of the bucket units at the price of the service units they convert to
+        let price = tax::round(order.amount / service.conversion_rate * service.unit_price);
+        let tax_rate = match &order.customer {
+            Some(customer) => match self.tax_rate(ctx, &self.tax_rules(ctx).await?, keys.tenant(), customer).await? {
+                Some(rate) => rate,
+                // taxed at the `*` rule instead of the rule of its location, the order is refused
+                None => bail!("Customer '{}' is not found in tenant {:?}, its tax rate is unknown", customer, keys.tenant()),
+            },
+            None => tax::rate(&self.tax_rules(ctx).await?, None, None),
+        };
+        let taxes = tax::taxes(price, tax_rate);
+
+        let id = self.generate_guid(ctx).await?;
+        info!("Creating order with id {}", id);
+        let stored = ServiceOrder {
+            credentials: VendorCredentials { secret: "".to_string(), ..order.credentials.clone() },
+            ..order.clone()
+        };
+        let request = SetRequest {
+            key: keys.order(&id),
+            value: serde_json::to_string(&stored)?,
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request).await?;
+
+        bucket.balance += order.amount;
+        bucket.credited += order.amount;
+        self.save_bucket(ctx, keys, &bucket).await?;
+
+        self.record(ctx, keys, &order.group, &LedgerEntry {
+            kind: invoice::ORDER.to_string(),
+            service: service.id.clone(),
+            order: Some(id.clone()),
+            bucket_units: order.amount,
+            timestamp: order.timestamp,
+            ..Default::default()
+        }).await?;
+
+        let event = UsageEvent {
+            event_type: "order.confirmed".to_string(),
+            group: Some(order.group.clone()),
+            service: Some(order.service.id.clone()),
+            order: Some(id.clone()),
+            units: Some(order.amount),
+            balance: Some(bucket.balance),
+            ..Default::default()
+        };
+        if let Err(e) = self.publish(ctx, keys, event).await {
+            error!("Error publishing order confirmed event : {}", e);
+        }
+
+        Ok(BuyServiceReply {
+            success: true,
+            id,
+            balance: bucket.balance,
+            price: Some(price),
+            taxes: Some(taxes),
+            total: Some(tax::round(price + taxes)),
+        })
+    }
+
+    async fn find_order(&self, ctx: &Context, keys: &Keys, id: &str) -> anyhow::Result<Option<ServiceOrder>> {
+        match self.kv()?.get(ctx, &keys.order(id)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for order '{}' : {:?}", id, e)
+        }
+    }
+
+    /// Debits the bucket for the refunded units of an order. When the units were already consumed,
+    /// refunds only debit what is left in the bucket, unless the `negative` policy is configured,
+    /// chargebacks always debit all the units requested.
+    async fn refund(&self, ctx: &Context, keys: &Keys, request: &RefundRequest, order: &ServiceOrder) -> anyhow::Result<RefundReply> {
+        let refunded_key = keys.order_refunded(&request.order);
+        let already_refunded: f64 = match self.kv()?.get(ctx, &refunded_key).await {
+            Ok(GetResponse { exists: true, value }) => value.parse().unwrap_or(0.0),
+            _ => 0.0,
+        };
+        let refundable = order.amount - already_refunded;
+        let requested = request.amount.unwrap_or(refundable);
+        if requested <= 0.0 || requested > refundable {
+            bail!("Cannot refund {} units of order '{}', {} are refundable", requested, request.order, refundable);
+        }
+
+        let chargeback = request.reason.as_deref() == Some("chargeback");
+        let negative_policy = match self.kv()?.get(ctx, "config:refund_policy").await {
+            Ok(GetResponse { exists: true, value }) => value == "negative",
+            _ => false,
+        };
+
+        let mut bucket = match self.find_bucket(ctx, keys, &order.group).await? {
+            Some(bucket) => bucket,
+            None => bail!("No bucket for group '{}'", order.group),
+        };
+        let refunded = if chargeback || negative_policy {
+            requested
+        } else {
+            requested.min(bucket.balance.max(0.0))
+        };
+        if refunded <= 0.0 {
+            info!("Nothing left to refund of order '{}', bucket '{}' balance is {}", request.order, order.group, bucket.balance);
+            return Ok(RefundReply { success: false, refunded: 0.0, balance: bucket.balance });
+        }
+
+        info!("Refunding {} units of order '{}'", refunded, request.order);
+        bucket.balance -= refunded;
+        bucket.credited -= refunded;
+        self.save_bucket(ctx, keys, &bucket).await?;
+
+        let request_refunded = SetRequest {
+            key: refunded_key,
+            value: (already_refunded + refunded).to_string(),
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request_refunded).await?;
+
+        self.record(ctx, keys, &order.group, &LedgerEntry {
+            kind: invoice::REFUND.to_string(),
+            service: order.service.id.clone(),
+            order: Some(request.order.clone()),
+            bucket_units: refunded,
+            timestamp: request.timestamp,
+            ..Default::default()
+        }).await?;
+
+        let event = UsageEvent {
+            event_type: "order.refunded".to_string(),
+            group: Some(order.group.clone()),
+            service: Some(order.service.id.clone()),
+            order: Some(request.order.clone()),
+            units: Some(refunded),
+            balance: Some(bucket.balance),
+            ..Default::default()
+        };
+        if let Err(e) = self.publish(ctx, keys, event).await {
+            error!("Error publishing order refunded event : {}", e);
+        }
+        if let Err(e) = self.check_thresholds(ctx, keys, &bucket, &request.timestamp).await {
+            error!("Error checking thresholds of bucket '{}' : {}", bucket.group, e);
+        }
+
+        Ok(RefundReply { success: true, refunded, balance: bucket.balance })
+    }
+
+    async fn save_transfer(&self, ctx: &Context, keys: &Keys, transfer: &Transfer) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: keys.transfer(&transfer.id),
+            value: serde_json::to_string(transfer)?,
+            expires: 0,
+        };
+
+        self.kv()?.set(ctx, &request).await?;
+
+        Ok(())
+    }
+
+    /// Moves units between two buckets. The transfer is journaled as pending before any change :
+    /// when a step fails, both buckets are restored, the ledger entries recorded are dropped and the
+    /// transfer is marked as failed. Entries left by a transfer which did not complete are ignored.
+    async fn transfer(&self, ctx: &Context, keys: &Keys, request: &TransferRequest) -> anyhow::Result<TransferReply> {
+        if request.from == request.to {
+            bail!("Cannot transfer units from group '{}' to itself", request.from);
+        }
+        if request.amount <= 0.0 || !request.amount.is_finite() {
+            bail!("Invalid transfer amount {}", request.amount);
+        }
+        if !request.principal.as_ref().is_some_and(is_operator) {
+            let max_units = match self.kv()?.get(ctx, "config:transfer_max_units").await {
+                Ok(GetResponse { exists: true, value }) => value.parse().unwrap_or(DEFAULT_TRANSFER_MAX_UNITS),
+                _ => DEFAULT_TRANSFER_MAX_UNITS,
+            };
+            if request.amount > max_units {
+                bail!("Transfers are limited to {} units", max_units);
+            }
+        }
+
+        if !self.group_exists(ctx, keys, &request.to).await? {
+            bail!("No group '{}' in tenant {:?}", request.to, keys.tenant());
+        }
+
+        let source = match self.find_bucket(ctx, keys, &request.from).await? {
+            Some(bucket) => bucket,
+            None => bail!("No bucket for group '{}'", request.from),
+        };
+        if source.balance < request.amount {
+            bail!("Bucket '{}' balance {} is lower than {}", request.from, source.balance, request.amount);
+        }
+        let destination = self.find_bucket(ctx, keys, &request.to).await?.unwrap_or_else(|| Bucket {
+            group: request.to.clone(),
+            balance: 0.0,
+            credited: 0.0,
+            unit: source.unit.clone(),
+        });
+        if destination.unit.is_some() && source.unit.is_some() && destination.unit != source.unit {
+            bail!("Bucket '{}' is in {:?}, bucket '{}' in {:?}", request.from, source.unit, request.to, destination.unit);
+        }
+
+        let id = self.generate_guid(ctx).await?;
+        info!("Transferring {} units from '{}' to '{}' ({})", request.amount, request.from, request.to, id);
+        let mut transfer = Transfer {
+            id: id.clone(),
+            from: request.from.clone(),
+            to: request.to.clone(),
+            amount: request.amount,
+            reason: request.reason.clone(),
+            timestamp: request.timestamp,
+            by: request.principal.as_ref().map(|p| p.id.clone()),
+            status: "pending".to_string(),
+        };
+        self.save_transfer(ctx, keys, &transfer).await?;
+        for group in [&request.from, &request.to] {
+            self.kv()?.list_add(ctx, &ListAddRequest {
+                list_name: keys.transfers(group),
+                value: id.clone(),
+            }).await?;
+        }
+
+        let debited = Bucket { balance: source.balance - request.amount, credited: source.credited - request.amount, ..source.clone() };
+        let credited = Bucket { balance: destination.balance + request.amount, credited: destination.credited + request.amount, ..destination.clone() };
+        let entry = LedgerEntry {
+            service: "".to_string(),
+            transfer: Some(id.clone()),
+            bucket_units: request.amount,
+            timestamp: request.timestamp,
+            ..Default::default()
+        };
+        let out = LedgerEntry { kind: invoice::TRANSFER_OUT.to_string(), ..entry.clone() };
+        let into = LedgerEntry { kind: invoice::TRANSFER_IN.to_string(), ..entry };
+        let result = async {
+            self.save_bucket(ctx, keys, &debited).await?;
+            self.save_bucket(ctx, keys, &credited).await?;
+            self.record(ctx, keys, &request.from, &out).await?;
+            self.record(ctx, keys, &request.to, &into).await
+        }.await;
+
+        if let Err(e) = result {
+            error!("Error transferring units ({}), restoring the buckets : {}", id, e);
+            // every step is compensated even when another one fails, the entries left being ignored
+            // as long as the transfer is not completed
+            for (group, bucket) in [(&request.from, &source), (&request.to, &destination)] {
+                if let Err(e) = self.save_bucket(ctx, keys, bucket).await {
+                    error!("Error restoring bucket '{}' of transfer {} : {}", group, id, e);
+                }
+            }
+            for (group, entry) in [(&request.from, &out), (&request.to, &into)] {
+                if let Err(e) = self.unrecord(ctx, keys, group, entry).await {
+                    error!("Error dropping ledger entry of group '{}' of transfer {} : {}", group, id, e);
+                }
+            }
+            transfer.status = "failed".to_string();
+            if let Err(e) = self.save_transfer(ctx, keys, &transfer).await {
+                error!("Error marking transfer {} as failed : {}", id, e);
+            }
+            return Err(e);
+        }
+
+        transfer.status = "completed".to_string();
+        self.save_transfer(ctx, keys, &transfer).await?;
+        if let Err(e) = self.check_thresholds(ctx, keys, &debited, &request.timestamp).await {
+            error!("Error checking thresholds of bucket '{}' : {}", debited.group, e);
+        }
+
+        Ok(TransferReply { success: true, id, from_balance: debited.balance, to_balance: credited.balance })
+    }
+
+    async fn list_group_transfers(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<Transfers> {
+        let ids = self.kv()?.list_range(ctx, &ListRangeRequest {
+            list_name: keys.transfers(group),
+            start: 0,
+            stop: -1,
+        }).await?;
+
+        let mut transfers = Vec::new();
+        for id in ids {
+            if let Ok(GetResponse { exists: true, value }) = self.kv()?.get(ctx, &keys.transfer(&id)).await {
+                transfers.push(serde_json::from_str(&value)?);
+            }
+        }
+
+        Ok(transfers)
+    }
+
+    async fn tax_rules(&self, ctx: &Context) -> anyhow::Result<Vec<TaxRule>> {
+        match self.kv()?.get(ctx, "config:tax_rules").await {
+            Ok(GetResponse { exists: true, value }) => Ok(serde_json::from_str(&value)?),
+            Ok(GetResponse { exists: false, .. }) => Ok(Vec::new()),
+            Err(e) => bail!("Error reading tax rules : {:?}", e)
+        }
+    }
+
+    /// Tax rate of the location of a customer of a tenant (of the platform without tenant), `None`
+    /// when the customer is unknown
+    async fn tax_rate(&self, ctx: &Context, rules: &[TaxRule], tenant: Option<&str>, customer: &str) -> anyhow::Result<Option<f64>> {
+        let reply = CustomersSender::via(self.lattice.actor(CUSTOMERS_ACTOR)?)
+            .find_customer(ctx, &FindCustomerRequest { id: customer.to_string(), principal: Some(billing(tenant)) })
+            .await?;
+
+        Ok(reply.customer.map(|customer| tax::rate(rules, customer.country.as_deref(), customer.region.as_deref())))
+    }
+
+    /// Whether a group of a tenant exists, the groups of the other tenants being not found
+    async fn group_exists(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<bool> {
+        let reply = CustomerGroupsSender::via(self.lattice.actor(CUSTOMERS_ACTOR)?)
+            .find_customer_group(ctx, &FindCustomerGroupRequest { id: group.to_string(), principal: Some(billing(keys.tenant())) })
+            .await?;
+
+        Ok(reply.group.is_some())
+    }
+
+    /// Appends an entry to the ledger of its period, the periods of a group being kept in a set
+    async fn record(&self, ctx: &Context, keys: &Keys, group: &str, entry: &LedgerEntry) -> anyhow::Result<()> {
+        self.kv()?.list_add(ctx, &ListAddRequest {
+            list_name: keys.ledger(group, &period(&entry.timestamp)),
+            value: serde_json::to_string(entry)?,
+        }).await?;
+        self.kv()?.set_add(ctx, &SetAddRequest {
+            set_name: keys.ledger_periods(group),
+            value: period(&entry.timestamp),
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Drops an entry appended to a ledger, if it was
+    async fn unrecord(&self, ctx: &Context, keys: &Keys, group: &str, entry: &LedgerEntry) -> anyhow::Result<()> {
+        self.kv()?.list_del(ctx, &ListDelRequest {
+            list_name: keys.ledger(group, &period(&entry.timestamp)),
+            value: serde_json::to_string(entry)?,
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Whether a ledger entry counts : the entries of a transfer only count once it completed.
+    /// Restored entries have no transfer journaled, they were exported once completed.
+    async fn committed(&self, ctx: &Context, keys: &Keys, entry: &LedgerEntry) -> anyhow::Result<bool> {
+        let id = match &entry.transfer {
+            Some(id) => id,
+            None => return Ok(true),
+        };
+        match self.kv()?.get(ctx, &keys.transfer(id)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(serde_json::from_str::<Transfer>(&value)?.status == "completed"),
+            Ok(GetResponse { exists: false, .. }) => Ok(true),
+            Err(e) => bail!("Error searching for transfer '{}' : {:?}", id, e)
+        }
+    }
+
+    /// Period set in a config key
+    async fn config_period(&self, ctx: &Context, key: &str) -> anyhow::Result<Option<String>> {
+        match self.kv()?.get(ctx, key).await {
+            Ok(GetResponse { exists: true, value }) if valid_period(&value) => Ok(Some(value)),
+            Ok(GetResponse { exists: true, value }) => bail!("Invalid period '{}' in {}, expected YYYY-MM", value, key),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error reading {} : {:?}", key, e)
+        }
+    }
+
+    /// Periods of the ledgers of a group, in order
+    async fn ledger_periods(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<Vec<String>> {
+        let mut periods = self.kv()?.set_query(ctx, &keys.ledger_periods(group)).await?;
+        periods.sort_unstable();
+        Ok(periods)
+    }
+
+    /// Keeps the periods of the ledgers of a group written before their periods were kept, from
+    /// `config:ledger_start` to `config:ledger_end` (the latest period kept when not set). Returns
+    /// whether periods were added.
+    async fn migrate_ledger(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<bool> {
+        let set_name = keys.ledger_periods(group);
+        let periods = self.kv()?.set_query(ctx, &set_name).await?;
+        let start = self.config_period(ctx, "config:ledger_start").await?.unwrap_or_else(|| DEFAULT_LEDGER_START.to_string());
+        let end = match self.config_period(ctx, "config:ledger_end").await? {
+            Some(end) => end,
+            None => match periods.iter().max() {
+                Some(end) => end.clone(),
+                // without an end the group has no ledger kept yet
+                None => return Ok(false),
+            },
+        };
+
+        let mut migrated = false;
+        let mut period = start;
+        while period <= end {
+            let list_name = keys.ledger(group, &period);
+            if !periods.contains(&period) && !self.kv()?.list_range(ctx, &ListRangeRequest { list_name, start: 0, stop: 0 }).await?.is_empty() {
+                info!("Keeping period {} of the ledger of group '{}'", period, group);
+                self.kv()?.set_add(ctx, &SetAddRequest { set_name: set_name.clone(), value: period.clone() }).await?;
+                migrated = true;
+            }
+            period = next_period(&period);
+        }
+
+        Ok(migrated)
+    }
+
+    /// Bucket of a group then its ledger entries, period after period. At most `limit` entries
+    /// are read after the cursor (`{period}:{index}`), the bucket is only in the first page.
+    async fn export_group_bucket(&self, ctx: &Context, keys: &Keys, request: &ExportBucketRequest) -> anyhow::Result<ExportBucketReply> {
+        let group = &request.group;
+        let after = match request.after.as_deref() {
+            None => None,
+            Some(after) => match after.split_once(':').map(|(period, index)| (period, index.parse::<usize>())) {
+                Some((period, Ok(index))) if valid_period(period) => Some((period, index)),
+                _ => bail!("Invalid cursor '{}'", after),
+            },
+        };
+
+        let mut records = Vec::new();
+        if after.is_none() {
+            if let Some(bucket) = self.find_bucket(ctx, keys, group).await? {
+                records.push(ExportedRecord { kind: "bucket".to_string(), bucket: Some(bucket), ..Default::default() });
+            }
+        }
+
+        let limit = request.limit as usize;
+        let mut read = 0;
+        for period in self.ledger_periods(ctx, keys, group).await? {
+            let start = match after {
+                Some((after, index)) if after == period => index,
+                Some((after, _)) if period.as_str() < after => continue,
+                _ => 0,
+            };
+            // an entry more than the page holds tells whether another page follows
+            let values = self.kv()?.list_range(ctx, &ListRangeRequest {
+                list_name: keys.ledger(group, &period),
+                start: start as i32,
+                stop: (start + limit - read) as i32,
+            }).await?;
+            for (index, value) in values.iter().enumerate() {
+                if read == limit {
+                    return Ok(ExportBucketReply { records, next: Some(format!("{}:{}", period, start + index)) });
+                }
+                read += 1;
+                let entry = GroupLedgerEntry { group: group.to_string(), entry: serde_json::from_str(value)? };
+                if self.committed(ctx, keys, &entry.entry).await? {
+                    records.push(ExportedRecord { kind: "ledgerEntry".to_string(), ledger_entry: Some(entry), ..Default::default() });
+                }
+            }
+        }
+
+        Ok(ExportBucketReply { records, next: None })
+    }
+
+    /// Writes exported buckets and appends exported entries to their ledgers
+    async fn restore_group_buckets(&self, ctx: &Context, keys: &Keys, records: &[ExportedRecord]) -> anyhow::Result<u32> {
+        for record in records {
+            match (record.kind.as_str(), record) {
+                ("bucket", ExportedRecord { bucket: Some(bucket), .. }) => self.save_bucket(ctx, keys, bucket).await?,
+                ("ledgerEntry", ExportedRecord { ledger_entry: Some(entry), .. }) => self.record(ctx, keys, &entry.group, &entry.entry).await?,
+                (kind, _) => bail!("A {} record may not be restored by the service vendors actor", kind),
+            }
+        }
+
+        Ok(records.len() as u32)
+    }
+
+    async fn find_invoice(&self, ctx: &Context, keys: &Keys, group: &str, period: &str) -> anyhow::Result<Option<Invoice>> {
+        match self.kv()?.get(ctx, &keys.invoice(group, period)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for invoice of group '{}' for {} : {:?}", group, period, e)
+        }
+    }
+
+    /// Builds the invoice from the ledger of the period. Numbers are sequential in the period,
+    /// a regenerated invoice keeps its number.
+    async fn build_invoice(&self, ctx: &Context, keys: &Keys, request: &InvoiceRequest) -> anyhow::Result<Invoice> {
+        if !valid_period(&request.period) {
+            bail!("Invalid period '{}', expected YYYY-MM", request.period);
+        }
+
+        let number = match self.find_invoice(ctx, keys, &request.group, &request.period).await? {
+            Some(invoice) => invoice.number,
+            None => {
+                let sequence = self.kv()?.increment(ctx, &IncrementRequest {
+                    key: keys.invoice_sequence(&request.period),
+                    value: 1,
+                }).await?;
+                format!("INV-{}-{:06}", request.period.replace('-', ""), sequence)
+            },
+        };
+
+        let mut entries = Vec::new();
+        for value in self.kv()?.list_range(ctx, &ListRangeRequest {
+            list_name: keys.ledger(&request.group, &request.period),
+            start: 0,
+            stop: -1,
+        }).await? {
+            let entry = serde_json::from_str::<LedgerEntry>(&value)?;
+            if self.committed(ctx, keys, &entry).await? {
+                entries.push(entry);
+            }
+        }
+
+        let rules = self.tax_rules(ctx).await?;
+        let mut rates = HashMap::new();
+        for entry in &entries {
+            if let Some(client) = &entry.client {
+                if !rates.contains_key(client) {
+                    rates.insert(client.clone(), self.tax_rate(ctx, &rules, keys.tenant(), client).await?);
+                }
+            }
+        }
+
+        info!("Generating invoice {} of group '{}' for {}", number, request.group, request.period);
+        let invoice = invoice::build(number, &request.group, &request.period, &entries, |member| {
+            rates.get(member).copied().flatten()
+        }, tax::rate(&rules, None, None));
+        let set = SetRequest {
+            key: keys.invoice(&request.group, &request.period),
+            value: serde_json::to_string(&invoice)?,
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &set).await?;
+
+        Ok(invoice)
+    }
+
+    async fn publish(&self, ctx: &Context, keys: &Keys, event: UsageEvent) -> anyhow::Result<()> {
+        let event = UsageEvent {
+            schema_version: USAGE_EVENT_SCHEMA_VERSION.to_string(),
+            id: self.generate_guid(ctx).await?,
+            tenant: keys.tenant().map(String::from),
+            ..event
+        };
+
+        MessagingSender::via(self.lattice.provider(MESSAGING)?).publish(ctx, &PubMessage {
+            subject: format!("{}.{}", USAGE_EVENT_SUBJECT_PREFIX, event.event_type),
+            reply_to: None,
+            body: serde_json::to_vec(&event)?,
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn find_thresholds(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<BucketThresholds> {
+        match self.kv()?.get(ctx, &keys.thresholds(group)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(serde_json::from_str(&value)?),
+            Ok(GetResponse { exists: false, .. }) => Ok(BucketThresholds {
+                group: group.to_string(),
+                thresholds: Vec::new(),
+            }),
+            Err(e) => bail!("Error searching for thresholds of bucket '{}' : {:?}", group, e)
+        }
+    }
+
+    async fn save_thresholds(&self, ctx: &Context, keys: &Keys, thresholds: &BucketThresholds) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: keys.thresholds(&thresholds.group),
+            value: serde_json::to_string(thresholds)?,
+            expires: 0,
+        };
+
+        self.kv()?.set(ctx, &request).await?;
+
+        Ok(())
+    }
+
+    /// Fires the thresholds reached by a debited bucket that did not already fire in the period of
+    /// the debit or a later one : a debit dated before the period a threshold last fired in does
+    /// not fire it again. The fired period is saved before publishing, so a threshold never fires
+    /// twice in a period.
+    async fn check_thresholds(&self, ctx: &Context, keys: &Keys, bucket: &Bucket, timestamp: &Timestamp) -> anyhow::Result<()> {
+        let mut thresholds = self.find_thresholds(ctx, keys, &bucket.group).await?;
+        let period = period(timestamp);
+        let used_percentage = if bucket.credited > 0.0 {
+            (bucket.credited - bucket.balance) / bucket.credited * 100.0
+        } else {
+            100.0
+        };
+
+        let mut reached = Vec::new();
+        for threshold in thresholds.thresholds.iter_mut() {
+            let is_reached = threshold.used_percentage.is_some_and(|p| used_percentage >= p)
+                || threshold.remaining.is_some_and(|r| bucket.balance <= r);
+            if is_reached && threshold.fired_period.as_ref().is_none_or(|fired| period > *fired) {
+                threshold.fired_period = Some(period.clone());
+                reached.push(threshold.id.clone());
+            }
+        }
+
+        if reached.is_empty() {
+            return Ok(());
+        }
+        self.save_thresholds(ctx, keys, &thresholds).await?;
+
+        for threshold in reached {
+            info!("Threshold '{}' reached for bucket '{}'", threshold, bucket.group);
+            let event = UsageEvent {
+                event_type: "bucket.threshold_reached".to_string(),
+                group: Some(bucket.group.clone()),
+                threshold: Some(threshold),
+                balance: Some(bucket.balance),
+                ..Default::default()
+            };
+            if let Err(e) = self.publish(ctx, keys, event).await {
+                error!("Error publishing threshold reached event : {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn idempotency_window(&self, ctx: &Context) -> anyhow::Result<u32> {
+        Ok(match self.kv()?.get(ctx, "config:idempotency_window").await {
+            Ok(GetResponse { exists: true, value }) => value.parse().unwrap_or(DEFAULT_IDEMPOTENCY_WINDOW),
+            _ => DEFAULT_IDEMPOTENCY_WINDOW,
+        })
+    }
+
+    /// Replies are saved under the caller sending the key, so that callers sending the same key
+    /// do not get each other's replies
+    async fn find_reply<T: DeserializeOwned>(&self, ctx: &Context, keys: &Keys, caller: &str, operation: &str, key: &str) -> anyhow::Result<Option<T>> {
+        match self.kv()?.get(ctx, &keys.idempotency(caller, operation, key)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for idempotency key '{}' : {:?}", key, e)
+        }
+    }
+
+    async fn save_reply<T: Serialize>(&self, ctx: &Context, keys: &Keys, caller: &str, operation: &str, key: &str, reply: &T) -> anyhow::Result<()> {
+        let request = SetRequest {
+            key: keys.idempotency(caller, operation, key),
+            value: serde_json::to_string(reply)?,
+            expires: self.idempotency_window(ctx).await?,
+        };
+
+        self.kv()?.set(ctx, &request).await?;
+
+        Ok(())
+    }
+}
+
+/// Implementation of ServiceVendors trait methods
+#[async_trait]
+impl ServiceVendors for ServiceVendorsActor {
+    async fn register_vendor(&self, ctx: &Context, arg: &Vendor) -> RpcResult<RegisterVendorReply> {
+        info!("register vendor");
+
+        match self.register_vendor_services(ctx, arg).await {
+            Ok(reply) => Ok(reply),
+            Err(e) => {
+                error!("Error registering vendor '{}' : {}", arg.name, e);
+                Ok(RegisterVendorReply { success: false, id: "".to_string(), secret: None })
+            },
+        }
+    }
+
+    async fn register_service(&self, ctx: &Context, arg: &CatalogServiceRequest) -> RpcResult<CatalogServiceReply> {
+        info!("register service");
+        let record = self.authenticate_vendor(ctx, &arg.credentials).await?;
+
+        match self.register_catalog_service(ctx, record, &arg.service).await {
+            Ok(()) => Ok(CatalogServiceReply { success: true }),
+            Err(e) => {
+                error!("Error registering service '{}' : {}", arg.service.id, e);
+                Ok(CatalogServiceReply { success: false })
+            },
+        }
+    }
+
+    async fn update_service(&self, ctx: &Context, arg: &CatalogServiceRequest) -> RpcResult<CatalogServiceReply> {
+        info!("update service");
+        let record = self.check_vendor(ctx, &arg.credentials, &Service { id: arg.service.id.clone() }).await?;
+
+        let service = CatalogService { vendor: record.vendor.id, ..arg.service.clone() };
+        match self.save_service(ctx, &service).await {
+            Ok(()) => Ok(CatalogServiceReply { success: true }),
+            Err(e) => {
+                error!("Error updating service '{}' : {}", arg.service.id, e);
+                Ok(CatalogServiceReply { success: false })
+            },
+        }
+    }
+
+    async fn delete_service(&self, ctx: &Context, arg: &ServiceRef) -> RpcResult<CatalogServiceReply> {
+        info!("delete service");
+        self.check_vendor(ctx, &arg.credentials, &Service { id: arg.id.clone() }).await?;
+
+        match self.delete_catalog_service(ctx, &arg.id).await {
+            Ok(success) => Ok(CatalogServiceReply { success }),
+            Err(e) => {
+                error!("Error deleting service '{}' : {}", arg.id, e);
+                Ok(CatalogServiceReply { success: false })
+            },
+        }
+    }
+
+    async fn get_service<TS: ToString + ?Sized + Sync>(&self, ctx: &Context, arg: &TS) -> RpcResult<GetServiceReply> {
+        self.find_service(ctx, &arg.to_string()).await
+            .map(|service| GetServiceReply { service })
+            .map_err(|e| RpcError::Other(format!("{}", e)))
+    }
+
+    async fn list_services(&self, ctx: &Context) -> RpcResult<CatalogServices> {
+        self.list_catalog_services(ctx).await
+            .map_err(|e| RpcError::Other(format!("{}", e)))
+    }
+
+    async fn authorize_service_usage(
+        &self,
+        ctx: &Context,
+        arg: &ServiceUsage,
+    ) -> RpcResult<AuthorizeServiceUsageReply> {
+        info!("authorize service usage");
+        let keys = &Self::vendor_keys(&self.check_vendor(ctx, &arg.credentials, &arg.service).await?)?;
+
+        if let Some(key) = &arg.idempotency_key {
+            if let Ok(Some(reply)) = self.find_reply(ctx, keys, &vendor_caller(&arg.credentials), "authorize_service_usage", key).await {
+                info!("Replaying service usage for idempotency key '{}'", key);
+                return Ok(reply);
+            }
+        }
+
+        match self.debit(ctx, keys, arg).await {
+            Ok(reply) => {
+                if let Some(key) = &arg.idempotency_key {
+                    if let Err(e) = self.save_reply(ctx, keys, &vendor_caller(&arg.credentials), "authorize_service_usage", key, &reply).await {
+                        error!("Error saving reply for idempotency key '{}' : {}", key, e);
+                    }
+                }
+                Ok(reply)
+            },
+            Err(e) => {
+                error!("Error debiting bucket '{}' : {}", arg.group, e);
+                Err(RpcError::Other(format!("Error debiting bucket '{}'", arg.group)))
+            },
+        }
+    }
+
+    async fn buy_service(&self, ctx: &Context, arg: &ServiceOrder) -> RpcResult<BuyServiceReply> {
+        info!("buy service");
+        let keys = &Self::vendor_keys(&self.check_vendor(ctx, &arg.credentials, &arg.service).await?)?;
+
+        if let Some(key) = &arg.idempotency_key {
+            if let Ok(Some(reply)) = self.find_reply(ctx, keys, &vendor_caller(&arg.credentials), "buy_service", key).await {
+                info!("Replaying service order for idempotency key '{}'", key);
+                return Ok(reply);
+            }
+        }
+
+        let reply = match self.credit(ctx, keys, arg).await {
+            Ok(reply) => {
+                info!("Order created : {}", reply.id);
+                if let Some(key) = &arg.idempotency_key {
+                    if let Err(e) = self.save_reply(ctx, keys, &vendor_caller(&arg.credentials), "buy_service", key, &reply).await {
+                        error!("Error saving reply for idempotency key '{}' : {}", key, e);
+                    }
+                }
+                reply
+            },
+            Err(e) => {
+                error!("Error buying service : {}", e);
+                BuyServiceReply {
+                    success: false,
+                    id: "".to_string(),
+                    balance: 0.0,
+                    ..Default::default()
+                }
+            },
+        };
+
+        Ok(reply)
+    }
+
+    async fn refund_order(&self, ctx: &Context, arg: &RefundRequest) -> RpcResult<RefundReply> {
+        info!("refund order");
+
+        // orders are looked up in the tenant of the vendor
+        let record = self.authenticate_vendor(ctx, &arg.credentials).await?;
+        let keys = &Self::vendor_keys(&record)?;
+        let order = match self.find_order(ctx, keys, &arg.order).await {
+            Ok(Some(order)) => order,
+            Ok(None) => return Ok(RefundReply { success: false, refunded: 0.0, balance: 0.0 }),
+            Err(e) => return Err(RpcError::Other(format!("{}", e))),
+        };
+        Self::check_owner(&arg.credentials, &record, &order.service)?;
+
+        if let Some(key) = &arg.idempotency_key {
+            if let Ok(Some(reply)) = self.find_reply(ctx, keys, &vendor_caller(&arg.credentials), "refund_order", key).await {
+                info!("Replaying refund for idempotency key '{}'", key);
+                return Ok(reply);
+            }
+        }
+
+        let reply = match self.refund(ctx, keys, arg, &order).await {
+            Ok(reply) => {
+                if let Some(key) = &arg.idempotency_key {
+                    if let Err(e) = self.save_reply(ctx, keys, &vendor_caller(&arg.credentials), "refund_order", key, &reply).await {
+                        error!("Error saving reply for idempotency key '{}' : {}", key, e);
+                    }
+                }
+                reply
+            },
+            Err(e) => {
+                error!("Error refunding order '{}' : {}", arg.order, e);
+                RefundReply { success: false, refunded: 0.0, balance: 0.0 }
+            },
+        };
+
+        Ok(reply)
+    }
+
+    async fn transfer_units(&self, ctx: &Context, arg: &TransferRequest) -> RpcResult<TransferReply> {
+        info!("transfer units");
+        let keys = &Self::keys(&arg.principal)?;
+
+        if let Some(key) = &arg.idempotency_key {
+            if let Ok(Some(reply)) = self.find_reply(ctx, keys, &principal_caller(&arg.principal), "transfer_units", key).await {
+                info!("Replaying transfer for idempotency key '{}'", key);
+                return Ok(reply);
+            }
+        }
+
+        let reply = match self.transfer(ctx, keys, arg).await {
+            Ok(reply) => {
+                if let Some(key) = &arg.idempotency_key {
+                    if let Err(e) = self.save_reply(ctx, keys, &principal_caller(&arg.principal), "transfer_units", key, &reply).await {
+                        error!("Error saving reply for idempotency key '{}' : {}", key, e);
+                    }
+                }
+                reply
+            },
+            Err(e) => {
+                error!("Error transferring units from '{}' to '{}' : {}", arg.from, arg.to, e);
+                TransferReply { success: false, id: "".to_string(), from_balance: 0.0, to_balance: 0.0 }
+            },
+        };
+
+        Ok(reply)
+    }
+
+    async fn list_transfers(&self, ctx: &Context, arg: &BucketRef) -> RpcResult<Transfers> {
+        self.list_group_transfers(ctx, &Self::keys(&arg.principal)?, &arg.group).await
+            .map_err(|e| RpcError::Other(format!("{}", e)))
+    }
+
+    async fn export_bucket(&self, ctx: &Context, arg: &ExportBucketRequest) -> RpcResult<ExportBucketReply> {
+        self.export_group_bucket(ctx, &Self::keys(&arg.principal)?, arg).await
+            .map_err(|e| RpcError::Other(format!("Error exporting bucket : {}", e)))
+    }
+
+    async fn restore_buckets(&self, ctx: &Context, arg: &RestoreBucketsRequest) -> RpcResult<u32> {
+        info!("restore {} bucket records", arg.records.len());
+        self.restore_group_buckets(ctx, &Self::keys(&arg.principal)?, &arg.records).await
+            .map_err(|e| RpcError::Other(format!("Error restoring buckets : {}", e)))
+    }
+
+    async fn migrate_ledgers(&self, ctx: &Context, arg: &MigrateLedgersRequest) -> RpcResult<MigrateRecordsReply> {
+        info!("migrate the ledgers of {} groups", arg.groups.len());
+        if !arg.principal.as_ref().is_some_and(is_operator) {
+            return Err(RpcError::Other(format!("{} : only operators migrate ledgers", ACCESS_DENIED)));
+        }
+        let keys = &Self::keys(&arg.principal)?;
+
+        let mut reply = MigrateRecordsReply::default();
+        for group in &arg.groups {
+            match self.migrate_ledger(ctx, keys, group).await {
+                Ok(true) => reply.migrated += 1,
+                Ok(false) => reply.current += 1,
+                Err(e) => {
+                    error!("Error migrating the ledgers of group '{}' : {}", group, e);
+                    reply.failed += 1;
+                },
+            }
+        }
+
+        Ok(reply)
+    }
+
+    async fn set_bucket_thresholds(
+        &self,
+        ctx: &Context,
+        arg: &SetBucketThresholdsRequest,
+    ) -> RpcResult<SetBucketThresholdsReply> {
+        info!("set bucket thresholds");
+        let keys = &Self::keys(&arg.principal)?;
+
+        let result = async {
+            // keep the fired period of the thresholds that are kept, so they do not fire again
+            let existing = self.find_thresholds(ctx, keys, &arg.thresholds.group).await?;
+            let mut thresholds = arg.thresholds.clone();
+            for threshold in thresholds.thresholds.iter_mut() {
+                threshold.fired_period = existing.thresholds.iter()
+                    .find(|t| t.id == threshold.id)
+                    .and_then(|t| t.fired_period.clone());
+            }
+            self.save_thresholds(ctx, keys, &thresholds).await
+        }.await;
+
+        match result {
+            Ok(()) => Ok(SetBucketThresholdsReply { success: true }),
+            Err(e) => {
+                error!("Error saving thresholds of bucket '{}' : {}", arg.thresholds.group, e);
+                Ok(SetBucketThresholdsReply { success: false })
+            },
+        }
+    }
 
-#[derive(Debug, Default, Actor, HealthResponder)]
-#[services(Actor)]
-struct ServiceVendorsActor {}
+    async fn get_bucket_thresholds(&self, ctx: &Context, arg: &BucketRef) -> RpcResult<BucketThresholds> {
+        self.find_thresholds(ctx, &Self::keys(&arg.principal)?, &arg.group).await
+            .map_err(|e| RpcError::Other(format!("{}", e)))
+    }
+}
 
-// Implementation of trait methods : TODO
+#[async_trait]
+impl Invoices for ServiceVendorsActor {
+    async fn generate_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<Invoice> {
+        info!("generate invoice");
 
+        self.build_invoice(ctx, &Self::keys(&arg.principal)?, arg).await.map_err(|e| {
+            RpcError::Other(format!("Error generating invoice of group '{}' for {} : {}", arg.group, arg.period, e))
+        })
+    }
 
+    async fn get_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<GetInvoiceReply> {
+        self.find_invoice(ctx, &Self::keys(&arg.principal)?, &arg.group, &arg.period).await
+            .map(|invoice| GetInvoiceReply { invoice })
+            .map_err(|e| RpcError::Other(format!("{}", e)))
+    }
+}
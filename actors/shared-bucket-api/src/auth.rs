@@ -0,0 +1,224 @@
+//! Authentication of the API callers, with a bearer token (JWT) or an API key
+//!
+//! Configuration is read from the KeyValue store :
+//! - `config:jwt_issuer` : expected `iss` claim, no token is accepted when missing
+//! - `config:jwt_keys` : JSON list of the issuer keys, `[{"kid": "..", "alg": "HS256" | "RS256", "key": ".."}]`,
+//!   the key being the shared secret (HS256) or the PEM encoded public key (RS256)
+//! - `config:jwt_audience` : expected `aud` claim (optional)
+//! - `config:jwt_scope` : scope the token must be granted (optional)
+//!
+//! API keys are sent in the `X-API-Key` header, and stored as `api_key:<hex sha256 of the key>`
+//! with the JSON `Principal` they authenticate as value.
+//!
+//! Vendors send their credentials with the `Basic` scheme, they are verified by the service vendors actor.
+
+use hmac::{Hmac, Mac};
+use rsa::{pkcs8::DecodePublicKey, Hash, PaddingScheme, PublicKey, RsaPublicKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use shared_bucket::lattice::Link;
+use shared_bucket::{Principal, VendorCredentials};
+use wasmbus_rpc::actor::prelude::*;
+use wasmcloud_interface_httpserver::HttpRequest;
+use wasmcloud_interface_keyvalue::{GetResponse, KeyValue, KeyValueSender, SetRequest};
+
+use crate::header;
+
+/// Tokens living longer than this (in seconds) are refused
+const MAX_TOKEN_LIFETIME: i64 = 24 * 60 * 60;
+
+/// How long (in seconds) a used token is remembered
+const SEEN_TOKEN_RETENTION: u32 = 30 * 24 * 60 * 60;
+
+pub enum AuthError {
+    /// Missing, malformed, wrongly signed or expired credentials (401)
+    Unauthenticated(String),
+    /// Valid credentials lacking the required scope (403)
+    Forbidden(String),
+}
+
+#[derive(Deserialize)]
+struct JwtHeader {
+    alg: String,
+    kid: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    iss: Option<String>,
+    sub: Option<String>,
+    #[serde(default)]
+    aud: serde_json::Value,
+    exp: Option<i64>,
+    iat: Option<i64>,
+    jti: Option<String>,
+    scope: Option<String>,
+    #[serde(default)]
+    roles: Vec<String>,
+    tenant: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JwtKey {
+    kid: Option<String>,
+    alg: String,
+    key: String,
+}
+
+fn unauthenticated<T: ToString>(reason: T) -> AuthError {
+    AuthError::Unauthenticated(reason.to_string())
+}
+
+fn decode_part(part: &str) -> Result<Vec<u8>, AuthError> {
+    base64::decode_config(part, base64::URL_SAFE_NO_PAD).map_err(|e| unauthenticated(format!("malformed token : {}", e)))
+}
+
+/// KeyValue store holding the configuration, the API keys and the tokens seen
+pub type Store = KeyValueSender<Link>;
+
+async fn config(ctx: &Context, kv: &Store, name: &str) -> Result<Option<String>, AuthError> {
+    match kv.get(ctx, &format!("config:{}", name)).await {
+        Ok(GetResponse { exists: true, value }) => Ok(Some(value)),
+        Ok(GetResponse { exists: false, .. }) => Ok(None),
+        Err(e) => Err(unauthenticated(format!("error reading config '{}' : {}", name, e))),
+    }
+}
+
+/// Authenticates the caller of a request
+pub async fn authenticate(ctx: &Context, kv: &Store, req: &HttpRequest) -> Result<Principal, AuthError> {
+    if let Some(key) = header(req, "X-API-Key") {
+        return authenticate_api_key(ctx, kv, key).await;
+    }
+
+    match header(req, "Authorization").and_then(|value| value.split_once(' ')) {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("Bearer") => authenticate_token(ctx, kv, token.trim()).await,
+        _ => Err(unauthenticated("missing credentials")),
+    }
+}
+
+/// Vendor credentials of a request, not verified
+pub fn vendor_credentials(req: &HttpRequest) -> Option<VendorCredentials> {
+    let (scheme, encoded) = header(req, "Authorization")?.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("Basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(base64::decode(encoded.trim()).ok()?).ok()?;
+    let (vendor, secret) = decoded.split_once(':')?;
+
+    Some(VendorCredentials { vendor: vendor.to_string(), secret: secret.to_string() })
+}
+
+async fn authenticate_api_key(ctx: &Context, kv: &Store, key: &str) -> Result<Principal, AuthError> {
+    let hash = hex::encode(Sha256::digest(key.as_bytes()));
+    match kv.get(ctx, &format!("api_key:{}", hash)).await {
+        Ok(GetResponse { exists: true, value }) => serde_json::from_str(&value).map_err(unauthenticated),
+        Ok(GetResponse { exists: false, .. }) => Err(unauthenticated("unknown API key")),
+        Err(e) => Err(unauthenticated(e)),
+    }
+}
+
+async fn authenticate_token(ctx: &Context, kv: &Store, token: &str) -> Result<Principal, AuthError> {
+    let parts: Vec<&str> = token.split('.').collect();
+    let (header, payload, signature) = match parts.as_slice() {
+        [header, payload, signature] => (*header, *payload, *signature),
+        _ => return Err(unauthenticated("malformed token")),
+    };
+    let jwt_header: JwtHeader = serde_json::from_slice(&decode_part(header)?).map_err(unauthenticated)?;
+    let claims: JwtClaims = serde_json::from_slice(&decode_part(payload)?).map_err(unauthenticated)?;
+
+    let keys: Vec<JwtKey> = match config(ctx, kv, "jwt_keys").await? {
+        Some(keys) => serde_json::from_str(&keys).map_err(|e| unauthenticated(format!("invalid jwt_keys config : {}", e)))?,
+        None => Vec::new(),
+    };
+    let key = keys.iter()
+        .find(|key| key.alg == jwt_header.alg && (jwt_header.kid.is_none() || key.kid == jwt_header.kid))
+        .ok_or_else(|| unauthenticated("no key matching the token"))?;
+    verify_signature(key, format!("{}.{}", header, payload).as_bytes(), &decode_part(signature)?)?;
+
+    let issuer = config(ctx, kv, "jwt_issuer").await?;
+    if issuer.is_none() || claims.iss != issuer {
+        return Err(unauthenticated("unexpected issuer"));
+    }
+    if let Some(audience) = config(ctx, kv, "jwt_audience").await? {
+        let matches = match &claims.aud {
+            serde_json::Value::String(aud) => *aud == audience,
+            serde_json::Value::Array(auds) => auds.iter().any(|aud| aud.as_str() == Some(audience.as_str())),
+            _ => false,
+        };
+        if !matches {
+            return Err(unauthenticated("unexpected audience"));
+        }
+    }
+
+    let id = claims.jti.clone().unwrap_or_else(|| signature.to_string());
+    match (claims.iat, claims.exp) {
+        (Some(iat), Some(exp)) => check_lifetime(ctx, kv, &id, iat, exp).await?,
+        _ => return Err(unauthenticated("token without iat and exp")),
+    }
+
+    if let Some(scope) = config(ctx, kv, "jwt_scope").await? {
+        if !claims.scope.unwrap_or_default().split(' ').any(|s| s == scope) {
+            return Err(AuthError::Forbidden(format!("token is not granted the '{}' scope", scope)));
+        }
+    }
+
+    match claims.sub {
+        Some(sub) => Ok(Principal { id: sub, kind: "user".to_string(), roles: Some(claims.roles), tenant: claims.tenant }),
+        None => Err(unauthenticated("token without subject")),
+    }
+}
+
+fn verify_signature(key: &JwtKey, message: &[u8], signature: &[u8]) -> Result<(), AuthError> {
+    match key.alg.as_str() {
+        "HS256" => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key.key.as_bytes()).map_err(unauthenticated)?;
+            mac.update(message);
+            mac.verify_slice(signature).map_err(|_| unauthenticated("invalid signature"))
+        },
+        "RS256" => {
+            let public_key = RsaPublicKey::from_public_key_pem(&key.key).map_err(unauthenticated)?;
+            public_key.verify(PaddingScheme::new_pkcs1v15_sign(Some(Hash::SHA2_256)), &Sha256::digest(message), signature)
+                .map_err(|_| unauthenticated("invalid signature"))
+        },
+        alg => Err(unauthenticated(format!("unsupported algorithm {}", alg))),
+    }
+}
+
+/// Actors have no clock (there is no time source in wasm32-unknown-unknown), so the token expiry is enforced with :
+/// - a watermark, the most recent `iat` of the accepted tokens : tokens that expired before it are refused
+/// - the KeyValue store expiry : a token is accepted during `exp - iat` seconds from its first use
+async fn check_lifetime(ctx: &Context, kv: &Store, id: &str, iat: i64, exp: i64) -> Result<(), AuthError> {
+    let lifetime = exp - iat;
+    if lifetime <= 0 || lifetime > MAX_TOKEN_LIFETIME {
+        return Err(unauthenticated("invalid token lifetime"));
+    }
+
+    let watermark = match kv.get(ctx, "jwt_watermark").await {
+        Ok(GetResponse { exists: true, value }) => value.parse().unwrap_or(0),
+        _ => 0,
+    };
+    if exp <= watermark {
+        return Err(unauthenticated("token expired"));
+    }
+
+    match kv.get(ctx, &format!("jwt_live:{}", id)).await {
+        Ok(GetResponse { exists: true, .. }) => return Ok(()),
+        Ok(GetResponse { exists: false, .. }) => {},
+        Err(e) => return Err(unauthenticated(e)),
+    }
+    if kv.contains(ctx, &format!("jwt_seen:{}", id)).await.map_err(unauthenticated)? {
+        return Err(unauthenticated("token expired"));
+    }
+
+    // first use of the token
+    let live = SetRequest { key: format!("jwt_live:{}", id), value: exp.to_string(), expires: lifetime as u32 };
+    let seen = SetRequest { key: format!("jwt_seen:{}", id), value: exp.to_string(), expires: SEEN_TOKEN_RETENTION };
+    kv.set(ctx, &live).await.map_err(unauthenticated)?;
+    kv.set(ctx, &seen).await.map_err(unauthenticated)?;
+    if iat > watermark {
+        let request = SetRequest { key: "jwt_watermark".to_string(), value: iat.to_string(), expires: 0 };
+        kv.set(ctx, &request).await.map_err(unauthenticated)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! Files of the bulk customer imports : CSV with a header row naming the columns, or JSON Lines
+//! with an object per line. The columns and keys are the JSON names of the customer fields, and
+//! `group` for the id of the group the customer joins.
+
+use std::mem;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use shared_bucket::{Customer, ImportResult, ImportRow};
+
+/// Columns and keys of the imported customers
+const FIELDS: [&str; 9] = ["firstName", "lastName", "address", "city", "country", "region", "telephone", "email", "group"];
+
+pub enum Format {
+    Csv,
+    JsonLines,
+}
+
+impl Format {
+    /// Format of a `Content-Type`, its parameters (e.g. the charset) being ignored
+    pub fn of(content_type: &str) -> Option<Format> {
+        match content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase().as_str() {
+            "text/csv" => Some(Format::Csv),
+            "application/x-ndjson" | "application/jsonl" => Some(Format::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// Fields of a line, or why it has none
+type Fields = Result<Map<String, Value>, String>;
+
+#[derive(Deserialize)]
+struct Line {
+    #[serde(flatten)]
+    customer: Customer,
+    group: Option<String>,
+}
+
+/// Rows of a file, and the results of the lines which are not rows. Fails when the file as a
+/// whole can't be read.
+pub fn parse(format: Format, body: &[u8]) -> Result<(Vec<ImportRow>, Vec<ImportResult>), String> {
+    let text = std::str::from_utf8(body).map_err(|_| "The file is not UTF-8 text".to_string())?;
+    let lines = match format {
+        Format::Csv => csv_lines(text)?,
+        Format::JsonLines => json_lines(text),
+    };
+
+    let mut rows = Vec::new();
+    let mut invalid = Vec::new();
+    for (line, fields) in lines {
+        match fields.and_then(|fields| row(line, fields)) {
+            Ok(row) => rows.push(row),
+            Err(reason) => invalid.push(ImportResult { line, status: "invalid".to_string(), id: None, reason: Some(reason) }),
+        }
+    }
+
+    Ok((rows, invalid))
+}
+
+fn row(line: u32, fields: Map<String, Value>) -> Result<ImportRow, String> {
+    if let Some(unknown) = fields.keys().find(|key| !FIELDS.contains(&key.as_str())) {
+        return Err(format!("Unknown field '{}'", unknown));
+    }
+    let Line { customer, group } = serde_json::from_value(Value::Object(fields)).map_err(|e| e.to_string())?;
+
+    Ok(ImportRow { line, customer, group })
+}
+
+/// Fields of the non blank lines, by line number
+fn json_lines(text: &str) -> Vec<(u32, Fields)> {
+    text.lines()
+        .zip(1..)
+        .filter(|(line, _)| !line.trim().is_empty())
+        .map(|(line, number)| {
+            let fields = match serde_json::from_str(line) {
+                Ok(Value::Object(fields)) => Ok(fields),
+                Ok(_) => Err("The line is not an object".to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            (number, fields)
+        })
+        .collect()
+}
+
+/// Fields of the records following the header, by the line they start at. The empty fields are
+/// left out.
+fn csv_lines(text: &str) -> Result<Vec<(u32, Fields)>, String> {
+    let mut records = csv_records(text)?.into_iter();
+    let header = match records.next() {
+        Some((_, header)) => header,
+        None => return Ok(Vec::new()),
+    };
+    for (i, column) in header.iter().enumerate() {
+        if !FIELDS.contains(&column.as_str()) {
+            return Err(format!("Unknown column '{}'", column));
+        }
+        if header[..i].contains(column) {
+            return Err(format!("The column '{}' is repeated", column));
+        }
+    }
+
+    Ok(records
+        .map(|(line, values)| {
+            if values.len() != header.len() {
+                return (line, Err(format!("{} fields instead of {}", values.len(), header.len())));
+            }
+            let fields = header.iter()
+                .zip(values)
+                .filter(|(_, value)| !value.trim().is_empty())
+                .map(|(column, value)| (column.clone(), Value::String(value.trim().to_string())))
+                .collect();
+            (line, Ok(fields))
+        })
+        .collect())
+}
+
+/// Non blank records of a CSV text (RFC 4180) with the line they start at. A field may be quoted,
+/// a quote inside being doubled, to hold commas and line breaks.
+fn csv_records(text: &str) -> Result<Vec<(u32, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let (mut record, mut field) = (Vec::new(), String::new());
+    let (mut line, mut start) = (1, 1);
+    let mut quoted = false;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            },
+            (true, '"') => quoted = false,
+            (true, c) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            },
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {},
+            (false, '\n') => {
+                record.push(mem::take(&mut field));
+                records.push((start, mem::take(&mut record)));
+                line += 1;
+                start = line;
+            },
+            (false, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("The quoted field of line {} is not closed", start));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+
+    records.retain(|(_, record)| record.len() > 1 || !record[0].trim().is_empty());
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        let text = "firstName,address\r\n\"Doe, Jane\",\"1 \"\"Main\"\" St\nParis\"\n\nbob,\n";
+        assert_eq!(csv_records(text).unwrap(), vec![
+            (1, vec!["firstName".to_string(), "address".to_string()]),
+            (2, vec!["Doe, Jane".to_string(), "1 \"Main\" St\nParis".to_string()]),
+            (5, vec!["bob".to_string(), "".to_string()]),
+        ]);
+        assert!(csv_records("firstName\n\"alice").is_err());
+    }
+
+    #[test]
+    fn lines_are_parsed_into_rows() {
+        let csv = "firstName,email,group\nalice,alice@example.com,family\nbob,bob@example.com\n";
+        let (rows, invalid) = parse(Format::Csv, csv.as_bytes()).unwrap();
+        assert_eq!((rows.len(), rows[0].line, rows[0].group.as_deref()), (1, 2, Some("family")));
+        assert_eq!((invalid[0].line, invalid[0].reason.as_deref()), (3, Some("2 fields instead of 3")));
+        assert!(parse(Format::Csv, b"firstName,phone\n").is_err());
+
+        let json = "{\"firstName\":\"alice\",\"email\":\"alice@example.com\"}\n\n[]\n{\"id\":\"42\"}\n";
+        let (rows, invalid) = parse(Format::JsonLines, json.as_bytes()).unwrap();
+        assert_eq!((rows[0].line, rows[0].customer.first_name.as_str(), rows[0].group.as_deref()), (1, "alice", None));
+        assert_eq!(invalid.iter().map(|result| result.line).collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn formats_are_told_by_the_content_type() {
+        assert!(matches!(Format::of("text/csv; charset=utf-8"), Some(Format::Csv)));
+        assert!(matches!(Format::of("application/x-ndjson"), Some(Format::JsonLines)));
+        assert!(Format::of("application/json").is_none());
+    }
+}
@@ -1,20 +1,189 @@
-use serde::Deserialize;
-use shared_bucket::{AddCustomerRequest, Customer, CustomerGroup, CustomerGroups, CustomerGroupsSender, Customers, CustomersSender};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use shared_bucket::export;
+use shared_bucket::lattice::{Lattice, Link, KEYVALUE};
+use shared_bucket::policy::{can_manage_group, ACCESS_DENIED};
+use shared_bucket::{AddCustomerRequest, AuthorizeServiceUsageReply, BucketRef, BucketThresholds, BuyServiceReply, CatalogService, CatalogServiceRequest, CreateCustomerGroupRequest, CreateCustomerRequest, Customer, CustomerGroup, CustomerGroups, CustomerGroupsSender, Customers, CustomersSender, ExportBucketRequest, ExportRecordsRequest, ExportedRecord, FindCustomerGroupRequest, FindCustomerRequest, GroupWebhooksRequest, ImportCustomersRequest, ImportResult, InvoiceRequest, Invoices, InvoicesSender, ListCustomersRequest, MigrateLedgersRequest, MigrateRecordsRequest, Principal, REVISION_MISMATCH, RefundRequest, RegisterWebhookRequest, RemoveCustomerRequest, RenameCustomerGroupRequest, RestoreBucketsRequest, RestoreRecordsReply, RestoreRecordsRequest, Service, ServiceOrder, ServiceRef, ServiceUsage, ServiceVendors, ServiceVendorsSender, SetBucketThresholdsRequest, ThresholdList, TransferRequest, Vendor, VendorCredentials, Webhook, WebhookRef, Webhooks, WebhooksSender};
 use wasmbus_rpc::actor::prelude::*;
+use wasmbus_rpc::Timestamp;
 use wasmcloud_interface_httpserver::{HttpRequest, HttpResponse, HttpServer, HttpServerReceiver};
+#[cfg(not(target_arch = "wasm32"))]
+use log::info;
+#[cfg(target_arch = "wasm32")]
 use wasmcloud_interface_logging::info;
+use wasmcloud_interface_keyvalue::KeyValueSender;
+
+use auth::{authenticate, vendor_credentials, AuthError};
+
+mod auth;
+mod import;
+mod policy;
 
 const CUSTOMERS_ACTOR: &str = "customers";
+const WEBHOOKS_ACTOR: &str = "webhooks";
+const SERVICE_VENDORS_ACTOR: &str = "service-vendors";
+
+/// How many records of a restored export are sent to an actor at once
+const RESTORE_BATCH: usize = 500;
+
+/// Most ledger entries in a page of an export
+const EXPORT_ENTRIES: u32 = 1000;
 
-#[derive(Debug, Default, Actor, HealthResponder)]
+/// How many rows of an import are sent to the customers actor at once
+const IMPORT_BATCH: usize = 500;
+
+// natively the wasm exports are not derived, so that the tests can link the actors together
+#[cfg_attr(target_arch = "wasm32", derive(Actor))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(wasmbus_rpc::provider::prelude::Provider))]
+#[derive(Debug, HealthResponder)]
 #[services(Actor, HttpServer)]
-struct SharedBucketAPIActor {}
+pub struct SharedBucketAPIActor {
+    lattice: Box<dyn Lattice>,
+}
+
+impl SharedBucketAPIActor {
+    pub fn new<L: Lattice + 'static>(lattice: L) -> Self {
+        SharedBucketAPIActor { lattice: Box::new(lattice) }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for SharedBucketAPIActor {
+    fn default() -> Self {
+        Self::new(shared_bucket::lattice::Host::default())
+    }
+}
+
+/// Body of `POST /services/{id}/authorizations`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageBody {
+    client: String,
+    group: String,
+    units: f64,
+    timestamp: Timestamp,
+}
+
+/// Body of `POST /services/{id}/orders/{order}/refunds`
+#[derive(Deserialize)]
+struct RefundBody {
+    amount: Option<f64>,
+    reason: Option<String>,
+    timestamp: Timestamp,
+}
+
+/// Body of `POST /customer-groups/{id}/transfers`
+#[derive(Deserialize)]
+struct TransferBody {
+    to: String,
+    amount: f64,
+    reason: Option<String>,
+    timestamp: Timestamp,
+}
+
+/// Body of `PATCH /customer-groups/{id}`
+#[derive(Deserialize)]
+struct RenameBody {
+    name: String,
+}
+
+/// Body of `POST /services/{id}/orders`
+#[derive(Deserialize)]
+struct OrderBody {
+    group: String,
+    amount: f64,
+    timestamp: Timestamp,
+    customer: Option<String>,
+}
+
+/// Reply of `POST /customers/imports`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportReport {
+    dry_run: bool,
+    /// Number of rows by status
+    totals: BTreeMap<String, u32>,
+    rows: Vec<ImportResult>,
+}
 
 /// Util functions
 fn deserialize<'de, T: Deserialize<'de>>(raw: &'de [u8]) -> RpcResult<T> {
     serde_json::from_slice(raw).map_err(|e| RpcError::Deser(format!("{}", e)))
 }
 
+/// Value of a query parameter
+fn query(req: &HttpRequest, name: &str) -> Option<String> {
+    form_urlencoded::parse(req.query_string.as_bytes())
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Header lookup, names are compared case insensitively
+fn header<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    req.header
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .and_then(|(_, values)| values.first())
+        .map(|value| value.as_str())
+}
+
+fn unauthorized(scheme: &str) -> HttpResponse {
+    HttpResponse {
+        status_code: 401,
+        header: HashMap::from([("WWW-Authenticate".to_string(), vec![scheme.to_string()])]),
+        body: b"Unauthorized".to_vec(),
+    }
+}
+
+fn forbidden() -> HttpResponse {
+    HttpResponse {
+        status_code: 403,
+        header: HashMap::new(),
+        body: b"Forbidden".to_vec(),
+    }
+}
+
+fn precondition_failed() -> HttpResponse {
+    HttpResponse {
+        status_code: 412,
+        header: HashMap::new(),
+        body: b"Precondition Failed".to_vec(),
+    }
+}
+
+/// Turns the access denied and revision mismatch errors of the called actors into 403 and 412 responses
+fn or_error_status(result: RpcResult<HttpResponse>) -> RpcResult<HttpResponse> {
+    match result {
+        Err(RpcError::Other(message)) if message.starts_with(ACCESS_DENIED) => Ok(forbidden()),
+        Err(RpcError::Other(message)) if message.starts_with(REVISION_MISMATCH) => Ok(precondition_failed()),
+        result => result,
+    }
+}
+
+/// Sets the ETag of a response to the revision of the record, `"3"` for revision 3
+fn with_etag(response: RpcResult<HttpResponse>, revision: Option<u64>) -> RpcResult<HttpResponse> {
+    let mut response = response?;
+    if let Some(revision) = revision {
+        response.header.insert("ETag".to_string(), vec![format!("\"{}\"", revision)]);
+    }
+    Ok(response)
+}
+
+/// Revision a record must be at, told by the `If-Match` header : any revision without the header
+/// or with `*`. Only a single ETag is compared, other values never match.
+fn if_match(value: Option<&str>) -> Result<Option<u64>, HttpResponse> {
+    match value.map(str::trim) {
+        None | Some("*") => Ok(None),
+        Some(etag) => etag
+            .strip_prefix('"')
+            .and_then(|etag| etag.strip_suffix('"'))
+            .and_then(|revision| revision.parse().ok())
+            .map(Some)
+            .ok_or_else(precondition_failed),
+    }
+}
+
 /// Implementation of HttpServer trait methods
 #[async_trait]
 impl HttpServer for SharedBucketAPIActor {
@@ -23,27 +192,96 @@ impl HttpServer for SharedBucketAPIActor {
         ctx: &Context,
         req: &HttpRequest,
     ) -> Result<HttpResponse, RpcError> {
-        info!("API request: {:?}", req);
+        // the request is not logged as a whole, its headers hold credentials
+        info!("API request: {} {}", req.method, req.path);
 
+        let lattice = self.lattice.as_ref();
         let path = &req.path[1..req.path.len()];
         let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
         info!("Segments: {:?}", segments);
 
-        match (req.method.as_ref(), segments.as_slice()) {
-            ("POST", ["customers"]) => create_customer(ctx, deserialize(&req.body)?).await,
-            ("GET", ["customers", customer_id]) => find_customer(ctx, customer_id).await,
-            ("POST", ["customer-groups"]) => create_customer_group(ctx, deserialize(&req.body)?).await,
-            ("GET", ["customer-groups", group_name, "customers"]) => get_group_customers(ctx, group_name).await,
-            ("POST", ["customer-groups", group_name, "customers", customer_id]) => add_customer_group(ctx, group_name, customer_id).await,
-            (_, _) => Ok(HttpResponse::not_found()),
+        if let ("POST" | "PUT" | "DELETE", ["services", ..]) = (req.method.as_ref(), segments.as_slice()) {
+            return or_error_status(handle_vendor_request(ctx, lattice, req, &segments).await);
+        }
+
+        let kv = KeyValueSender::via(lattice.provider(KEYVALUE)?);
+        let principal = match authenticate(ctx, &kv, req).await {
+            Ok(principal) => principal,
+            Err(AuthError::Unauthenticated(reason)) => {
+                info!("Authentication failed : {}", reason);
+                return Ok(unauthorized("Bearer"));
+            },
+            Err(AuthError::Forbidden(reason)) => {
+                info!("Access denied : {}", reason);
+                return Ok(forbidden());
+            },
+        };
+        let principal = &principal;
+
+        if !policy::allowed(principal, &req.method, &segments) {
+            info!("Access denied : {} may not {} {}", principal.id, req.method, req.path);
+            return Ok(forbidden());
         }
+
+        let response = match (req.method.as_ref(), segments.as_slice()) {
+            ("POST", ["customers"]) => create_customer(ctx, lattice, principal, deserialize(&req.body)?, header(req, "Idempotency-Key")).await,
+            ("POST", ["customers", "imports"]) => import_customers(ctx, lattice, principal, req).await,
+            ("GET", ["customers", customer_id]) => find_customer(ctx, lattice, principal, customer_id).await,
+            ("POST", ["customer-groups"]) => create_customer_group(ctx, lattice, principal, deserialize(&req.body)?).await,
+            ("GET", ["customer-groups", group_id]) => find_customer_group(ctx, lattice, principal, group_id).await,
+            ("PATCH", ["customer-groups", group_id]) => rename_customer_group(ctx, lattice, principal, group_id, deserialize(&req.body)?, header(req, "If-Match")).await,
+            ("GET", ["customer-groups", group_id, "customers"]) => get_group_customers(ctx, lattice, principal, group_id, req).await,
+            ("POST", ["customer-groups", group_id, "customers", customer_id]) => add_customer_group(ctx, lattice, principal, group_id, customer_id, header(req, "If-Match")).await,
+            ("DELETE", ["customer-groups", group_id, "customers", customer_id]) => remove_customer_group(ctx, lattice, principal, group_id, customer_id, header(req, "If-Match")).await,
+            ("POST", ["customer-groups", group_id, "webhooks"]) => register_webhook(ctx, lattice, principal, group_id, deserialize(&req.body)?).await,
+            ("GET", ["customer-groups", group_id, "webhooks"]) => list_webhooks(ctx, lattice, principal, group_id).await,
+            ("DELETE", ["customer-groups", group_id, "webhooks", webhook_id]) => delete_webhook(ctx, lattice, principal, group_id, webhook_id).await,
+            ("GET", ["customer-groups", group_id, "webhooks", webhook_id, "deliveries"]) => list_webhook_deliveries(ctx, lattice, principal, group_id, webhook_id).await,
+            ("POST", ["customer-groups", group_id, "invoices", period]) => generate_invoice(ctx, lattice, principal, group_id, period).await,
+            ("GET", ["customer-groups", group_id, "invoices", period]) => get_invoice(ctx, lattice, principal, group_id, period).await,
+            ("POST", ["customer-groups", group_id, "transfers"]) => transfer_units(ctx, lattice, principal, group_id, deserialize(&req.body)?, header(req, "Idempotency-Key")).await,
+            ("GET", ["customer-groups", group_id, "transfers"]) => list_transfers(ctx, lattice, principal, group_id).await,
+            ("PUT", ["customer-groups", group_id, "thresholds"]) => set_bucket_thresholds(ctx, lattice, principal, group_id, deserialize(&req.body)?).await,
+            ("GET", ["customer-groups", group_id, "thresholds"]) => get_bucket_thresholds(ctx, lattice, principal, group_id).await,
+            ("POST", ["vendors"]) => register_vendor(ctx, lattice, principal, deserialize(&req.body)?).await,
+            ("GET", ["services"]) => list_services(ctx, lattice).await,
+            ("GET", ["services", service_id]) => get_service(ctx, lattice, service_id).await,
+            ("POST", ["admin", "migrations"]) => migrate_records(ctx, lattice, principal).await,
+            ("GET", ["admin", "export"]) => export_records(ctx, lattice, principal, req).await,
+            ("POST", ["admin", "restore"]) => restore_records(ctx, lattice, principal, &req.body).await,
+            (_, _) => Ok(HttpResponse::not_found()),
+        };
+
+        or_error_status(response)
+    }
+}
+
+/// Routes of the vendors, authenticated with their credentials which the service vendors actor verifies
+async fn handle_vendor_request(ctx: &Context, lattice: &dyn Lattice, req: &HttpRequest, segments: &[&str]) -> RpcResult<HttpResponse> {
+    let credentials = match vendor_credentials(req) {
+        Some(credentials) => credentials,
+        None => return Ok(unauthorized("Basic")),
+    };
+
+    match (req.method.as_ref(), segments) {
+        ("POST", ["services"]) => register_service(ctx, lattice, credentials, deserialize(&req.body)?).await,
+        ("PUT", ["services", service_id]) => update_service(ctx, lattice, credentials, service_id, deserialize(&req.body)?).await,
+        ("DELETE", ["services", service_id]) => delete_service(ctx, lattice, credentials, service_id).await,
+        ("POST", ["services", service_id, "authorizations"]) => authorize_service_usage(ctx, lattice, credentials, service_id, deserialize(&req.body)?, header(req, "Idempotency-Key")).await,
+        ("POST", ["services", service_id, "orders"]) => buy_service(ctx, lattice, credentials, service_id, deserialize(&req.body)?, header(req, "Idempotency-Key")).await,
+        ("POST", ["services", _, "orders", order_id, "refunds"]) => refund_order(ctx, lattice, credentials, order_id, deserialize(&req.body)?, header(req, "Idempotency-Key")).await,
+        (_, _) => Ok(HttpResponse::not_found()),
     }
 }
 
-async fn create_customer(ctx: &Context, customer: Customer) -> RpcResult<HttpResponse> {
+async fn create_customer(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, customer: Customer, idempotency_key: Option<&str>) -> RpcResult<HttpResponse> {
     info!("Customer: {:?}", customer);
-    let x = CustomersSender::to_actor(CUSTOMERS_ACTOR)
-        .create_customer(ctx, &customer)
+    let x = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .create_customer(ctx, &CreateCustomerRequest {
+            customer,
+            idempotency_key: idempotency_key.map(|key| key.to_string()),
+            principal: Some(principal.clone()),
+        })
         .await?;
     if x.success {
         HttpResponse::json(x, 200)
@@ -54,23 +292,89 @@ async fn create_customer(ctx: &Context, customer: Customer) -> RpcResult<HttpRes
     }
 }
 
-async fn find_customer(ctx: &Context, customer_id: &str) -> RpcResult<HttpResponse> {
+/// Creates the customers of a CSV or JSON Lines file (told by the `Content-Type`), or only checks
+/// them with `dryRun=true`. The report tells what became of each line.
+async fn import_customers(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, req: &HttpRequest) -> RpcResult<HttpResponse> {
+    let format = match header(req, "Content-Type").and_then(import::Format::of) {
+        Some(format) => format,
+        None => return Ok(HttpResponse {
+            status_code: 415,
+            header: HashMap::new(),
+            body: b"Imports are text/csv or application/x-ndjson".to_vec(),
+        }),
+    };
+    let dry_run = match query(req, "dryRun").as_deref() {
+        None | Some("false") => false,
+        Some("true") => true,
+        Some(_) => return Ok(HttpResponse::bad_request("dryRun is not a boolean")),
+    };
+    let (rows, mut results) = match import::parse(format, &req.body) {
+        Ok(parsed) => parsed,
+        Err(reason) => return Ok(HttpResponse::bad_request(reason)),
+    };
+    info!("Import {} customers, dry run : {}", rows.len(), dry_run);
+
+    let customers = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?);
+    // a dry run creates nothing : the rows sharing an email with a row of an earlier batch are told apart here
+    let mut emails = HashSet::new();
+    for batch in rows.chunks(IMPORT_BATCH) {
+        let batch_emails: HashMap<_, _> = batch.iter().map(|row| (row.line, row.customer.email.trim().to_lowercase())).collect();
+        let x = customers
+            .import_customers(ctx, &ImportCustomersRequest {
+                rows: batch.to_vec(),
+                dry_run,
+                principal: Some(principal.clone()),
+            })
+            .await?;
+        for result in x {
+            let email = match batch_emails.get(&result.line) {
+                Some(email) => email,
+                None => return Err(RpcError::Other(format!("Import result of line {} which is not in the batch", result.line))),
+            };
+            if result.status == "created" && !emails.insert(email.clone()) {
+                results.push(ImportResult {
+                    status: "duplicate".to_string(),
+                    id: None,
+                    reason: Some("The email is the one of an earlier row".to_string()),
+                    ..result
+                });
+            } else {
+                results.push(result);
+            }
+        }
+    }
+    results.sort_by_key(|result| result.line);
+
+    let mut totals = BTreeMap::new();
+    for result in &results {
+        *totals.entry(result.status.clone()).or_insert(0) += 1;
+    }
+    HttpResponse::json(ImportReport { dry_run, totals, rows: results }, 200)
+}
+
+async fn find_customer(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, customer_id: &str) -> RpcResult<HttpResponse> {
     info!("Find customer : {}", customer_id);
-    let x = CustomersSender::to_actor(CUSTOMERS_ACTOR)
-        .find_customer(ctx, customer_id)
+    let x = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .find_customer(ctx, &FindCustomerRequest {
+            id: customer_id.to_string(),
+            principal: Some(principal.clone()),
+        })
         .await?;
 
     match x.customer {
-        Some(customer) => HttpResponse::json(customer, 200),
+        Some(customer) => with_etag(HttpResponse::json(customer, 200), x.revision),
         None => Ok(HttpResponse::not_found())
     }
 
 }
 
-async fn create_customer_group(ctx: &Context, group: CustomerGroup) -> RpcResult<HttpResponse> {
+async fn create_customer_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group: CustomerGroup) -> RpcResult<HttpResponse> {
     info!("Customer Group: {:?}", group);
-    let x = CustomerGroupsSender::to_actor(CUSTOMERS_ACTOR)
-        .create_customer_group(ctx, &group)
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .create_customer_group(ctx, &CreateCustomerGroupRequest {
+            group,
+            principal: Some(principal.clone()),
+        })
         .await?;
     if x.success {
         HttpResponse::json(x, 200)
@@ -81,27 +385,656 @@ async fn create_customer_group(ctx: &Context, group: CustomerGroup) -> RpcResult
     }
 }
 
-async fn get_group_customers(ctx: &Context, group_name: &str) -> RpcResult<HttpResponse> {
-    info!("Get group customers : {}", group_name);
-    let x = CustomerGroupsSender::to_actor(CUSTOMERS_ACTOR)
-        .list_customers(ctx, group_name)
+/// Group with its members left out, they are listed a page at a time
+async fn find_customer_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str) -> RpcResult<HttpResponse> {
+    info!("Find customer group : {}", group_id);
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .find_customer_group(ctx, &FindCustomerGroupRequest {
+            id: group_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    match x.group {
+        Some(group) => with_etag(HttpResponse::json(group, 200), x.revision),
+        None => Ok(HttpResponse::not_found())
+    }
+}
+
+async fn rename_customer_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, body: RenameBody, if_match_header: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Rename customer group '{}' to '{}'", group_id, body.name);
+    let revision = match if_match(if_match_header) {
+        Ok(revision) => revision,
+        Err(response) => return Ok(response),
+    };
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .rename_customer_group(ctx, &RenameCustomerGroupRequest {
+            id: group_id.to_string(),
+            name: body.name,
+            principal: Some(principal.clone()),
+            revision,
+        })
+        .await?;
+
+    match x.success {
+        true => with_etag(HttpResponse::json((), 200), x.revision),
+        false => Ok(HttpResponse::not_found()),
+    }
+}
+
+/// Members of a group by increasing id, with the comma separated `fields` only when given. A page
+/// of `limit` members starts after the member id `after`.
+async fn get_group_customers(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, req: &HttpRequest) -> RpcResult<HttpResponse> {
+    info!("Get group customers : {}", group_id);
+    let limit = match query(req, "limit").map(|limit| limit.parse::<u32>()) {
+        Some(Ok(limit)) => Some(limit),
+        Some(Err(_)) => return Ok(HttpResponse::bad_request("limit is not a number")),
+        None => None,
+    };
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .list_customers(ctx, &ListCustomersRequest {
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+            fields: query(req, "fields").map(|fields| fields.split(',').map(|field| field.trim().to_string()).collect()),
+            after: query(req, "after"),
+            limit,
+        })
         .await?;
 
     HttpResponse::json(x, 200)
 }
 
-async fn add_customer_group(ctx: &Context, group_name: &str, customer_id: &str) -> RpcResult<HttpResponse> {
-    info!("Add customer '{}' to group '{}'", customer_id, group_name);
+async fn add_customer_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, customer_id: &str, if_match_header: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Add customer '{}' to group '{}'", customer_id, group_id);
+    let revision = match if_match(if_match_header) {
+        Ok(revision) => revision,
+        Err(response) => return Ok(response),
+    };
 
-    let x = CustomerGroupsSender::to_actor(CUSTOMERS_ACTOR)
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
         .add_customer(ctx, &AddCustomerRequest {
             customer: customer_id.to_string(),
-            group: group_name.to_string(),
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+            revision,
         })
         .await?;
 
     match x.success {
-        true => HttpResponse::json((), 200),
+        true => with_etag(HttpResponse::json((), 200), x.revision),
         false => Ok(HttpResponse::internal_server_error("Error adding customer to group")),
     }
-}
\ No newline at end of file
+}
+
+async fn remove_customer_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, customer_id: &str, if_match_header: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Remove customer '{}' from group '{}'", customer_id, group_id);
+    let revision = match if_match(if_match_header) {
+        Ok(revision) => revision,
+        Err(response) => return Ok(response),
+    };
+
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .remove_customer(ctx, &RemoveCustomerRequest {
+            customer: customer_id.to_string(),
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+            revision,
+        })
+        .await?;
+
+    match x.success {
+        true => with_etag(HttpResponse::json((), 200), x.revision),
+        false => Ok(HttpResponse::not_found()),
+    }
+}
+
+/// Upgrades the customers and groups, then keeps the periods of the ledgers of the groups written
+/// before their periods were kept, the groups being read from the export of the customers actor
+async fn migrate_records(ctx: &Context, lattice: &dyn Lattice, principal: &Principal) -> RpcResult<HttpResponse> {
+    info!("Migrate records");
+    let customers = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?);
+    let mut x = customers
+        .migrate_records(ctx, &MigrateRecordsRequest {
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    let vendors = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?);
+    let mut after = None;
+    loop {
+        let page = customers
+            .export_records(ctx, &ExportRecordsRequest {
+                after,
+                limit: None,
+                principal: Some(principal.clone()),
+            })
+            .await?;
+        let groups: Vec<String> = page.records.iter().filter_map(|record| record.group.as_ref()?.id.clone()).collect();
+        if !groups.is_empty() {
+            let ledgers = vendors
+                .migrate_ledgers(ctx, &MigrateLedgersRequest {
+                    groups,
+                    principal: Some(principal.clone()),
+                })
+                .await?;
+            x.migrated += ledgers.migrated;
+            x.current += ledgers.current;
+            x.failed += ledgers.failed;
+        }
+        match page.next {
+            Some(next) => after = Some(next),
+            None => break,
+        }
+    }
+
+    HttpResponse::json(x, 200)
+}
+
+/// Records of the tenant as JSON Lines, each tagged with its `kind`. A page holds `limit`
+/// customers, groups and members, each group followed by its members then, once its last member
+/// is written, its bucket and its ledger entries. A page holds at most `EXPORT_ENTRIES` ledger
+/// entries, the ledger of a group going on in the next page. The `Link` header of a page leads to
+/// the next one.
+async fn export_records(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, req: &HttpRequest) -> RpcResult<HttpResponse> {
+    let limit = match query(req, "limit").map(|limit| limit.parse::<u32>()) {
+        Some(Ok(limit)) => Some(limit),
+        Some(Err(_)) => return Ok(HttpResponse::bad_request("limit is not a number")),
+        None => None,
+    };
+    let after = query(req, "after");
+    info!("Export records after {:?}", after);
+
+    let vendors = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?);
+    let mut body = Vec::new();
+    let mut entries = EXPORT_ENTRIES;
+    // cursors of the next page : after a customer, group or member, and in the ledger of the group
+    let mut next = None;
+    let ledger = query(req, "ledger");
+    if let Some(ledger) = ledger.clone() {
+        let group = match after.as_deref().and_then(export::cursor_group) {
+            Some(group) => group,
+            None => return Ok(HttpResponse::bad_request("ledger is a cursor in the ledger of the group the page is after")),
+        };
+        if let Some(ledger) = export_bucket(ctx, &vendors, principal, &mut body, &mut entries, group, Some(ledger)).await? {
+            next = Some((after.clone().unwrap_or_default(), Some(ledger)));
+        }
+    }
+
+    if next.is_none() {
+        // the group the previous page ended in, its bucket not written yet unless its ledger was
+        let mut open = match (&after, &ledger) {
+            (Some(after), None) => export::cursor_group(after).map(|group| (group.to_string(), after.clone())),
+            _ => None,
+        };
+        let x = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+            .export_records(ctx, &ExportRecordsRequest {
+                after,
+                limit,
+                principal: Some(principal.clone()),
+            })
+            .await?;
+        next = x.next.clone().map(|after| (after, None));
+
+        let mut records = x.records.into_iter();
+        loop {
+            let record = records.next();
+            // the bucket of the open group follows its last member, which may be in the next page
+            if let Some((group, cursor)) = open.take() {
+                let member = record.as_ref().and_then(|record| record.member.as_ref()).is_some_and(|member| member.group == group);
+                if member || (record.is_none() && x.next.is_some()) {
+                    open = Some((group, cursor));
+                } else if let Some(ledger) = export_bucket(ctx, &vendors, principal, &mut body, &mut entries, &group, None).await? {
+                    next = Some((cursor, Some(ledger)));
+                    break;
+                }
+            }
+            let record = match record {
+                Some(record) => record,
+                None => break,
+            };
+            write_record(&mut body, &record)?;
+            if let Some(group) = export::record_group(&record) {
+                open = Some((group.to_string(), export::cursor(&record)));
+            }
+        }
+    }
+
+    let mut header = HashMap::from([("Content-Type".to_string(), vec!["application/x-ndjson".to_string()])]);
+    if let Some((after, ledger)) = next {
+        let mut link = format!("</admin/export?after={}", form_urlencoded::byte_serialize(after.as_bytes()).collect::<String>());
+        if let Some(ledger) = ledger {
+            link.push_str(&format!("&ledger={}", form_urlencoded::byte_serialize(ledger.as_bytes()).collect::<String>()));
+        }
+        if let Some(limit) = limit {
+            link.push_str(&format!("&limit={}", limit));
+        }
+        header.insert("Link".to_string(), vec![format!("{}>; rel=\"next\"", link)]);
+    }
+    Ok(HttpResponse { status_code: 200, header, body })
+}
+
+fn write_record(body: &mut Vec<u8>, record: &ExportedRecord) -> RpcResult<()> {
+    serde_json::to_writer(&mut *body, record).map_err(|e| RpcError::Ser(e.to_string()))?;
+    body.push(b'\n');
+    Ok(())
+}
+
+/// Writes the bucket of a group and its ledger entries after `after`, as many as the page has
+/// `entries` left. Returns the cursor of the rest of the ledger.
+async fn export_bucket(ctx: &Context, vendors: &ServiceVendorsSender<Link>, principal: &Principal, body: &mut Vec<u8>, entries: &mut u32, group: &str, after: Option<String>) -> RpcResult<Option<String>> {
+    let x = vendors
+        .export_bucket(ctx, &ExportBucketRequest {
+            group: group.to_string(),
+            after,
+            limit: *entries,
+            principal: Some(principal.clone()),
+        })
+        .await?;
+    for record in &x.records {
+        write_record(body, record)?;
+    }
+    let written = x.records.iter().filter(|record| record.kind == "ledgerEntry").count() as u32;
+    *entries = entries.saturating_sub(written);
+
+    Ok(x.next)
+}
+
+/// Restores the JSON Lines of an export, its pages being concatenated, in a tenant without
+/// customers and groups. Nothing is written unless every line is a record.
+async fn restore_records(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, body: &[u8]) -> RpcResult<HttpResponse> {
+    let text = match std::str::from_utf8(body) {
+        Ok(text) => text,
+        Err(_) => return Ok(HttpResponse::bad_request("The export is not UTF-8 text")),
+    };
+    let mut records = Vec::new();
+    for (line, number) in text.lines().zip(1..) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<ExportedRecord>(line) {
+            Ok(record) => records.push(record),
+            Err(e) => return Ok(HttpResponse::bad_request(format!("Line {} : {}", number, e))),
+        }
+    }
+
+    let customers = CustomersSender::via(lattice.actor(CUSTOMERS_ACTOR)?);
+    let existing = customers
+        .export_records(ctx, &ExportRecordsRequest {
+            after: None,
+            limit: Some(1),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+    if !existing.records.is_empty() {
+        return Ok(HttpResponse {
+            status_code: 409,
+            header: HashMap::new(),
+            body: b"Exports are restored in a tenant without customers and groups".to_vec(),
+        });
+    }
+    info!("Restore {} records", records.len());
+
+    let (buckets, records): (Vec<_>, Vec<_>) = records.into_iter().partition(|record| record.kind == "bucket" || record.kind == "ledgerEntry");
+    let mut restored = 0;
+    for batch in records.chunks(RESTORE_BATCH) {
+        restored += customers
+            .restore_records(ctx, &RestoreRecordsRequest {
+                records: batch.to_vec(),
+                principal: Some(principal.clone()),
+            })
+            .await?
+            .restored;
+    }
+    let vendors = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?);
+    for batch in buckets.chunks(RESTORE_BATCH) {
+        restored += vendors
+            .restore_buckets(ctx, &RestoreBucketsRequest {
+                records: batch.to_vec(),
+                principal: Some(principal.clone()),
+            })
+            .await?;
+    }
+
+    HttpResponse::json(RestoreRecordsReply { restored }, 200)
+}
+
+async fn register_webhook(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, webhook: Webhook) -> RpcResult<HttpResponse> {
+    info!("Register webhook '{}' for group '{}'", webhook.url, group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+    let x = WebhooksSender::via(lattice.actor(WEBHOOKS_ACTOR)?)
+        .register_webhook(ctx, &RegisterWebhookRequest {
+            webhook: Webhook {
+                id: None,
+                group: group_id.to_string(),
+                ..webhook
+            },
+            principal: Some(principal.clone()),
+        })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to register webhook"))
+    }
+}
+
+async fn list_webhooks(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str) -> RpcResult<HttpResponse> {
+    info!("List webhooks of group '{}'", group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+    let x = WebhooksSender::via(lattice.actor(WEBHOOKS_ACTOR)?)
+        .list_webhooks(ctx, &GroupWebhooksRequest {
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+async fn delete_webhook(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, webhook_id: &str) -> RpcResult<HttpResponse> {
+    info!("Delete webhook '{}' of group '{}'", webhook_id, group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+    let x = WebhooksSender::via(lattice.actor(WEBHOOKS_ACTOR)?)
+        .delete_webhook(ctx, &WebhookRef {
+            group: group_id.to_string(),
+            id: webhook_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    match x.success {
+        true => HttpResponse::json((), 200),
+        false => Ok(HttpResponse::not_found()),
+    }
+}
+
+async fn list_webhook_deliveries(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, webhook_id: &str) -> RpcResult<HttpResponse> {
+    info!("List deliveries of webhook '{}' of group '{}'", webhook_id, group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+    let x = WebhooksSender::via(lattice.actor(WEBHOOKS_ACTOR)?)
+        .list_webhook_deliveries(ctx, &WebhookRef {
+            group: group_id.to_string(),
+            id: webhook_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+async fn list_services(ctx: &Context, lattice: &dyn Lattice) -> RpcResult<HttpResponse> {
+    info!("List services");
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .list_services(ctx)
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+async fn get_service(ctx: &Context, lattice: &dyn Lattice, service_id: &str) -> RpcResult<HttpResponse> {
+    info!("Get service : {}", service_id);
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .get_service(ctx, service_id)
+        .await?;
+
+    match x.service {
+        Some(service) => HttpResponse::json(service, 200),
+        None => Ok(HttpResponse::not_found())
+    }
+}
+
+/// Vendors serve the groups and customers of the tenant of the principal registering them
+async fn register_vendor(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, vendor: Vendor) -> RpcResult<HttpResponse> {
+    info!("Register vendor '{}'", vendor.name);
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .register_vendor(ctx, &Vendor { tenant: principal.tenant.clone(), ..vendor })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to register vendor"))
+    }
+}
+
+async fn register_service(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, service: CatalogService) -> RpcResult<HttpResponse> {
+    info!("Register service '{}'", service.id);
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .register_service(ctx, &CatalogServiceRequest { credentials, service })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to register service"))
+    }
+}
+
+async fn update_service(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, service_id: &str, service: CatalogService) -> RpcResult<HttpResponse> {
+    info!("Update service '{}'", service_id);
+    let service = CatalogService { id: service_id.to_string(), ..service };
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .update_service(ctx, &CatalogServiceRequest { credentials, service })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to update service"))
+    }
+}
+
+async fn delete_service(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, service_id: &str) -> RpcResult<HttpResponse> {
+    info!("Delete service '{}'", service_id);
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .delete_service(ctx, &ServiceRef { credentials, id: service_id.to_string() })
+        .await?;
+
+    match x.success {
+        true => HttpResponse::json((), 200),
+        false => Ok(HttpResponse::not_found()),
+    }
+}
+
+async fn authorize_service_usage(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, service_id: &str, usage: UsageBody, idempotency_key: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Authorize usage of service '{}' for group '{}'", service_id, usage.group);
+    let x: AuthorizeServiceUsageReply = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .authorize_service_usage(ctx, &ServiceUsage {
+            credentials,
+            service: Service { id: service_id.to_string() },
+            client: usage.client,
+            group: usage.group,
+            units: usage.units,
+            timestamp: usage.timestamp,
+            idempotency_key: idempotency_key.map(|key| key.to_string()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+async fn buy_service(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, service_id: &str, order: OrderBody, idempotency_key: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Buy {} units of service '{}' for group '{}'", order.amount, service_id, order.group);
+    let x: BuyServiceReply = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .buy_service(ctx, &ServiceOrder {
+            credentials,
+            service: Service { id: service_id.to_string() },
+            group: order.group,
+            amount: order.amount,
+            timestamp: order.timestamp,
+            customer: order.customer,
+            idempotency_key: idempotency_key.map(|key| key.to_string()),
+        })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to buy service"))
+    }
+}
+
+async fn generate_invoice(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, period: &str) -> RpcResult<HttpResponse> {
+    info!("Generate invoice of group '{}' for {}", group_id, period);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+
+    let x = InvoicesSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .generate_invoice(ctx, &InvoiceRequest {
+            group: group_id.to_string(),
+            period: period.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+/// Checks a group rule, the group being read from the customers actor on behalf of the principal.
+/// The operators only find the groups of their tenant. Returns the response to send when the
+/// rule is not met.
+async fn check_group(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, rule: fn(&Principal, &CustomerGroup) -> bool) -> RpcResult<Option<HttpResponse>> {
+    let x = CustomerGroupsSender::via(lattice.actor(CUSTOMERS_ACTOR)?)
+        .find_customer_group(ctx, &FindCustomerGroupRequest {
+            id: group_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+    match x.group {
+        Some(group) if rule(principal, &group) => Ok(None),
+        Some(_) => Ok(Some(forbidden())),
+        None => Ok(Some(HttpResponse::not_found())),
+    }
+}
+
+/// Rule of the routes reading a group : the customers actor only finds the groups the principal
+/// reads, as one of their members or admins
+fn found(_: &Principal, _: &CustomerGroup) -> bool {
+    true
+}
+
+/// Invoices are readable by the group admins
+async fn get_invoice(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, period: &str) -> RpcResult<HttpResponse> {
+    info!("Get invoice of group '{}' for {}", group_id, period);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+
+    let x = InvoicesSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .get_invoice(ctx, &InvoiceRequest {
+            group: group_id.to_string(),
+            period: period.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    match x.invoice {
+        Some(invoice) => HttpResponse::json(invoice, 200),
+        None => Ok(HttpResponse::not_found())
+    }
+}
+
+async fn refund_order(ctx: &Context, lattice: &dyn Lattice, credentials: VendorCredentials, order_id: &str, refund: RefundBody, idempotency_key: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Refund order '{}'", order_id);
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .refund_order(ctx, &RefundRequest {
+            credentials,
+            order: order_id.to_string(),
+            amount: refund.amount,
+            reason: refund.reason,
+            timestamp: refund.timestamp,
+            idempotency_key: idempotency_key.map(|key| key.to_string()),
+        })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to refund order"))
+    }
+}
+
+/// Members gift units of their group
+async fn transfer_units(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, transfer: TransferBody, idempotency_key: Option<&str>) -> RpcResult<HttpResponse> {
+    info!("Transfer {} units from group '{}' to group '{}'", transfer.amount, group_id, transfer.to);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, found).await? {
+        return Ok(response);
+    }
+
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .transfer_units(ctx, &TransferRequest {
+            from: group_id.to_string(),
+            to: transfer.to,
+            amount: transfer.amount,
+            reason: transfer.reason,
+            timestamp: transfer.timestamp,
+            principal: Some(principal.clone()),
+            idempotency_key: idempotency_key.map(|key| key.to_string()),
+        })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to transfer units"))
+    }
+}
+
+async fn list_transfers(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str) -> RpcResult<HttpResponse> {
+    info!("List transfers of group '{}'", group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, found).await? {
+        return Ok(response);
+    }
+
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .list_transfers(ctx, &BucketRef {
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
+
+/// The group admins replace the thresholds of the group bucket, the ones kept firing no more in
+/// the period they fired in
+async fn set_bucket_thresholds(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str, thresholds: ThresholdList) -> RpcResult<HttpResponse> {
+    info!("Set thresholds of group '{}'", group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, can_manage_group).await? {
+        return Ok(response);
+    }
+
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .set_bucket_thresholds(ctx, &SetBucketThresholdsRequest {
+            thresholds: BucketThresholds {
+                group: group_id.to_string(),
+                thresholds,
+            },
+            principal: Some(principal.clone()),
+        })
+        .await?;
+    if x.success {
+        HttpResponse::json(x, 200)
+    } else {
+        Ok(HttpResponse::bad_request("Failed to set thresholds"))
+    }
+}
+
+async fn get_bucket_thresholds(ctx: &Context, lattice: &dyn Lattice, principal: &Principal, group_id: &str) -> RpcResult<HttpResponse> {
+    info!("Get thresholds of group '{}'", group_id);
+    if let Some(response) = check_group(ctx, lattice, principal, group_id, found).await? {
+        return Ok(response);
+    }
+
+    let x = ServiceVendorsSender::via(lattice.actor(SERVICE_VENDORS_ACTOR)?)
+        .get_bucket_thresholds(ctx, &BucketRef {
+            group: group_id.to_string(),
+            principal: Some(principal.clone()),
+        })
+        .await?;
+
+    HttpResponse::json(x, 200)
+}
@@ -0,0 +1,28 @@
+//! Route level access rules
+//!
+//! Operators may call every route. The other callers are checked here with what the principal
+//! tells, the group membership and admins being checked by the customers actor which owns the groups.
+
+use shared_bucket::policy::{can_read_customer, is_operator};
+use shared_bucket::Principal;
+
+/// Whether the principal may call the route
+pub fn allowed(principal: &Principal, method: &str, segments: &[&str]) -> bool {
+    if is_operator(principal) {
+        return true;
+    }
+
+    match (method, segments) {
+        ("GET", ["customers", customer_id]) => can_read_customer(principal, customer_id),
+        ("GET", ["customer-groups", _]) | ("PATCH", ["customer-groups", _]) => true,
+        ("GET", ["customer-groups", _, "customers"]) => true,
+        ("POST", ["customer-groups", _, "customers", _]) | ("DELETE", ["customer-groups", _, "customers", _]) => true,
+        ("GET", ["customer-groups", _, "invoices", _]) => true,
+        ("POST", ["customer-groups", _, "webhooks"]) | ("GET", ["customer-groups", _, "webhooks"]) => true,
+        ("DELETE", ["customer-groups", _, "webhooks", _]) | ("GET", ["customer-groups", _, "webhooks", _, "deliveries"]) => true,
+        ("POST", ["customer-groups", _, "transfers"]) | ("GET", ["customer-groups", _, "transfers"]) => true,
+        ("PUT", ["customer-groups", _, "thresholds"]) | ("GET", ["customer-groups", _, "thresholds"]) => true,
+        ("GET", ["services"]) | ("GET", ["services", _]) => true,
+        _ => false,
+    }
+}
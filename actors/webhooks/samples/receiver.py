@@ -0,0 +1,33 @@
+#!/usr/bin/env python3
+# Stub webhook receiver : prints the deliveries and checks their signature and timestamp
+#
+# usage: receiver.py <port> <secret> [status code to answer]
+
+import hashlib
+import hmac
+import sys
+import time
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+port = int(sys.argv[1])
+secret = sys.argv[2].encode()
+status = int(sys.argv[3]) if len(sys.argv) > 3 else 200
+tolerance = 5 * 60
+
+
+class Receiver(BaseHTTPRequestHandler):
+    def do_POST(self):
+        body = self.rfile.read(int(self.headers.get("Content-Length", 0)))
+        timestamp = self.headers.get("X-SharedBucket-Timestamp", "0")
+        expected = "sha256=" + hmac.new(secret, f"{timestamp}.".encode() + body, hashlib.sha256).hexdigest()
+        signature = self.headers.get("X-SharedBucket-Signature", "")
+        recent = timestamp.isdigit() and abs(time.time() - int(timestamp)) <= tolerance
+        valid = hmac.compare_digest(expected, signature) and recent
+        print(f"{self.headers.get('X-SharedBucket-Delivery')} "
+              f"{self.headers.get('X-SharedBucket-Event')} "
+              f"signature {'ok' if valid else 'INVALID'} : {body.decode()}")
+        self.send_response(status if valid else 401)
+        self.end_headers()
+
+
+HTTPServer(("", port), Receiver).serve_forever()
@@ -0,0 +1,49 @@
+//! Storage keys of a tenant.
+//!
+//! The webhooks of the groups of a tenant and their delivery logs are prefixed with
+//! `tenant:{tenant}:`, group ids being only unique in a tenant. The retry queue and its clock
+//! are platform wide, a pending delivery telling the tenant of its webhook.
+
+use anyhow::bail;
+use shared_bucket::Principal;
+
+#[derive(Debug, Default)]
+pub struct Keys {
+    tenant: Option<String>,
+    prefix: String,
+}
+
+impl Keys {
+    /// Keys of a tenant, the platform wide ones without tenant. The tenant may not contain the
+    /// `:` separator.
+    pub fn of(tenant: Option<&str>) -> anyhow::Result<Self> {
+        match tenant {
+            None => Ok(Keys::default()),
+            Some(tenant) if tenant.is_empty() || tenant.contains(':') => bail!("Invalid tenant '{}'", tenant),
+            Some(tenant) => Ok(Keys { tenant: Some(tenant.to_string()), prefix: format!("tenant:{}:", tenant) }),
+        }
+    }
+
+    /// Keys of the tenant of a principal
+    pub fn of_principal(principal: &Option<Principal>) -> anyhow::Result<Self> {
+        Self::of(principal.as_ref().and_then(|p| p.tenant.as_deref()))
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    pub fn webhook(&self, id: &str) -> String {
+        format!("{}webhook:{}", self.prefix, id)
+    }
+
+    /// Set of the webhook ids of a group
+    pub fn webhooks(&self, group: &str) -> String {
+        format!("{}webhooks:{}", self.prefix, group)
+    }
+
+    /// List of the delivery attempts of a webhook
+    pub fn deliveries(&self, id: &str) -> String {
+        format!("{}webhook_deliveries:{}", self.prefix, id)
+    }
+}
@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::bail;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use url::{Host, Url};
+use shared_bucket::lattice::{Lattice, Link, HTTPCLIENT, KEYVALUE, NUMBERGEN};
+use shared_bucket::{DeleteWebhookReply, GroupWebhooksRequest, Principal, RegisterWebhookReply, RegisterWebhookRequest, UsageEvent, Webhook, WebhookDelivery, WebhookDeliveryList, WebhookList, WebhookRef, Webhooks, WebhooksReceiver, USAGE_EVENT_SUBJECT_PREFIX};
+
+use wasmbus_rpc::actor::prelude::*;
+use wasmcloud_interface_httpclient::{HttpClient, HttpClientSender, HttpRequest};
+use wasmcloud_interface_keyvalue::{GetResponse, IncrementRequest, KeyValue, KeyValueSender, ListAddRequest, ListDelRequest, ListRangeRequest, SetAddRequest, SetDelRequest, SetRequest};
+#[cfg(not(target_arch = "wasm32"))]
+use log::{error, info};
+#[cfg(target_arch = "wasm32")]
+use wasmcloud_interface_logging::{error, info};
+use wasmcloud_interface_messaging::{MessageSubscriber, MessageSubscriberReceiver, SubMessage};
+use wasmcloud_interface_numbergen::{NumberGen, NumberGenSender};
+
+pub mod keys;
+
+use keys::Keys;
+
+/// Every message published on this subject is one retry tick
+pub const RETRY_SUBJECT: &str = "sharedbucket.webhooks.retry";
+
+/// Deliveries are marked failed after this number of attempts
+const MAX_ATTEMPTS: u32 = 6;
+
+/// Attempts kept in the delivery log of a webhook, the oldest ones are dropped
+pub const MAX_LOGGED_ATTEMPTS: u32 = 100;
+
+// natively only the message dispatch is derived : the wasm exports would clash once linked with the other actors
+#[cfg_attr(target_arch = "wasm32", derive(Actor))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(wasmbus_rpc::provider::prelude::Provider))]
+#[derive(Debug, HealthResponder)]
+#[services(Actor, Webhooks, MessageSubscriber)]
+pub struct WebhooksActor {
+    lattice: Box<dyn Lattice>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for WebhooksActor {
+    fn default() -> Self {
+        Self::new(shared_bucket::lattice::Host::default())
+    }
+}
+
+/// A delivery waiting for its next attempt
+#[derive(Debug, Deserialize, Serialize)]
+struct PendingDelivery {
+    delivery: WebhookDelivery,
+    body: String,
+    next_tick: i32,
+    /// Tenant of the webhook, absent for the platform wide ones
+    #[serde(default)]
+    tenant: Option<String>,
+}
+
+/// Hex encoded HMAC-SHA256 of the timestamp, a `.` and the body
+fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(format!("{}.", timestamp).as_bytes());
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Whether an IPv4 address is only reachable from the host or its network
+fn is_internal_ipv4(ip: &Ipv4Addr) -> bool {
+    ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast()
+}
+
+/// Whether an IPv6 address is only reachable from the host or its network : loopback, unique
+/// local (`fc00::/7`), link local (`fe80::/10`) or mapping an internal IPv4 address
+fn is_internal_ipv6(ip: &Ipv6Addr) -> bool {
+    let segment = ip.segments()[0];
+    ip.is_loopback() || ip.is_unspecified() || (segment & 0xfe00) == 0xfc00 || (segment & 0xffc0) == 0xfe80
+        || ip.to_ipv4_mapped().is_some_and(|ip| is_internal_ipv4(&ip))
+}
+
+/// Checks a webhook url is an http(s) url of a public host, the actor posting from inside the platform.
+/// Host names are not resolved : they must be resolved to public addresses by the DNS of the platform.
+fn check_url(url: &str) -> anyhow::Result<()> {
+    let parsed = match Url::parse(url) {
+        Ok(parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => parsed,
+        _ => bail!("Invalid webhook url '{}'", url),
+    };
+    let internal = match parsed.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        },
+        Some(Host::Ipv4(ip)) => is_internal_ipv4(&ip),
+        Some(Host::Ipv6(ip)) => is_internal_ipv6(&ip),
+        None => true,
+    };
+    if internal {
+        bail!("The webhook url '{}' is not of a public host", url);
+    }
+
+    Ok(())
+}
+
+impl WebhooksActor {
+    pub fn new<L: Lattice + 'static>(lattice: L) -> Self {
+        WebhooksActor { lattice: Box::new(lattice) }
+    }
+
+    fn keys(principal: &Option<Principal>) -> RpcResult<Keys> {
+        Keys::of_principal(principal).map_err(|e| RpcError::Other(e.to_string()))
+    }
+
+    fn kv(&self) -> RpcResult<KeyValueSender<Link>> {
+        Ok(KeyValueSender::via(self.lattice.provider(KEYVALUE)?))
+    }
+
+    async fn generate_guid(&self, ctx: &Context) -> RpcResult<String> {
+        NumberGenSender::via(self.lattice.provider(NUMBERGEN)?).generate_guid(ctx).await
+    }
+
+    async fn find(&self, ctx: &Context, keys: &Keys, id: &str) -> anyhow::Result<Option<Webhook>> {
+        match self.kv()?.get(ctx, &keys.webhook(id)).await {
+            Ok(GetResponse { exists: true, value }) => Ok(Some(serde_json::from_str(&value)?)),
+            Ok(GetResponse { exists: false, .. }) => Ok(None),
+            Err(e) => bail!("Error searching for webhook '{}' : {:?}", id, e)
+        }
+    }
+
+    async fn find_in_group(&self, ctx: &Context, keys: &Keys, webhook: &WebhookRef) -> anyhow::Result<Option<Webhook>> {
+        Ok(self.find(ctx, keys, &webhook.id).await?.filter(|w| w.group == webhook.group))
+    }
+
+    async fn find_group_webhooks(&self, ctx: &Context, keys: &Keys, group: &str) -> anyhow::Result<Vec<Webhook>> {
+        let ids = self.kv()?.set_query(ctx, &keys.webhooks(group)).await?;
+
+        let mut webhooks = Vec::new();
+        for id in ids {
+            if let Some(webhook) = self.find(ctx, keys, &id).await? {
+                webhooks.push(webhook);
+            }
+        }
+
+        Ok(webhooks)
+    }
+
+    async fn register(&self, ctx: &Context, keys: &Keys, webhook: &Webhook) -> anyhow::Result<RegisterWebhookReply> {
+        check_url(&webhook.url)?;
+
+        let id = self.generate_guid(ctx).await?;
+        let secret = match &webhook.secret {
+            Some(secret) if !secret.is_empty() => secret.clone(),
+            _ => self.generate_guid(ctx).await?,
+        };
+        info!("Registering webhook {} for group '{}'", id, webhook.group);
+
+        let webhook = Webhook {
+            id: Some(id.clone()),
+            secret: Some(secret.clone()),
+            ..webhook.clone()
+        };
+        let request = SetRequest {
+            key: keys.webhook(&id),
+            value: serde_json::to_string(&webhook)?,
+            expires: 0,
+        };
+        self.kv()?.set(ctx, &request).await?;
+        self.kv()?.set_add(ctx, &SetAddRequest {
+            set_name: keys.webhooks(&webhook.group),
+            value: id.clone(),
+        }).await?;
+
+        Ok(RegisterWebhookReply { success: true, id, secret: Some(secret) })
+    }
+
+    async fn delete(&self, ctx: &Context, keys: &Keys, webhook: &WebhookRef) -> anyhow::Result<bool> {
+        if self.find_in_group(ctx, keys, webhook).await?.is_none() {
+            return Ok(false);
+        }
+
+        info!("Deleting webhook {} of group '{}'", webhook.id, webhook.group);
+        self.kv()?.set_del(ctx, &SetDelRequest {
+            set_name: keys.webhooks(&webhook.group),
+            value: webhook.id.clone(),
+        }).await?;
+        self.kv()?.del(ctx, &keys.webhook(&webhook.id)).await?;
+        self.kv()?.list_clear(ctx, &keys.deliveries(&webhook.id)).await?;
+
+        Ok(true)
+    }
+
+    /// Starts a delivery of the event to every webhook of its group accepting its type
+    async fn dispatch_event(&self, ctx: &Context, body: &[u8]) -> anyhow::Result<()> {
+        let event: UsageEvent = serde_json::from_slice(body)?;
+        let group = match &event.group {
+            Some(group) => group,
+            None => return Ok(()),
+        };
+        let keys = Keys::of(event.tenant.as_deref())?;
+
+        for webhook in self.find_group_webhooks(ctx, &keys, group).await? {
+            let accepted = match &webhook.event_types {
+                Some(types) if !types.is_empty() => types.contains(&event.event_type),
+                _ => true,
+            };
+            if !accepted {
+                continue;
+            }
+
+            let pending = PendingDelivery {
+                delivery: WebhookDelivery {
+                    id: self.generate_guid(ctx).await?,
+                    webhook: webhook.id.clone().unwrap_or_default(),
+                    event: event.id.clone(),
+                    event_type: event.event_type.clone(),
+                    attempt: 0,
+                    status_code: None,
+                    status: "retrying".to_string(),
+                },
+                body: String::from_utf8(body.to_vec())?,
+                next_tick: 0,
+                tenant: keys.tenant().map(String::from),
+            };
+            if let Err(e) = self.attempt(ctx, &keys, &webhook, pending).await {
+                error!("Error delivering event {} to webhook {:?} : {}", event.id, webhook.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Posts the delivery to the webhook, logs the attempt and schedules the next one on failure
+    async fn attempt(&self, ctx: &Context, keys: &Keys, webhook: &Webhook, pending: PendingDelivery) -> anyhow::Result<()> {
+        let mut delivery = pending.delivery;
+        delivery.attempt += 1;
+
+        let secret = webhook.secret.as_deref().unwrap_or_default();
+        let timestamp = self.current_time(ctx).await?;
+        let headers = HashMap::from([
+            ("Content-Type".to_string(), vec!["application/json".to_string()]),
+            ("X-SharedBucket-Event".to_string(), vec![delivery.event_type.clone()]),
+            ("X-SharedBucket-Delivery".to_string(), vec![delivery.id.clone()]),
+            ("X-SharedBucket-Timestamp".to_string(), vec![timestamp.to_string()]),
+            ("X-SharedBucket-Signature".to_string(), vec![format!("sha256={}", sign(secret, timestamp, pending.body.as_bytes()))]),
+        ]);
+        let request = HttpRequest {
+            method: "POST".to_string(),
+            url: webhook.url.clone(),
+            headers,
+            body: pending.body.as_bytes().to_vec(),
+        };
+
+        delivery.status_code = match HttpClientSender::via(self.lattice.provider(HTTPCLIENT)?).request(ctx, &request).await {
+            Ok(response) => Some(response.status_code),
+            Err(e) => {
+                error!("Error calling webhook '{}' : {}", webhook.url, e);
+                None
+            },
+        };
+        delivery.status = match delivery.status_code {
+            Some(code) if (200..300).contains(&code) => "delivered",
+            _ if delivery.attempt >= MAX_ATTEMPTS => "failed",
+            _ => "retrying",
+        }.to_string();
+        info!("Delivery {} attempt {} : {}", delivery.id, delivery.attempt, delivery.status);
+
+        self.log(ctx, keys, &delivery).await?;
+
+        if delivery.status == "retrying" {
+            let next_tick = self.current_tick(ctx).await? + (1 << (delivery.attempt - 1));
+            let request = SetRequest {
+                key: format!("webhook_retry:{}", delivery.id),
+                value: serde_json::to_string(&PendingDelivery { delivery: delivery.clone(), body: pending.body, next_tick, tenant: pending.tenant })?,
+                expires: 0,
+            };
+            self.kv()?.set(ctx, &request).await?;
+            self.kv()?.set_add(ctx, &SetAddRequest {
+                set_name: "webhook_retries".to_string(),
+                value: delivery.id.clone(),
+            }).await?;
+        } else if delivery.attempt > 1 {
+            self.kv()?.set_del(ctx, &SetDelRequest {
+                set_name: "webhook_retries".to_string(),
+                value: delivery.id.clone(),
+            }).await?;
+            self.kv()?.del(ctx, &format!("webhook_retry:{}", delivery.id)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Appends the attempt to the delivery log of its webhook, dropping the oldest attempts
+    /// past `MAX_LOGGED_ATTEMPTS`
+    async fn log(&self, ctx: &Context, keys: &Keys, delivery: &WebhookDelivery) -> anyhow::Result<()> {
+        let list_name = keys.deliveries(&delivery.webhook);
+        let logged = self.kv()?.list_add(ctx, &ListAddRequest {
+            list_name: list_name.clone(),
+            value: serde_json::to_string(delivery)?,
+        }).await?;
+        if logged <= MAX_LOGGED_ATTEMPTS {
+            return Ok(());
+        }
+
+        let oldest = self.kv()?.list_range(ctx, &ListRangeRequest {
+            list_name: list_name.clone(),
+            start: 0,
+            stop: (logged - MAX_LOGGED_ATTEMPTS) as i32 - 1,
+        }).await?;
+        for value in oldest {
+            self.kv()?.list_del(ctx, &ListDelRequest { list_name: list_name.clone(), value }).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn current_tick(&self, ctx: &Context) -> anyhow::Result<i32> {
+        match self.kv()?.get(ctx, "webhook_ticks").await {
+            Ok(GetResponse { exists: true, value }) => Ok(value.parse()?),
+            Ok(GetResponse { exists: false, .. }) => Ok(0),
+            Err(e) => bail!("Error reading webhook ticks : {:?}", e)
+        }
+    }
+
+    /// Unix time in seconds of the latest tick telling it, the actors having no clock
+    async fn current_time(&self, ctx: &Context) -> anyhow::Result<i64> {
+        match self.kv()?.get(ctx, "webhook_time").await {
+            Ok(GetResponse { exists: true, value }) => Ok(value.parse()?),
+            Ok(GetResponse { exists: false, .. }) => Ok(0),
+            Err(e) => bail!("Error reading webhook time : {:?}", e)
+        }
+    }
+
+    /// Advances the retry clock by one tick, keeps the Unix time the tick may hold and attempts
+    /// the deliveries that are due
+    async fn retry_due(&self, ctx: &Context, body: &[u8]) -> anyhow::Result<()> {
+        let tick = self.kv()?.increment(ctx, &IncrementRequest {
+            key: "webhook_ticks".to_string(),
+            value: 1,
+        }).await?;
+        if let Some(time) = std::str::from_utf8(body).ok().and_then(|body| body.trim().parse::<i64>().ok()) {
+            self.kv()?.set(ctx, &SetRequest { key: "webhook_time".to_string(), value: time.to_string(), expires: 0 }).await?;
+        }
+
+        for id in self.kv()?.set_query(ctx, "webhook_retries").await? {
+            let pending: PendingDelivery = match self.kv()?.get(ctx, &format!("webhook_retry:{}", id)).await? {
+                GetResponse { exists: true, value } => serde_json::from_str(&value)?,
+                GetResponse { exists: false, .. } => continue,
+            };
+            if pending.next_tick > tick {
+                continue;
+            }
+
+            let keys = Keys::of(pending.tenant.as_deref())?;
+            match self.find(ctx, &keys, &pending.delivery.webhook).await? {
+                Some(webhook) => {
+                    if let Err(e) = self.attempt(ctx, &keys, &webhook, pending).await {
+                        error!("Error retrying delivery {} : {}", id, e);
+                    }
+                },
+                None => {
+                    // the webhook was deleted in the meantime
+                    self.kv()?.set_del(ctx, &SetDelRequest {
+                        set_name: "webhook_retries".to_string(),
+                        value: id.clone(),
+                    }).await?;
+                    self.kv()?.del(ctx, &format!("webhook_retry:{}", id)).await?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Implementation of Webhooks trait methods
+#[async_trait]
+impl Webhooks for WebhooksActor {
+    async fn register_webhook(&self, ctx: &Context, arg: &RegisterWebhookRequest) -> RpcResult<RegisterWebhookReply> {
+        info!("register webhook");
+
+        match self.register(ctx, &Self::keys(&arg.principal)?, &arg.webhook).await {
+            Ok(reply) => Ok(reply),
+            Err(e) => {
+                error!("Error registering webhook : {}", e);
+                Ok(RegisterWebhookReply {
+                    success: false,
+                    id: "".to_string(),
+                    secret: None,
+                })
+            },
+        }
+    }
+
+    async fn list_webhooks(&self, ctx: &Context, arg: &GroupWebhooksRequest) -> RpcResult<WebhookList> {
+        match self.find_group_webhooks(ctx, &Self::keys(&arg.principal)?, &arg.group).await {
+            Ok(webhooks) => Ok(webhooks.into_iter().map(|webhook| Webhook { secret: None, ..webhook }).collect()),
+            Err(e) => Err(RpcError::Other(format!("{}", e))),
+        }
+    }
+
+    async fn delete_webhook(&self, ctx: &Context, arg: &WebhookRef) -> RpcResult<DeleteWebhookReply> {
+        info!("delete webhook");
+
+        let success = match self.delete(ctx, &Self::keys(&arg.principal)?, arg).await {
+            Ok(deleted) => deleted,
+            Err(e) => {
+                error!("Error deleting webhook {} : {}", arg.id, e);
+                false
+            },
+        };
+
+        Ok(DeleteWebhookReply { success })
+    }
+
+    async fn list_webhook_deliveries(&self, ctx: &Context, arg: &WebhookRef) -> RpcResult<WebhookDeliveryList> {
+        let keys = Self::keys(&arg.principal)?;
+        match self.find_in_group(ctx, &keys, arg).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Err(RpcError::Other(format!("The webhook '{}' doesn't exists", arg.id))),
+            Err(e) => return Err(RpcError::Other(format!("{}", e))),
+        }
+
+        let deliveries = self.kv()?.list_range(ctx, &ListRangeRequest {
+            list_name: keys.deliveries(&arg.id),
+            start: 0,
+            stop: -1,
+        }).await?;
+
+        deliveries.iter()
+            .map(|delivery| serde_json::from_str(delivery).map_err(|e| RpcError::Deser(format!("{}", e))))
+            .collect()
+    }
+}
+
+/// Implementation of MessageSubscriber trait methods
+#[async_trait]
+impl MessageSubscriber for WebhooksActor {
+    async fn handle_message(&self, ctx: &Context, arg: &SubMessage) -> RpcResult<()> {
+        let result = if arg.subject == RETRY_SUBJECT {
+            self.retry_due(ctx, &arg.body).await
+        } else if arg.subject.starts_with(USAGE_EVENT_SUBJECT_PREFIX) {
+            self.dispatch_event(ctx, &arg.body).await
+        } else {
+            Ok(())
+        };
+
+        if let Err(e) = result {
+            error!("Error handling message on '{}' : {}", arg.subject, e);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+//! Deserializes arbitrary messages as every type of the interface, as the actors and providers
+//! dispatch them: deserializing must fail rather than panic or hang, and a deserialized value must
+//! serialize to a message deserializing to the same value.
+//!
+//! Run with `cargo fuzz run decode` from the `rust` folder, on a nightly toolchain.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wasmbus_rpc::common::{deserialize, serialize};
+
+include!("../../tests/codecs/mod.rs");
+
+fuzz_target!(|data: &[u8]| {
+    // the messages are compared, as values holding NaN are not equal to themselves
+    macro_rules! round_trip {
+        ($type:ident, $encode:ident, $decode:ident) => {
+            if let Ok(value) = deserialize::<shared_bucket::$type>(data) {
+                let message = serialize(&value).unwrap();
+                let decoded = deserialize::<shared_bucket::$type>(&message).unwrap();
+                assert_eq!(serialize(&decoded).unwrap(), message, "{} does not round-trip", stringify!($type));
+            }
+        };
+    }
+    for_each_codec!(round_trip);
+});
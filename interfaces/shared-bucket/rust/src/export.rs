@@ -0,0 +1,49 @@
+//! Cursors of the export of the customers, groups and members of a tenant
+
+use crate::ExportedRecord;
+
+/// Cursor of the page of the export ending with a record : `customer:{id}`, `group:{id}` or
+/// `member:{group}:{customer}`, ids holding no `:`. The next page starts with the records
+/// following it.
+pub fn cursor(record: &ExportedRecord) -> String {
+    match record {
+        ExportedRecord { member: Some(member), .. } => format!("member:{}:{}", member.group, member.customer),
+        ExportedRecord { group: Some(group), .. } => format!("group:{}", group.id.as_deref().unwrap_or_default()),
+        ExportedRecord { customer, .. } => format!("customer:{}", customer.as_ref().and_then(|customer| customer.id.as_deref()).unwrap_or_default()),
+    }
+}
+
+/// Group of a cursor after a group or one of its members, whose members may go on in the next page
+pub fn cursor_group(cursor: &str) -> Option<&str> {
+    match cursor.split_once(':') {
+        Some(("group", group)) => Some(group),
+        Some(("member", member)) => member.split_once(':').map(|(group, _)| group),
+        _ => None,
+    }
+}
+
+/// Group of a group record or of a member record
+pub fn record_group(record: &ExportedRecord) -> Option<&str> {
+    match record {
+        ExportedRecord { group: Some(group), .. } => group.id.as_deref(),
+        ExportedRecord { member: Some(member), .. } => Some(&member.group),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomerGroup, Membership};
+
+    #[test]
+    fn cursors_tell_their_group() {
+        let group = ExportedRecord { kind: "group".to_string(), group: Some(CustomerGroup { id: Some("g1".to_string()), ..Default::default() }), ..Default::default() };
+        let member = ExportedRecord { kind: "member".to_string(), member: Some(Membership { group: "g1".to_string(), customer: "c1".to_string() }), ..Default::default() };
+
+        assert_eq!((cursor(&group).as_str(), cursor(&member).as_str()), ("group:g1", "member:g1:c1"));
+        assert_eq!((cursor_group(&cursor(&group)), cursor_group(&cursor(&member))), (Some("g1"), Some("g1")));
+        assert_eq!((record_group(&group), record_group(&member)), (Some("g1"), Some("g1")));
+        assert_eq!((cursor_group("customer:c1"), cursor_group("member:g1")), (None, None));
+    }
+}
@@ -0,0 +1,147 @@
+//! Invoice building from the ledger of a group bucket
+
+use std::collections::BTreeMap;
+
+use crate::tax;
+use crate::{Invoice, InvoiceLine, InvoiceOrder, LedgerEntry};
+
+/// Ledger entry of a bucket debit
+pub const USAGE: &str = "usage";
+
+/// Ledger entry of a bucket credit
+pub const ORDER: &str = "order";
+
+/// Ledger entry of a bucket debit compensating an order
+pub const REFUND: &str = "refund";
+
+/// Ledger entry of a bucket debit moving units to another bucket
+pub const TRANSFER_OUT: &str = "transfer_out";
+
+/// Ledger entry of a bucket credit moving units from another bucket
+pub const TRANSFER_IN: &str = "transfer_in";
+
+/// Invoice of the ledger entries of a period : one line per service, member and unit price,
+/// sorted by service then member. Each line is taxed at the rate of its member, the lines of the
+/// members without rate being taxed at the default rate and flagged. Line amounts and taxes are
+/// rounded to the cent and the totals are the sums of the rounded values.
+pub fn build<F: Fn(&str) -> Option<f64>>(number: String, group: &str, period: &str, entries: &[LedgerEntry], tax_rate: F, default_rate: f64) -> Invoice {
+    let mut lines: BTreeMap<(String, String, u64), InvoiceLine> = BTreeMap::new();
+    let mut orders = Vec::new();
+
+    for entry in entries {
+        match entry.kind.as_str() {
+            USAGE => {
+                let member = entry.client.clone().unwrap_or_default();
+                let unit_price = entry.unit_price.unwrap_or_default();
+                let quantity = entry.units.unwrap_or_default();
+                let line = lines.entry((entry.service.clone(), member.clone(), unit_price.to_bits()))
+                    .or_insert_with(|| InvoiceLine {
+                        service: entry.service.clone(),
+                        member,
+                        unit: entry.unit.clone().unwrap_or_default(),
+                        unit_price,
+                        ..Default::default()
+                    });
+                line.quantity += quantity;
+                line.bucket_units += entry.bucket_units;
+                line.amount += quantity * unit_price;
+            },
+            ORDER => orders.push(InvoiceOrder {
+                id: entry.order.clone().unwrap_or_default(),
+                service: entry.service.clone(),
+                amount: entry.bucket_units,
+            }),
+            REFUND => orders.push(InvoiceOrder {
+                id: entry.order.clone().unwrap_or_default(),
+                service: entry.service.clone(),
+                amount: -entry.bucket_units,
+            }),
+            _ => {},
+        }
+    }
+
+    let lines: Vec<InvoiceLine> = lines.into_values()
+        .map(|line| {
+            let amount = tax::round(line.amount);
+            let (tax_rate, unknown_member) = match tax_rate(&line.member) {
+                Some(rate) => (rate, false),
+                None => (default_rate, true),
+            };
+            InvoiceLine { amount, tax_rate, taxes: tax::taxes(amount, tax_rate), unknown_member, ..line }
+        })
+        .collect();
+    let subtotal = tax::round(lines.iter().map(|line| line.amount).sum::<f64>());
+    let taxes = tax::round(lines.iter().map(|line| line.taxes).sum::<f64>());
+
+    Invoice {
+        number,
+        group: group.to_string(),
+        period: period.to_string(),
+        lines,
+        orders,
+        subtotal,
+        taxes,
+        total: tax::round(subtotal + taxes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasmbus_rpc::Timestamp;
+
+    use super::*;
+
+    fn usage(service: &str, client: &str, units: f64, unit_price: f64) -> LedgerEntry {
+        LedgerEntry {
+            kind: USAGE.to_string(),
+            service: service.to_string(),
+            client: Some(client.to_string()),
+            unit: Some("minute".to_string()),
+            units: Some(units),
+            unit_price: Some(unit_price),
+            bucket_units: units,
+            timestamp: Timestamp { sec: 0, nsec: 0 },
+            ..Default::default()
+        }
+    }
+
+    fn rate(member: &str) -> Option<f64> {
+        match member {
+            "alice" => Some(20.0),
+            "bob" => Some(7.0),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn lines_are_taxed_at_the_member_rate() {
+        let entries = vec![usage("voice", "alice", 10.0, 0.5), usage("voice", "bob", 10.0, 0.5), usage("voice", "alice", 10.0, 0.5)];
+        let invoice = build("INV-202401-000001".to_string(), "family", "2024-01", &entries, rate, 0.0);
+
+        assert_eq!(invoice.lines.len(), 2);
+        assert_eq!((invoice.lines[0].member.as_str(), invoice.lines[0].amount, invoice.lines[0].taxes), ("alice", 10.0, 2.0));
+        assert_eq!((invoice.lines[1].member.as_str(), invoice.lines[1].amount, invoice.lines[1].taxes), ("bob", 5.0, 0.35));
+        assert_eq!((invoice.subtotal, invoice.taxes, invoice.total), (15.0, 2.35, 17.35));
+    }
+
+    #[test]
+    fn lines_are_rounded_before_being_summed() {
+        // 0.015 per line : rounded lines sum to 0.04, where rounding the sum would give 0.03
+        let entries = vec![usage("sms", "alice", 1.0, 0.015), usage("voice", "alice", 1.0, 0.015)];
+        let invoice = build("INV-202401-000001".to_string(), "family", "2024-01", &entries, |_| Some(0.0), 0.0);
+
+        assert_eq!(invoice.lines.iter().map(|line| line.amount).collect::<Vec<_>>(), vec![0.02, 0.02]);
+        assert_eq!(invoice.subtotal, 0.04);
+        assert_eq!(invoice.total, 0.04);
+    }
+
+    #[test]
+    fn lines_of_unknown_members_are_taxed_at_the_default_rate() {
+        let entries = vec![usage("voice", "alice", 10.0, 0.5), usage("voice", "carol", 10.0, 0.5)];
+        let invoice = build("INV-202401-000001".to_string(), "family", "2024-01", &entries, rate, 10.0);
+
+        assert_eq!((invoice.lines[0].taxes, invoice.lines[0].unknown_member), (1.0, false));
+        assert_eq!((invoice.lines[1].member.as_str(), invoice.lines[1].taxes, invoice.lines[1].unknown_member), ("carol", 0.5, true));
+        assert_eq!(invoice.total, 11.5);
+    }
+}
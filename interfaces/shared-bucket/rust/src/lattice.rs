@@ -0,0 +1,76 @@
+//! Links of the actors to the other actors and to the capability providers.
+//!
+//! Actors send their messages through a `Lattice` rather than building their senders with
+//! `to_actor` or `new`, which only exist in wasm32 : natively, the tests link the actors
+//! together in process (see the integration tests).
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use wasmbus_rpc::common::{Context, Message, SendOpts, Transport};
+use wasmbus_rpc::error::RpcResult;
+
+/// Contract of the KeyValue capability providers
+pub const KEYVALUE: &str = "wasmcloud:keyvalue";
+
+/// Contract of the Messaging capability providers
+pub const MESSAGING: &str = "wasmcloud:messaging";
+
+/// Contract of the HttpClient capability providers
+pub const HTTPCLIENT: &str = "wasmcloud:httpclient";
+
+/// Contract of the builtin number generator
+pub const NUMBERGEN: &str = "wasmcloud:builtin:numbergen";
+
+/// Transport to an actor or a capability provider, to build senders with `via`
+#[derive(Clone)]
+pub struct Link(Arc<dyn Transport + Sync>);
+
+impl Link {
+    pub fn new<T: Transport + Sync + 'static>(transport: T) -> Self {
+        Link(Arc::new(transport))
+    }
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Link")
+    }
+}
+
+#[async_trait]
+impl Transport for Link {
+    async fn send(&self, ctx: &Context, req: Message<'_>, opts: Option<SendOpts>) -> RpcResult<Vec<u8>> {
+        self.0.send(ctx, req, opts).await
+    }
+
+    fn set_timeout(&self, interval: std::time::Duration) {
+        self.0.set_timeout(interval)
+    }
+}
+
+/// Where the messages of an actor are sent
+pub trait Lattice: fmt::Debug + Send + Sync {
+    /// Link to an actor, by its call alias
+    fn actor(&self, alias: &str) -> RpcResult<Link>;
+
+    /// Link to the capability provider of a contract, on the default link
+    fn provider(&self, contract_id: &str) -> RpcResult<Link>;
+}
+
+/// The wasmCloud host running the actor
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default)]
+pub struct Host {}
+
+#[cfg(target_arch = "wasm32")]
+impl Lattice for Host {
+    fn actor(&self, alias: &str) -> RpcResult<Link> {
+        Ok(Link::new(wasmbus_rpc::actor::prelude::WasmHost::to_actor(alias)?))
+    }
+
+    fn provider(&self, contract_id: &str) -> RpcResult<Link> {
+        Ok(Link::new(wasmbus_rpc::actor::prelude::WasmHost::to_provider(contract_id, "default")?))
+    }
+}
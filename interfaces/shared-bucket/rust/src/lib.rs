@@ -2,3 +2,19 @@
 
 mod shared_bucket;
 pub use shared_bucket::*;
+
+pub mod export;
+pub mod invoice;
+pub mod lattice;
+pub mod policy;
+pub mod rating;
+pub mod tax;
+
+/// Version of the usage event JSON schema (see usage-event.v1.schema.json)
+pub const USAGE_EVENT_SCHEMA_VERSION: &str = "1";
+
+/// Prefix of the errors returned when a record is not at the revision the caller expects
+pub const REVISION_MISMATCH: &str = "revision mismatch";
+
+/// Prefix of the messaging subjects usage events are published on
+pub const USAGE_EVENT_SUBJECT_PREFIX: &str = "sharedbucket.events";
@@ -0,0 +1,38 @@
+//! Access rules, applied by the API actor and enforced again by the actors owning the data
+
+use crate::{CustomerGroup, Principal};
+
+/// Role granting access to every customer and group
+pub const OPERATOR_ROLE: &str = "operator";
+
+/// Role of the actors billing the customers of a tenant : they only read the customers, whose
+/// location selects the taxes, and the groups units are transferred to
+pub const BILLING_ROLE: &str = "billing";
+
+/// Prefix of the errors returned to callers that are not allowed
+pub const ACCESS_DENIED: &str = "access denied";
+
+pub fn is_operator(principal: &Principal) -> bool {
+    principal.roles.as_ref().is_some_and(|roles| roles.iter().any(|role| role == OPERATOR_ROLE))
+}
+
+pub fn is_billing(principal: &Principal) -> bool {
+    principal.roles.as_ref().is_some_and(|roles| roles.iter().any(|role| role == BILLING_ROLE))
+}
+
+/// Customers only see themselves
+pub fn can_read_customer(principal: &Principal, customer: &str) -> bool {
+    is_operator(principal) || is_billing(principal) || principal.id == customer
+}
+
+/// Members and admins of a group see its customers. The members are stored apart from the group :
+/// whether the principal is one of them is told by the caller.
+pub fn can_read_group(principal: &Principal, group: &CustomerGroup, member: bool) -> bool {
+    member || is_billing(principal) || can_manage_group(principal, group)
+}
+
+/// Admins of a group manage its members
+pub fn can_manage_group(principal: &Principal, group: &CustomerGroup) -> bool {
+    let admin = group.admins.as_ref().is_some_and(|admins| admins.contains(&principal.id));
+    admin || is_operator(principal)
+}
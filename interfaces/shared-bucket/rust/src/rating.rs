@@ -0,0 +1,199 @@
+//! Rating of service usage into bucket units, following the catalog tariff of the service
+
+use crate::{CatalogService, ServiceUsage, Tariff};
+
+pub const FLAT: &str = "flat";
+pub const TIERED: &str = "tiered";
+pub const TIME_OF_DAY: &str = "time_of_day";
+pub const VOLUME: &str = "volume";
+
+/// Bucket units to debit for a usage, `period_units` being the units of the service
+/// already consumed by the group in the period (used by tiered and volume tariffs).
+/// Services without tariff are rated at their conversion rate.
+pub fn rate(usage: &ServiceUsage, service: &CatalogService, period_units: f64) -> Result<f64, String> {
+    if usage.units < 0.0 || !usage.units.is_finite() {
+        return Err(format!("Invalid number of units {}", usage.units));
+    }
+
+    let tariff = match &service.tariff {
+        Some(tariff) => tariff,
+        None => return Ok(usage.units * service.conversion_rate),
+    };
+    let default_rate = tariff.rate.unwrap_or(service.conversion_rate);
+
+    match tariff.kind.as_str() {
+        FLAT => Ok(usage.units * default_rate),
+        TIERED => Ok(tiered(tariff, default_rate, usage.units, period_units)),
+        TIME_OF_DAY => Ok(usage.units * hour_rate(tariff, default_rate, usage.timestamp.sec)),
+        VOLUME => volume(tariff, default_rate, usage.units, period_units),
+        kind => Err(format!("Unknown tariff kind '{}' for service '{}'", kind, service.id)),
+    }
+}
+
+/// Each unit is rated at the rate of the tier the period cumulated units fall in,
+/// units beyond the last tier at the default rate
+fn tiered(tariff: &Tariff, default_rate: f64, units: f64, period_units: f64) -> f64 {
+    let mut position = period_units;
+    let mut remaining = units;
+    let mut debit = 0.0;
+
+    for tier in tariff.tiers.iter().flatten() {
+        let limit = tier.up_to.unwrap_or(f64::INFINITY);
+        if remaining <= 0.0 {
+            break;
+        }
+        if position >= limit {
+            continue;
+        }
+        let in_tier = remaining.min(limit - position);
+        debit += in_tier * tier.rate;
+        position += in_tier;
+        remaining -= in_tier;
+    }
+
+    debit + remaining.max(0.0) * default_rate
+}
+
+/// Rate of the hour (UTC) the usage happened in
+fn hour_rate(tariff: &Tariff, default_rate: f64, sec: i64) -> f64 {
+    let hour = (sec.rem_euclid(86_400) / 3_600) as u8;
+
+    tariff.hours.iter().flatten()
+        .find(|rate| {
+            if rate.from_hour < rate.to_hour {
+                rate.from_hour <= hour && hour < rate.to_hour
+            } else {
+                hour >= rate.from_hour || hour < rate.to_hour
+            }
+        })
+        .map_or(default_rate, |rate| rate.rate)
+}
+
+/// The best discount reached by the period cumulated units applies to the whole usage
+fn volume(tariff: &Tariff, default_rate: f64, units: f64, period_units: f64) -> Result<f64, String> {
+    let total = period_units + units;
+    let percentage = tariff.discounts.iter().flatten()
+        .filter(|discount| discount.from <= total)
+        .map(|discount| discount.percentage)
+        .fold(0.0, f64::max);
+
+    if !(0.0..=100.0).contains(&percentage) {
+        return Err(format!("Invalid discount percentage {}", percentage));
+    }
+
+    Ok(units * default_rate * (1.0 - percentage / 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use wasmbus_rpc::Timestamp;
+
+    use super::*;
+    use crate::{HourRate, Service, TariffTier, VendorCredentials, VolumeDiscount};
+
+    fn usage(units: f64, sec: i64) -> ServiceUsage {
+        ServiceUsage {
+            credentials: VendorCredentials { vendor: "vendor".to_string(), secret: "secret".to_string() },
+            service: Service { id: "voice".to_string() },
+            client: "alice".to_string(),
+            group: "family".to_string(),
+            units,
+            timestamp: Timestamp { sec, nsec: 0 },
+            idempotency_key: None,
+        }
+    }
+
+    fn service(conversion_rate: f64, tariff: Option<Tariff>) -> CatalogService {
+        CatalogService {
+            id: "voice".to_string(),
+            name: "Voice".to_string(),
+            unit: "minute".to_string(),
+            unit_price: 0.1,
+            bucket_unit: "unit".to_string(),
+            conversion_rate,
+            tariff,
+            ..Default::default()
+        }
+    }
+
+    fn tariff(kind: &str) -> Tariff {
+        Tariff { kind: kind.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn without_tariff_uses_conversion_rate() {
+        assert_eq!(rate(&usage(10.0, 0), &service(1.0, None), 0.0), Ok(10.0));
+        assert_eq!(rate(&usage(10.0, 0), &service(0.5, None), 0.0), Ok(5.0));
+    }
+
+    #[test]
+    fn flat() {
+        let roaming = service(1.0, Some(Tariff { rate: Some(3.0), ..tariff(FLAT) }));
+        assert_eq!(rate(&usage(4.0, 0), &roaming, 100.0), Ok(12.0));
+
+        let sms = service(0.5, Some(tariff(FLAT)));
+        assert_eq!(rate(&usage(3.0, 0), &sms, 0.0), Ok(1.5));
+    }
+
+    #[test]
+    fn tiered_splits_usage_across_tiers() {
+        let tiers = vec![
+            TariffTier { up_to: Some(100.0), rate: 1.0 },
+            TariffTier { up_to: Some(200.0), rate: 0.5 },
+            TariffTier { up_to: None, rate: 0.25 },
+        ];
+        let service = service(1.0, Some(Tariff { tiers: Some(tiers), ..tariff(TIERED) }));
+
+        assert_eq!(rate(&usage(50.0, 0), &service, 0.0), Ok(50.0));
+        // 10 units at 1.0, 100 at 0.5, 10 at 0.25
+        assert_eq!(rate(&usage(120.0, 0), &service, 90.0), Ok(10.0 + 50.0 + 2.5));
+        assert_eq!(rate(&usage(8.0, 0), &service, 500.0), Ok(2.0));
+    }
+
+    #[test]
+    fn tiered_rates_units_beyond_last_tier_at_default_rate() {
+        let tiers = vec![TariffTier { up_to: Some(10.0), rate: 0.0 }];
+        let service = service(2.0, Some(Tariff { tiers: Some(tiers), ..tariff(TIERED) }));
+
+        assert_eq!(rate(&usage(15.0, 0), &service, 0.0), Ok(10.0));
+    }
+
+    #[test]
+    fn time_of_day() {
+        let hours = vec![
+            HourRate { from_hour: 8, to_hour: 20, rate: 1.0 },
+            HourRate { from_hour: 22, to_hour: 6, rate: 0.25 },
+        ];
+        let service = service(1.0, Some(Tariff { rate: Some(0.5), hours: Some(hours), ..tariff(TIME_OF_DAY) }));
+        let at = |hour: i64| 1_700_000_000 - 1_700_000_000 % 86_400 + hour * 3_600;
+
+        assert_eq!(rate(&usage(4.0, at(8)), &service, 0.0), Ok(4.0));
+        assert_eq!(rate(&usage(4.0, at(19)), &service, 0.0), Ok(4.0));
+        assert_eq!(rate(&usage(4.0, at(20)), &service, 0.0), Ok(2.0));
+        assert_eq!(rate(&usage(4.0, at(23)), &service, 0.0), Ok(1.0));
+        assert_eq!(rate(&usage(4.0, at(3)), &service, 0.0), Ok(1.0));
+    }
+
+    #[test]
+    fn volume_discount_applies_once_reached() {
+        let discounts = vec![
+            VolumeDiscount { from: 100.0, percentage: 25.0 },
+            VolumeDiscount { from: 1000.0, percentage: 50.0 },
+        ];
+        let service = service(1.0, Some(Tariff { discounts: Some(discounts), ..tariff(VOLUME) }));
+
+        assert_eq!(rate(&usage(10.0, 0), &service, 0.0), Ok(10.0));
+        assert_eq!(rate(&usage(10.0, 0), &service, 95.0), Ok(7.5));
+        assert_eq!(rate(&usage(10.0, 0), &service, 5000.0), Ok(5.0));
+    }
+
+    #[test]
+    fn invalid_usage_or_tariff_is_refused() {
+        assert!(rate(&usage(-1.0, 0), &service(1.0, None), 0.0).is_err());
+        assert!(rate(&usage(1.0, 0), &service(1.0, Some(tariff("weekend"))), 0.0).is_err());
+
+        let discounts = vec![VolumeDiscount { from: 0.0, percentage: 150.0 }];
+        let service = service(1.0, Some(Tariff { discounts: Some(discounts), ..tariff(VOLUME) }));
+        assert!(rate(&usage(1.0, 0), &service, 0.0).is_err());
+    }
+}
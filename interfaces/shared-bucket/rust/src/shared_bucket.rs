@@ -24,6 +24,9 @@ pub const SMITHY_VERSION: &str = "1.0";
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub struct AddCustomerReply {
+    /// Revision of the group
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
     #[serde(default)]
     pub success: bool,
 }
@@ -38,7 +41,13 @@ pub fn encode_add_customer_reply<W: wasmbus_rpc::cbor::Write>(
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(1)?;
+    e.map(2)?;
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
     e.str("success")?;
     e.bool(val.success)?;
     Ok(())
@@ -50,6 +59,7 @@ pub fn decode_add_customer_reply(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
 ) -> Result<AddCustomerReply, RpcError> {
     let __result = {
+        let mut revision: Option<Option<u64>> = Some(None);
         let mut success: Option<bool> = None;
 
         let is_array = match d.datatype()? {
@@ -65,7 +75,15 @@ pub fn decode_add_customer_reply(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => success = Some(d.bool()?),
+                    0 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    1 => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
@@ -73,17 +91,27 @@ pub fn decode_add_customer_reply(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
                     "success" => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
         }
         AddCustomerReply {
+            revision: revision.unwrap(),
+
             success: if let Some(__x) = success {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field AddCustomerReply.success (#0)".to_string(),
+                    "missing field AddCustomerReply.success (#1)".to_string(),
                 ));
             },
         }
@@ -94,8 +122,14 @@ pub fn decode_add_customer_reply(
 pub struct AddCustomerRequest {
     #[serde(default)]
     pub customer: String,
+    /// Id of the group
     #[serde(default)]
     pub group: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    /// Revision the group must be at, the change is refused with a revision mismatch otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
 }
 
 // Encode AddCustomerRequest as CBOR and append to output stream
@@ -108,11 +142,23 @@ pub fn encode_add_customer_request<W: wasmbus_rpc::cbor::Write>(
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(2)?;
+    e.map(4)?;
     e.str("customer")?;
     e.str(&val.customer)?;
     e.str("group")?;
     e.str(&val.group)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
     Ok(())
 }
 
@@ -124,6 +170,8 @@ pub fn decode_add_customer_request(
     let __result = {
         let mut customer: Option<String> = None;
         let mut group: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut revision: Option<Option<u64>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
@@ -140,6 +188,25 @@ pub fn decode_add_customer_request(
                 match __i {
                     0 => customer = Some(d.str()?.to_string()),
                     1 => group = Some(d.str()?.to_string()),
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    3 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+
                     _ => d.skip()?,
                 }
             }
@@ -149,6 +216,24 @@ pub fn decode_add_customer_request(
                 match d.str()? {
                     "customer" => customer = Some(d.str()?.to_string()),
                     "group" => group = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
                     _ => d.skip()?,
                 }
             }
@@ -169,46 +254,53 @@ pub fn decode_add_customer_request(
                     "missing field AddCustomerRequest.group (#1)".to_string(),
                 ));
             },
+            principal: principal.unwrap(),
+            revision: revision.unwrap(),
         }
     };
     Ok(__result)
 }
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct CreateCustomerGroupReply {
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct AuthorizeServiceUsageReply {
     #[serde(default)]
-    pub success: bool,
+    pub authorized: bool,
+    #[serde(default)]
+    pub balance: f64,
 }
 
-// Encode CreateCustomerGroupReply as CBOR and append to output stream
+// Encode AuthorizeServiceUsageReply as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_create_customer_group_reply<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_authorize_service_usage_reply<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &CreateCustomerGroupReply,
+    val: &AuthorizeServiceUsageReply,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(1)?;
-    e.str("success")?;
-    e.bool(val.success)?;
+    e.map(2)?;
+    e.str("authorized")?;
+    e.bool(val.authorized)?;
+    e.str("balance")?;
+    e.f64(val.balance)?;
     Ok(())
 }
 
-// Decode CreateCustomerGroupReply from cbor input stream
+// Decode AuthorizeServiceUsageReply from cbor input stream
 #[doc(hidden)]
-pub fn decode_create_customer_group_reply(
+pub fn decode_authorize_service_usage_reply(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<CreateCustomerGroupReply, RpcError> {
+) -> Result<AuthorizeServiceUsageReply, RpcError> {
     let __result = {
-        let mut success: Option<bool> = None;
+        let mut authorized: Option<bool> = None;
+        let mut balance: Option<f64> = None;
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct CreateCustomerGroupReply, expected array or map".to_string(),
+                    "decoding struct AuthorizeServiceUsageReply, expected array or map".to_string(),
                 ))
             }
         };
@@ -216,7 +308,8 @@ pub fn decode_create_customer_group_reply(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => success = Some(d.bool()?),
+                    0 => authorized = Some(d.bool()?),
+                    1 => balance = Some(d.f64()?),
                     _ => d.skip()?,
                 }
             }
@@ -224,64 +317,87 @@ pub fn decode_create_customer_group_reply(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "success" => success = Some(d.bool()?),
+                    "authorized" => authorized = Some(d.bool()?),
+                    "balance" => balance = Some(d.f64()?),
                     _ => d.skip()?,
                 }
             }
         }
-        CreateCustomerGroupReply {
-            success: if let Some(__x) = success {
+        AuthorizeServiceUsageReply {
+            authorized: if let Some(__x) = authorized {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field CreateCustomerGroupReply.success (#0)".to_string(),
+                    "missing field AuthorizeServiceUsageReply.authorized (#0)".to_string(),
+                ));
+            },
+
+            balance: if let Some(__x) = balance {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field AuthorizeServiceUsageReply.balance (#1)".to_string(),
                 ));
             },
         }
     };
     Ok(__result)
 }
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct CreateCustomerReply {
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Bucket {
     #[serde(default)]
-    pub id: String,
+    pub balance: f64,
+    /// Total units credited to the bucket
     #[serde(default)]
-    pub success: bool,
+    pub credited: f64,
+    #[serde(default)]
+    pub group: String,
+    /// Bucket unit of the first service credited, the services drawing from the bucket must use it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
 }
 
-// Encode CreateCustomerReply as CBOR and append to output stream
+// Encode Bucket as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_create_customer_reply<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_bucket<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &CreateCustomerReply,
+    val: &Bucket,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(2)?;
-    e.str("id")?;
-    e.str(&val.id)?;
-    e.str("success")?;
-    e.bool(val.success)?;
+    e.map(4)?;
+    e.str("balance")?;
+    e.f64(val.balance)?;
+    e.str("credited")?;
+    e.f64(val.credited)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.unit.as_ref() {
+        e.str("unit")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
     Ok(())
 }
 
-// Decode CreateCustomerReply from cbor input stream
+// Decode Bucket from cbor input stream
 #[doc(hidden)]
-pub fn decode_create_customer_reply(
-    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<CreateCustomerReply, RpcError> {
+pub fn decode_bucket(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Bucket, RpcError> {
     let __result = {
-        let mut id: Option<String> = None;
-        let mut success: Option<bool> = None;
+        let mut balance: Option<f64> = None;
+        let mut credited: Option<f64> = None;
+        let mut group: Option<String> = None;
+        let mut unit: Option<Option<String>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct CreateCustomerReply, expected array or map".to_string(),
+                    "decoding struct Bucket, expected array or map".to_string(),
                 ))
             }
         };
@@ -289,8 +405,18 @@ pub fn decode_create_customer_reply(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => id = Some(d.str()?.to_string()),
-                    1 => success = Some(d.bool()?),
+                    0 => balance = Some(d.f64()?),
+                    1 => credited = Some(d.f64()?),
+                    2 => group = Some(d.str()?.to_string()),
+                    3 => {
+                        unit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+
                     _ => d.skip()?,
                 }
             }
@@ -298,118 +424,94 @@ pub fn decode_create_customer_reply(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "id" => id = Some(d.str()?.to_string()),
-                    "success" => success = Some(d.bool()?),
+                    "balance" => balance = Some(d.f64()?),
+                    "credited" => credited = Some(d.f64()?),
+                    "group" => group = Some(d.str()?.to_string()),
+                    "unit" => {
+                        unit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
                     _ => d.skip()?,
                 }
             }
         }
-        CreateCustomerReply {
-            id: if let Some(__x) = id {
+        Bucket {
+            balance: if let Some(__x) = balance {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field CreateCustomerReply.id (#0)".to_string(),
+                    "missing field Bucket.balance (#0)".to_string(),
                 ));
             },
 
-            success: if let Some(__x) = success {
+            credited: if let Some(__x) = credited {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field CreateCustomerReply.success (#1)".to_string(),
+                    "missing field Bucket.credited (#1)".to_string(),
+                ));
+            },
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Bucket.group (#2)".to_string(),
                 ));
             },
+            unit: unit.unwrap(),
         }
     };
     Ok(__result)
 }
+/// Bucket of a group of the tenant of the principal
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct Customer {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub address: Option<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub city: Option<String>,
-    #[serde(default)]
-    pub email: String,
-    #[serde(rename = "firstName")]
+pub struct BucketRef {
     #[serde(default)]
-    pub first_name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "lastName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_name: Option<String>,
+    pub group: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub telephone: Option<String>,
+    pub principal: Option<Principal>,
 }
 
-// Encode Customer as CBOR and append to output stream
+// Encode BucketRef as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_customer<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_bucket_ref<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &Customer,
+    val: &BucketRef,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(7)?;
-    if let Some(val) = val.address.as_ref() {
-        e.str("address")?;
-        e.str(val)?;
-    } else {
-        e.null()?;
-    }
-    if let Some(val) = val.city.as_ref() {
-        e.str("city")?;
-        e.str(val)?;
-    } else {
-        e.null()?;
-    }
-    e.str("email")?;
-    e.str(&val.email)?;
-    e.str("firstName")?;
-    e.str(&val.first_name)?;
-    if let Some(val) = val.id.as_ref() {
-        e.str("id")?;
-        e.str(val)?;
-    } else {
-        e.null()?;
-    }
-    if let Some(val) = val.last_name.as_ref() {
-        e.str("lastName")?;
-        e.str(val)?;
-    } else {
-        e.null()?;
-    }
-    if let Some(val) = val.telephone.as_ref() {
-        e.str("telephone")?;
-        e.str(val)?;
+    e.map(2)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
     } else {
         e.null()?;
     }
     Ok(())
 }
 
-// Decode Customer from cbor input stream
+// Decode BucketRef from cbor input stream
 #[doc(hidden)]
-pub fn decode_customer(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Customer, RpcError> {
+pub fn decode_bucket_ref(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<BucketRef, RpcError> {
     let __result = {
-        let mut address: Option<Option<String>> = Some(None);
-        let mut city: Option<Option<String>> = Some(None);
-        let mut email: Option<String> = None;
-        let mut first_name: Option<String> = None;
-        let mut id: Option<Option<String>> = Some(None);
-        let mut last_name: Option<Option<String>> = Some(None);
-        let mut telephone: Option<Option<String>> = Some(None);
+        let mut group: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct Customer, expected array or map".to_string(),
+                    "decoding struct BucketRef, expected array or map".to_string(),
                 ))
             }
         };
@@ -417,46 +519,15 @@ pub fn decode_customer(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Custome
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => {
-                        address = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
+                    0 => group = Some(d.str()?.to_string()),
                     1 => {
-                        city = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
-                    2 => email = Some(d.str()?.to_string()),
-                    3 => first_name = Some(d.str()?.to_string()),
-                    4 => {
-                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
-                    5 => {
-                        last_name = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
-                    6 => {
-                        telephone = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(d.str()?.to_string()))
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
                         }
                     }
 
@@ -467,123 +538,199 @@ pub fn decode_customer(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Custome
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "address" => {
-                        address = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
-                    "city" => {
-                        city = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(d.str()?.to_string()))
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
                         }
                     }
-                    "email" => email = Some(d.str()?.to_string()),
-                    "firstName" => first_name = Some(d.str()?.to_string()),
-                    "id" => {
-                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
-                    }
-                    "lastName" => {
-                        last_name = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        BucketRef {
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field BucketRef.group (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BucketThresholds {
+    #[serde(default)]
+    pub group: String,
+    pub thresholds: ThresholdList,
+}
+
+// Encode BucketThresholds as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_bucket_thresholds<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &BucketThresholds,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    e.str("thresholds")?;
+    encode_threshold_list(e, &val.thresholds)?;
+    Ok(())
+}
+
+// Decode BucketThresholds from cbor input stream
+#[doc(hidden)]
+pub fn decode_bucket_thresholds(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<BucketThresholds, RpcError> {
+    let __result = {
+        let mut group: Option<String> = None;
+        let mut thresholds: Option<ThresholdList> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct BucketThresholds, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => group = Some(d.str()?.to_string()),
+                    1 => {
+                        thresholds = Some(decode_threshold_list(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ThresholdList': {}", e)
+                        })?)
                     }
-                    "telephone" => {
-                        telephone = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
-                            d.skip()?;
-                            Some(None)
-                        } else {
-                            Some(Some(d.str()?.to_string()))
-                        }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "thresholds" => {
+                        thresholds = Some(decode_threshold_list(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ThresholdList': {}", e)
+                        })?)
                     }
                     _ => d.skip()?,
                 }
             }
         }
-        Customer {
-            address: address.unwrap(),
-            city: city.unwrap(),
-
-            email: if let Some(__x) = email {
+        BucketThresholds {
+            group: if let Some(__x) = group {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field Customer.email (#2)".to_string(),
+                    "missing field BucketThresholds.group (#0)".to_string(),
                 ));
             },
 
-            first_name: if let Some(__x) = first_name {
+            thresholds: if let Some(__x) = thresholds {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field Customer.first_name (#3)".to_string(),
+                    "missing field BucketThresholds.thresholds (#1)".to_string(),
                 ));
             },
-            id: id.unwrap(),
-            last_name: last_name.unwrap(),
-            telephone: telephone.unwrap(),
         }
     };
     Ok(__result)
 }
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct CustomerGroup {
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BuyServiceReply {
+    #[serde(default)]
+    pub balance: f64,
+    #[serde(default)]
+    pub id: String,
+    /// Price of the bucket units bought, at the catalog price of the service
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customers: Option<GroupCustomers>,
+    pub price: Option<f64>,
     #[serde(default)]
-    pub name: String,
+    pub success: bool,
+    /// Taxes on the price, by the location of the buyer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taxes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
 }
 
-// Encode CustomerGroup as CBOR and append to output stream
+// Encode BuyServiceReply as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_customer_group<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_buy_service_reply<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &CustomerGroup,
+    val: &BuyServiceReply,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(2)?;
-    if let Some(val) = val.customers.as_ref() {
-        e.str("customers")?;
-        encode_group_customers(e, val)?;
+    e.map(6)?;
+    e.str("balance")?;
+    e.f64(val.balance)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.price.as_ref() {
+        e.str("price")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
+    if let Some(val) = val.taxes.as_ref() {
+        e.str("taxes")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.total.as_ref() {
+        e.str("total")?;
+        e.f64(*val)?;
     } else {
         e.null()?;
     }
-    e.str("name")?;
-    e.str(&val.name)?;
     Ok(())
 }
 
-// Decode CustomerGroup from cbor input stream
+// Decode BuyServiceReply from cbor input stream
 #[doc(hidden)]
-pub fn decode_customer_group(
+pub fn decode_buy_service_reply(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<CustomerGroup, RpcError> {
+) -> Result<BuyServiceReply, RpcError> {
     let __result = {
-        let mut customers: Option<Option<GroupCustomers>> = Some(None);
-        let mut name: Option<String> = None;
+        let mut balance: Option<f64> = None;
+        let mut id: Option<String> = None;
+        let mut price: Option<Option<f64>> = Some(None);
+        let mut success: Option<bool> = None;
+        let mut taxes: Option<Option<f64>> = Some(None);
+        let mut total: Option<Option<f64>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct CustomerGroup, expected array or map".to_string(),
+                    "decoding struct BuyServiceReply, expected array or map".to_string(),
                 ))
             }
         };
@@ -591,17 +738,34 @@ pub fn decode_customer_group(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => {
-                        customers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                    0 => balance = Some(d.f64()?),
+                    1 => id = Some(d.str()?.to_string()),
+                    2 => {
+                        price = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(decode_group_customers(d).map_err(|e| {
-                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
-                            })?))
+                            Some(Some(d.f64()?))
                         }
                     }
-                    1 => name = Some(d.str()?.to_string()),
+                    3 => success = Some(d.bool()?),
+                    4 => {
+                        taxes = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    5 => {
+                        total = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+
                     _ => d.skip()?,
                 }
             }
@@ -609,75 +773,166 @@ pub fn decode_customer_group(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "customers" => {
-                        customers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                    "balance" => balance = Some(d.f64()?),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "price" => {
+                        price = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(decode_group_customers(d).map_err(|e| {
-                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
-                            })?))
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
+                    "taxes" => {
+                        taxes = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "total" => {
+                        total = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
                         }
                     }
-                    "name" => name = Some(d.str()?.to_string()),
                     _ => d.skip()?,
                 }
             }
         }
-        CustomerGroup {
-            customers: customers.unwrap(),
+        BuyServiceReply {
+            balance: if let Some(__x) = balance {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field BuyServiceReply.balance (#0)".to_string(),
+                ));
+            },
 
-            name: if let Some(__x) = name {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field BuyServiceReply.id (#1)".to_string(),
+                ));
+            },
+            price: price.unwrap(),
+
+            success: if let Some(__x) = success {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field CustomerGroup.name (#1)".to_string(),
+                    "missing field BuyServiceReply.success (#3)".to_string(),
                 ));
             },
+            taxes: taxes.unwrap(),
+            total: total.unwrap(),
         }
     };
     Ok(__result)
 }
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct FindCustomerReply {
+/// Service of the catalog : usage is priced in its own unit and drawn from buckets in the bucket unit
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CatalogService {
+    /// Unit of the buckets the service draws from
+    #[serde(rename = "bucketUnit")]
+    #[serde(default)]
+    pub bucket_unit: String,
+    /// Bucket units consumed per unit of service, when there is no tariff
+    #[serde(rename = "conversionRate")]
+    #[serde(default)]
+    pub conversion_rate: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer: Option<Customer>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// How usage is rated into bucket units
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tariff: Option<Tariff>,
+    /// Unit the service is consumed in (minute, MB, SMS...)
+    #[serde(default)]
+    pub unit: String,
+    /// Price of a unit of service
+    #[serde(rename = "unitPrice")]
+    #[serde(default)]
+    pub unit_price: f64,
+    /// Owner of the service, set by the actor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
 }
 
-// Encode FindCustomerReply as CBOR and append to output stream
+// Encode CatalogService as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_find_customer_reply<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_catalog_service<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &FindCustomerReply,
+    val: &CatalogService,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(1)?;
-    if let Some(val) = val.customer.as_ref() {
-        e.str("customer")?;
-        encode_customer(e, val)?;
+    e.map(9)?;
+    e.str("bucketUnit")?;
+    e.str(&val.bucket_unit)?;
+    e.str("conversionRate")?;
+    e.f64(val.conversion_rate)?;
+    if let Some(val) = val.description.as_ref() {
+        e.str("description")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("name")?;
+    e.str(&val.name)?;
+    if let Some(val) = val.tariff.as_ref() {
+        e.str("tariff")?;
+        encode_tariff(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("unit")?;
+    e.str(&val.unit)?;
+    e.str("unitPrice")?;
+    e.f64(val.unit_price)?;
+    if let Some(val) = val.vendor.as_ref() {
+        e.str("vendor")?;
+        e.str(val)?;
     } else {
         e.null()?;
     }
     Ok(())
 }
 
-// Decode FindCustomerReply from cbor input stream
+// Decode CatalogService from cbor input stream
 #[doc(hidden)]
-pub fn decode_find_customer_reply(
+pub fn decode_catalog_service(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<FindCustomerReply, RpcError> {
+) -> Result<CatalogService, RpcError> {
     let __result = {
-        let mut customer: Option<Option<Customer>> = Some(None);
+        let mut bucket_unit: Option<String> = None;
+        let mut conversion_rate: Option<f64> = None;
+        let mut description: Option<Option<String>> = Some(None);
+        let mut id: Option<String> = None;
+        let mut name: Option<String> = None;
+        let mut tariff: Option<Option<Tariff>> = Some(None);
+        let mut unit: Option<String> = None;
+        let mut unit_price: Option<f64> = None;
+        let mut vendor: Option<Option<String>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct FindCustomerReply, expected array or map".to_string(),
+                    "decoding struct CatalogService, expected array or map".to_string(),
                 ))
             }
         };
@@ -685,16 +940,38 @@ pub fn decode_find_customer_reply(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => {
-                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                    0 => bucket_unit = Some(d.str()?.to_string()),
+                    1 => conversion_rate = Some(d.f64()?),
+                    2 => {
+                        description = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(decode_customer(d).map_err(|e| {
-                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => id = Some(d.str()?.to_string()),
+                    4 => name = Some(d.str()?.to_string()),
+                    5 => {
+                        tariff = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_tariff(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Tariff': {}", e)
                             })?))
                         }
                     }
+                    6 => unit = Some(d.str()?.to_string()),
+                    7 => unit_price = Some(d.f64()?),
+                    8 => {
+                        vendor = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
 
                     _ => d.skip()?,
                 }
@@ -703,84 +980,109 @@ pub fn decode_find_customer_reply(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "customer" => {
-                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                    "bucketUnit" => bucket_unit = Some(d.str()?.to_string()),
+                    "conversionRate" => conversion_rate = Some(d.f64()?),
+                    "description" => {
+                        description = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
                             d.skip()?;
                             Some(None)
                         } else {
-                            Some(Some(decode_customer(d).map_err(|e| {
-                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "id" => id = Some(d.str()?.to_string()),
+                    "name" => name = Some(d.str()?.to_string()),
+                    "tariff" => {
+                        tariff = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_tariff(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Tariff': {}", e)
                             })?))
                         }
                     }
+                    "unit" => unit = Some(d.str()?.to_string()),
+                    "unitPrice" => unit_price = Some(d.f64()?),
+                    "vendor" => {
+                        vendor = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
                     _ => d.skip()?,
                 }
             }
         }
-        FindCustomerReply {
-            customer: customer.unwrap(),
-        }
-    };
-    Ok(__result)
-}
-pub type GroupCustomers = Vec<String>;
+        CatalogService {
+            bucket_unit: if let Some(__x) = bucket_unit {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.bucket_unit (#0)".to_string(),
+                ));
+            },
 
-// Encode GroupCustomers as CBOR and append to output stream
-#[doc(hidden)]
-#[allow(unused_mut)]
-pub fn encode_group_customers<W: wasmbus_rpc::cbor::Write>(
-    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &GroupCustomers,
-) -> RpcResult<()>
-where
-    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
-{
-    e.array(val.len() as u64)?;
-    for item in val.iter() {
-        e.str(item)?;
-    }
-    Ok(())
-}
+            conversion_rate: if let Some(__x) = conversion_rate {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.conversion_rate (#1)".to_string(),
+                ));
+            },
+            description: description.unwrap(),
 
-// Decode GroupCustomers from cbor input stream
-#[doc(hidden)]
-pub fn decode_group_customers(
-    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<GroupCustomers, RpcError> {
-    let __result = {
-        if let Some(n) = d.array()? {
-            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
-            for _ in 0..(n as usize) {
-                arr.push(d.str()?.to_string())
-            }
-            arr
-        } else {
-            // indefinite array
-            let mut arr: Vec<String> = Vec::new();
-            loop {
-                match d.datatype() {
-                    Err(_) => break,
-                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
-                    Ok(_) => arr.push(d.str()?.to_string()),
-                }
-            }
-            arr
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.id (#3)".to_string(),
+                ));
+            },
+
+            name: if let Some(__x) = name {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.name (#4)".to_string(),
+                ));
+            },
+            tariff: tariff.unwrap(),
+
+            unit: if let Some(__x) = unit {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.unit (#6)".to_string(),
+                ));
+            },
+
+            unit_price: if let Some(__x) = unit_price {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogService.unit_price (#7)".to_string(),
+                ));
+            },
+            vendor: vendor.unwrap(),
         }
     };
     Ok(__result)
 }
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct HealthzReply {
+pub struct CatalogServiceReply {
     #[serde(default)]
     pub success: bool,
 }
 
-// Encode HealthzReply as CBOR and append to output stream
+// Encode CatalogServiceReply as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_healthz_reply<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_catalog_service_reply<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &HealthzReply,
+    val: &CatalogServiceReply,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
@@ -791,11 +1093,11 @@ where
     Ok(())
 }
 
-// Decode HealthzReply from cbor input stream
+// Decode CatalogServiceReply from cbor input stream
 #[doc(hidden)]
-pub fn decode_healthz_reply(
+pub fn decode_catalog_service_reply(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<HealthzReply, RpcError> {
+) -> Result<CatalogServiceReply, RpcError> {
     let __result = {
         let mut success: Option<bool> = None;
 
@@ -804,7 +1106,7 @@ pub fn decode_healthz_reply(
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct HealthzReply, expected array or map".to_string(),
+                    "decoding struct CatalogServiceReply, expected array or map".to_string(),
                 ))
             }
         };
@@ -825,151 +1127,216 @@ pub fn decode_healthz_reply(
                 }
             }
         }
-        HealthzReply {
+        CatalogServiceReply {
             success: if let Some(__x) = success {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field HealthzReply.success (#0)".to_string(),
+                    "missing field CatalogServiceReply.success (#0)".to_string(),
                 ));
             },
         }
     };
     Ok(__result)
 }
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct HealthzRequest {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CatalogServiceRequest {
+    pub credentials: VendorCredentials,
+    pub service: CatalogService,
+}
 
-// Encode HealthzRequest as CBOR and append to output stream
+// Encode CatalogServiceRequest as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_healthz_request<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_catalog_service_request<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    _val: &HealthzRequest,
+    val: &CatalogServiceRequest,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(0)?;
+    e.map(2)?;
+    e.str("credentials")?;
+    encode_vendor_credentials(e, &val.credentials)?;
+    e.str("service")?;
+    encode_catalog_service(e, &val.service)?;
     Ok(())
 }
 
-// Decode HealthzRequest from cbor input stream
+// Decode CatalogServiceRequest from cbor input stream
 #[doc(hidden)]
-pub fn decode_healthz_request(
+pub fn decode_catalog_service_request(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<HealthzRequest, RpcError> {
+) -> Result<CatalogServiceRequest, RpcError> {
     let __result = {
+        let mut credentials: Option<VendorCredentials> = None;
+        let mut service: Option<CatalogService> = None;
+
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct HealthzRequest, expected array or map".to_string(),
+                    "decoding struct CatalogServiceRequest, expected array or map".to_string(),
                 ))
             }
         };
         if is_array {
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
-                d.skip()?;
+                match __i {
+                    0 => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    1 => {
+                        service = Some(decode_catalog_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
             }
         } else {
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
-                d.str()?;
-                d.skip()?;
+                match d.str()? {
+                    "credentials" => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    "service" => {
+                        service = Some(decode_catalog_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
             }
         }
-        HealthzRequest {}
+        CatalogServiceRequest {
+            credentials: if let Some(__x) = credentials {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogServiceRequest.credentials (#0)".to_string(),
+                ));
+            },
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CatalogServiceRequest.service (#1)".to_string(),
+                ));
+            },
+        }
     };
     Ok(__result)
 }
-pub type ListCustomersReply = Vec<Customer>;
+pub type CatalogServices = Vec<CatalogService>;
 
-// Encode ListCustomersReply as CBOR and append to output stream
+// Encode CatalogServices as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_list_customers_reply<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_catalog_services<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &ListCustomersReply,
+    val: &CatalogServices,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
     e.array(val.len() as u64)?;
     for item in val.iter() {
-        encode_customer(e, item)?;
+        encode_catalog_service(e, item)?;
     }
     Ok(())
 }
 
-// Decode ListCustomersReply from cbor input stream
+// Decode CatalogServices from cbor input stream
 #[doc(hidden)]
-pub fn decode_list_customers_reply(
+pub fn decode_catalog_services(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<ListCustomersReply, RpcError> {
-    let __result =
-        {
-            if let Some(n) = d.array()? {
-                let mut arr: Vec<Customer> = Vec::with_capacity(n as usize);
-                for _ in 0..(n as usize) {
-                    arr.push(decode_customer(d).map_err(|e| {
-                        format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
-                    })?)
-                }
-                arr
-            } else {
-                // indefinite array
-                let mut arr: Vec<Customer> = Vec::new();
-                loop {
-                    match d.datatype() {
-                        Err(_) => break,
-                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
-                        Ok(_) => arr.push(decode_customer(d).map_err(|e| {
-                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
-                        })?),
-                    }
+) -> Result<CatalogServices, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<CatalogService> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_catalog_service(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<CatalogService> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_catalog_service(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                    })?),
                 }
-                arr
             }
-        };
+            arr
+        }
+    };
     Ok(__result)
 }
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct Service {
+pub struct CreateCustomerGroupReply {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     #[serde(default)]
-    pub id: String,
+    pub success: bool,
 }
 
-// Encode Service as CBOR and append to output stream
+// Encode CreateCustomerGroupReply as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_service<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_create_customer_group_reply<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &Service,
+    val: &CreateCustomerGroupReply,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
-    e.map(1)?;
-    e.str("id")?;
-    e.str(&val.id)?;
+    e.map(2)?;
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
     Ok(())
 }
 
-// Decode Service from cbor input stream
+// Decode CreateCustomerGroupReply from cbor input stream
 #[doc(hidden)]
-pub fn decode_service(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Service, RpcError> {
+pub fn decode_create_customer_group_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<CreateCustomerGroupReply, RpcError> {
     let __result = {
-        let mut id: Option<String> = None;
+        let mut id: Option<Option<String>> = Some(None);
+        let mut success: Option<bool> = None;
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct Service, expected array or map".to_string(),
+                    "decoding struct CreateCustomerGroupReply, expected array or map".to_string(),
                 ))
             }
         };
@@ -977,7 +1344,15 @@ pub fn decode_service(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Service,
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => id = Some(d.str()?.to_string()),
+                    0 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
@@ -985,61 +1360,77 @@ pub fn decode_service(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Service,
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "id" => id = Some(d.str()?.to_string()),
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
         }
-        Service {
-            id: if let Some(__x) = id {
+        CreateCustomerGroupReply {
+            id: id.unwrap(),
+
+            success: if let Some(__x) = success {
                 __x
             } else {
-                return Err(RpcError::Deser("missing field Service.id (#0)".to_string()));
+                return Err(RpcError::Deser(
+                    "missing field CreateCustomerGroupReply.success (#1)".to_string(),
+                ));
             },
         }
     };
     Ok(__result)
 }
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct ServiceOrder {
-    #[serde(default)]
-    pub amount: String,
-    pub service: Service,
+pub struct CreateCustomerGroupRequest {
+    pub group: CustomerGroup,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
 }
 
-// Encode ServiceOrder as CBOR and append to output stream
+// Encode CreateCustomerGroupRequest as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_service_order<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_create_customer_group_request<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &ServiceOrder,
+    val: &CreateCustomerGroupRequest,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
     e.map(2)?;
-    e.str("amount")?;
-    e.str(&val.amount)?;
-    e.str("service")?;
-    encode_service(e, &val.service)?;
-    Ok(())
-}
-
-// Decode ServiceOrder from cbor input stream
+    e.str("group")?;
+    encode_customer_group(e, &val.group)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode CreateCustomerGroupRequest from cbor input stream
 #[doc(hidden)]
-pub fn decode_service_order(
+pub fn decode_create_customer_group_request(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<ServiceOrder, RpcError> {
+) -> Result<CreateCustomerGroupRequest, RpcError> {
     let __result = {
-        let mut amount: Option<String> = None;
-        let mut service: Option<Service> = None;
+        let mut group: Option<CustomerGroup> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct ServiceOrder, expected array or map".to_string(),
+                    "decoding struct CreateCustomerGroupRequest, expected array or map".to_string(),
                 ))
             }
         };
@@ -1047,12 +1438,22 @@ pub fn decode_service_order(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => amount = Some(d.str()?.to_string()),
-                    1 => {
-                        service = Some(decode_service(d).map_err(|e| {
-                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                    0 => {
+                        group = Some(decode_customer_group(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
                         })?)
                     }
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
                     _ => d.skip()?,
                 }
             }
@@ -1060,76 +1461,79 @@ pub fn decode_service_order(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "amount" => amount = Some(d.str()?.to_string()),
-                    "service" => {
-                        service = Some(decode_service(d).map_err(|e| {
-                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                    "group" => {
+                        group = Some(decode_customer_group(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
                         })?)
                     }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
                     _ => d.skip()?,
                 }
             }
         }
-        ServiceOrder {
-            amount: if let Some(__x) = amount {
-                __x
-            } else {
-                return Err(RpcError::Deser(
-                    "missing field ServiceOrder.amount (#0)".to_string(),
-                ));
-            },
-
-            service: if let Some(__x) = service {
+        CreateCustomerGroupRequest {
+            group: if let Some(__x) = group {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field ServiceOrder.service (#1)".to_string(),
+                    "missing field CreateCustomerGroupRequest.group (#0)".to_string(),
                 ));
             },
+            principal: principal.unwrap(),
         }
     };
     Ok(__result)
 }
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct ServiceUsage {
+pub struct CreateCustomerReply {
     #[serde(default)]
-    pub client: String,
-    pub service: Service,
+    pub id: String,
+    #[serde(default)]
+    pub success: bool,
 }
 
-// Encode ServiceUsage as CBOR and append to output stream
+// Encode CreateCustomerReply as CBOR and append to output stream
 #[doc(hidden)]
 #[allow(unused_mut)]
-pub fn encode_service_usage<W: wasmbus_rpc::cbor::Write>(
+pub fn encode_create_customer_reply<W: wasmbus_rpc::cbor::Write>(
     mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
-    val: &ServiceUsage,
+    val: &CreateCustomerReply,
 ) -> RpcResult<()>
 where
     <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
 {
     e.map(2)?;
-    e.str("client")?;
-    e.str(&val.client)?;
-    e.str("service")?;
-    encode_service(e, &val.service)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("success")?;
+    e.bool(val.success)?;
     Ok(())
 }
 
-// Decode ServiceUsage from cbor input stream
+// Decode CreateCustomerReply from cbor input stream
 #[doc(hidden)]
-pub fn decode_service_usage(
+pub fn decode_create_customer_reply(
     d: &mut wasmbus_rpc::cbor::Decoder<'_>,
-) -> Result<ServiceUsage, RpcError> {
+) -> Result<CreateCustomerReply, RpcError> {
     let __result = {
-        let mut client: Option<String> = None;
-        let mut service: Option<Service> = None;
+        let mut id: Option<String> = None;
+        let mut success: Option<bool> = None;
 
         let is_array = match d.datatype()? {
             wasmbus_rpc::cbor::Type::Array => true,
             wasmbus_rpc::cbor::Type::Map => false,
             _ => {
                 return Err(RpcError::Deser(
-                    "decoding struct ServiceUsage, expected array or map".to_string(),
+                    "decoding struct CreateCustomerReply, expected array or map".to_string(),
                 ))
             }
         };
@@ -1137,12 +1541,8 @@ pub fn decode_service_usage(
             let len = d.fixed_array()?;
             for __i in 0..(len as usize) {
                 match __i {
-                    0 => client = Some(d.str()?.to_string()),
-                    1 => {
-                        service = Some(decode_service(d).map_err(|e| {
-                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
-                        })?)
-                    }
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
@@ -1150,122 +1550,10784 @@ pub fn decode_service_usage(
             let len = d.fixed_map()?;
             for __i in 0..(len as usize) {
                 match d.str()? {
-                    "client" => client = Some(d.str()?.to_string()),
-                    "service" => {
-                        service = Some(decode_service(d).map_err(|e| {
-                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
-                        })?)
-                    }
+                    "id" => id = Some(d.str()?.to_string()),
+                    "success" => success = Some(d.bool()?),
                     _ => d.skip()?,
                 }
             }
         }
-        ServiceUsage {
-            client: if let Some(__x) = client {
+        CreateCustomerReply {
+            id: if let Some(__x) = id {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field ServiceUsage.client (#0)".to_string(),
+                    "missing field CreateCustomerReply.id (#0)".to_string(),
                 ));
             },
 
-            service: if let Some(__x) = service {
+            success: if let Some(__x) = success {
                 __x
             } else {
                 return Err(RpcError::Deser(
-                    "missing field ServiceUsage.service (#1)".to_string(),
+                    "missing field CreateCustomerReply.success (#1)".to_string(),
                 ));
             },
         }
     };
     Ok(__result)
 }
-/// wasmbus.actorReceive
-#[async_trait]
-pub trait CustomerGroups {
-    async fn create_customer_group(
-        &self,
-        ctx: &Context,
-        arg: &CustomerGroup,
-    ) -> RpcResult<CreateCustomerGroupReply>;
-    async fn add_customer(
-        &self,
-        ctx: &Context,
-        arg: &AddCustomerRequest,
-    ) -> RpcResult<AddCustomerReply>;
-    async fn list_customers<TS: ToString + ?Sized + std::marker::Sync>(
-        &self,
-        ctx: &Context,
-        arg: &TS,
-    ) -> RpcResult<ListCustomersReply>;
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CreateCustomerRequest {
+    pub customer: Customer,
+    /// Client supplied token : a retried request with the same key gets the original reply
+    #[serde(rename = "idempotencyKey")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
 }
 
-/// CustomerGroupsReceiver receives messages defined in the CustomerGroups service trait
+// Encode CreateCustomerRequest as CBOR and append to output stream
 #[doc(hidden)]
-#[async_trait]
-pub trait CustomerGroupsReceiver: MessageDispatch + CustomerGroups {
-    async fn dispatch<'disp__, 'ctx__, 'msg__>(
-        &'disp__ self,
-        ctx: &'ctx__ Context,
-        message: &Message<'msg__>,
-    ) -> Result<Message<'msg__>, RpcError> {
-        match message.method {
-            "CreateCustomerGroup" => {
-                let value: CustomerGroup = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'CustomerGroup': {}", e)))?;
+#[allow(unused_mut)]
+pub fn encode_create_customer_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &CreateCustomerRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("customer")?;
+    encode_customer(e, &val.customer)?;
+    if let Some(val) = val.idempotency_key.as_ref() {
+        e.str("idempotencyKey")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
 
-                let resp = CustomerGroups::create_customer_group(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
+// Decode CreateCustomerRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_create_customer_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<CreateCustomerRequest, RpcError> {
+    let __result = {
+        let mut customer: Option<Customer> = None;
+        let mut idempotency_key: Option<Option<String>> = Some(None);
+        let mut principal: Option<Option<Principal>> = Some(None);
 
-                Ok(Message {
-                    method: "CustomerGroups.CreateCustomerGroup",
-                    arg: Cow::Owned(buf),
-                })
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct CreateCustomerRequest, expected array or map".to_string(),
+                ))
             }
-            "AddCustomer" => {
-                let value: AddCustomerRequest = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'AddCustomerRequest': {}", e)))?;
-
-                let resp = CustomerGroups::add_customer(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        customer = Some(decode_customer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                        })?)
+                    }
+                    1 => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
 
-                Ok(Message {
-                    method: "CustomerGroups.AddCustomer",
-                    arg: Cow::Owned(buf),
-                })
+                    _ => d.skip()?,
+                }
             }
-            "ListCustomers" => {
-                let value: String = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'String': {}", e)))?;
-
-                let resp = CustomerGroups::list_customers(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
-
-                Ok(Message {
-                    method: "CustomerGroups.ListCustomers",
-                    arg: Cow::Owned(buf),
-                })
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "customer" => {
+                        customer = Some(decode_customer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                        })?)
+                    }
+                    "idempotencyKey" => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
             }
-            _ => Err(RpcError::MethodNotHandled(format!(
-                "CustomerGroups::{}",
-                message.method
-            ))),
         }
-    }
-}
-
-/// CustomerGroupsSender sends messages to a CustomerGroups service
-/// client for sending CustomerGroups messages
-#[derive(Debug)]
-pub struct CustomerGroupsSender<T: Transport> {
-    transport: T,
+        CreateCustomerRequest {
+            customer: if let Some(__x) = customer {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CreateCustomerRequest.customer (#0)".to_string(),
+                ));
+            },
+            idempotency_key: idempotency_key.unwrap(),
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
 }
-
-impl<T: Transport> CustomerGroupsSender<T> {
-    /// Constructs a CustomerGroupsSender with the specified transport
-    pub fn via(transport: T) -> Self {
-        Self { transport }
-    }
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Customer {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2 code, selects the tax rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub email: String,
+    #[serde(rename = "firstName")]
+    #[serde(default)]
+    pub first_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "lastName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// ISO 3166-2 subdivision code (e.g. ES-CN), selects the regional tax rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telephone: Option<String>,
+}
+
+// Encode Customer as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_customer<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Customer,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(9)?;
+    if let Some(val) = val.address.as_ref() {
+        e.str("address")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.city.as_ref() {
+        e.str("city")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.country.as_ref() {
+        e.str("country")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("email")?;
+    e.str(&val.email)?;
+    e.str("firstName")?;
+    e.str(&val.first_name)?;
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.last_name.as_ref() {
+        e.str("lastName")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.region.as_ref() {
+        e.str("region")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.telephone.as_ref() {
+        e.str("telephone")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode Customer from cbor input stream
+#[doc(hidden)]
+pub fn decode_customer(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Customer, RpcError> {
+    let __result = {
+        let mut address: Option<Option<String>> = Some(None);
+        let mut city: Option<Option<String>> = Some(None);
+        let mut country: Option<Option<String>> = Some(None);
+        let mut email: Option<String> = None;
+        let mut first_name: Option<String> = None;
+        let mut id: Option<Option<String>> = Some(None);
+        let mut last_name: Option<Option<String>> = Some(None);
+        let mut region: Option<Option<String>> = Some(None);
+        let mut telephone: Option<Option<String>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Customer, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        address = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => {
+                        city = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => {
+                        country = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => email = Some(d.str()?.to_string()),
+                    4 => first_name = Some(d.str()?.to_string()),
+                    5 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    6 => {
+                        last_name = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    7 => {
+                        region = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    8 => {
+                        telephone = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "address" => {
+                        address = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "city" => {
+                        city = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "country" => {
+                        country = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "email" => email = Some(d.str()?.to_string()),
+                    "firstName" => first_name = Some(d.str()?.to_string()),
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "lastName" => {
+                        last_name = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "region" => {
+                        region = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "telephone" => {
+                        telephone = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Customer {
+            address: address.unwrap(),
+            city: city.unwrap(),
+            country: country.unwrap(),
+
+            email: if let Some(__x) = email {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Customer.email (#3)".to_string(),
+                ));
+            },
+
+            first_name: if let Some(__x) = first_name {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Customer.first_name (#4)".to_string(),
+                ));
+            },
+            id: id.unwrap(),
+            last_name: last_name.unwrap(),
+            region: region.unwrap(),
+            telephone: telephone.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CustomerGroup {
+    /// Customers allowed to manage the group members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admins: Option<GroupCustomers>,
+    /// Members of the group when it is created. They are stored apart from the group, which is found
+    /// without them : they are listed by ListCustomers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers: Option<GroupCustomers>,
+    /// Generated when the group is created, the group is addressed by it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Display name, unique regardless of the case
+    #[serde(default)]
+    pub name: String,
+}
+
+// Encode CustomerGroup as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_customer_group<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &CustomerGroup,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    if let Some(val) = val.admins.as_ref() {
+        e.str("admins")?;
+        encode_group_customers(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.customers.as_ref() {
+        e.str("customers")?;
+        encode_group_customers(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("name")?;
+    e.str(&val.name)?;
+    Ok(())
+}
+
+// Decode CustomerGroup from cbor input stream
+#[doc(hidden)]
+pub fn decode_customer_group(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<CustomerGroup, RpcError> {
+    let __result = {
+        let mut admins: Option<Option<GroupCustomers>> = Some(None);
+        let mut customers: Option<Option<GroupCustomers>> = Some(None);
+        let mut id: Option<Option<String>> = Some(None);
+        let mut name: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct CustomerGroup, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        admins = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_customers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        customers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_customers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
+                            })?))
+                        }
+                    }
+                    2 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => name = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "admins" => {
+                        admins = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_customers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
+                            })?))
+                        }
+                    }
+                    "customers" => {
+                        customers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_customers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#GroupCustomers': {}", e)
+                            })?))
+                        }
+                    }
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "name" => name = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        CustomerGroup {
+            admins: admins.unwrap(),
+            customers: customers.unwrap(),
+            id: id.unwrap(),
+
+            name: if let Some(__x) = name {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field CustomerGroup.name (#3)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DeleteWebhookReply {
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode DeleteWebhookReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_delete_webhook_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &DeleteWebhookReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode DeleteWebhookReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_delete_webhook_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<DeleteWebhookReply, RpcError> {
+    let __result = {
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct DeleteWebhookReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        DeleteWebhookReply {
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field DeleteWebhookReply.success (#0)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type EventTypes = Vec<String>;
+
+// Encode EventTypes as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_event_types<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &EventTypes,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode EventTypes from cbor input stream
+#[doc(hidden)]
+pub fn decode_event_types(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<EventTypes, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ExportBucketReply {
+    /// Cursor of the next page, absent from the last one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    /// The bucket in the first page, then the ledger entries of the completed transfers and of the other movements
+    pub records: ExportedRecords,
+}
+
+// Encode ExportBucketReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_export_bucket_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportBucketReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.next.as_ref() {
+        e.str("next")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("records")?;
+    encode_exported_records(e, &val.records)?;
+    Ok(())
+}
+
+// Decode ExportBucketReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_export_bucket_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportBucketReply, RpcError> {
+    let __result = {
+        let mut next: Option<Option<String>> = Some(None);
+        let mut records: Option<ExportedRecords> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ExportBucketReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        next = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "next" => {
+                        next = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "records" => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ExportBucketReply {
+            next: next.unwrap(),
+
+            records: if let Some(__x) = records {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ExportBucketReply.records (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExportBucketRequest {
+    /// Cursor in the ledger of the group, the page starts with the bucket when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub group: String,
+    /// Most ledger entries read for the page
+    #[serde(default)]
+    pub limit: u32,
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode ExportBucketRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_export_bucket_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportBucketRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    if let Some(val) = val.after.as_ref() {
+        e.str("after")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("group")?;
+    e.str(&val.group)?;
+    e.str("limit")?;
+    e.u32(val.limit)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode ExportBucketRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_export_bucket_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportBucketRequest, RpcError> {
+    let __result = {
+        let mut after: Option<Option<String>> = Some(None);
+        let mut group: Option<String> = None;
+        let mut limit: Option<u32> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ExportBucketRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => group = Some(d.str()?.to_string()),
+                    2 => limit = Some(d.u32()?),
+                    3 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "after" => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    "limit" => limit = Some(d.u32()?),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ExportBucketRequest {
+            after: after.unwrap(),
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ExportBucketRequest.group (#1)".to_string(),
+                ));
+            },
+
+            limit: if let Some(__x) = limit {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ExportBucketRequest.limit (#2)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ExportRecordsReply {
+    /// Cursor of the next page, absent from the last one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    /// Customers by increasing id, then groups by increasing id each followed by its members
+    pub records: ExportedRecords,
+}
+
+// Encode ExportRecordsReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_export_records_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportRecordsReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.next.as_ref() {
+        e.str("next")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("records")?;
+    encode_exported_records(e, &val.records)?;
+    Ok(())
+}
+
+// Decode ExportRecordsReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_export_records_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportRecordsReply, RpcError> {
+    let __result = {
+        let mut next: Option<Option<String>> = Some(None);
+        let mut records: Option<ExportedRecords> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ExportRecordsReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        next = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "next" => {
+                        next = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "records" => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ExportRecordsReply {
+            next: next.unwrap(),
+
+            records: if let Some(__x) = records {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ExportRecordsReply.records (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExportRecordsRequest {
+    /// Cursor of the page, the export starts with the first customer when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Most customers and groups in the page, 100 when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode ExportRecordsRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_export_records_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportRecordsRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    if let Some(val) = val.after.as_ref() {
+        e.str("after")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.limit.as_ref() {
+        e.str("limit")?;
+        e.u32(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode ExportRecordsRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_export_records_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportRecordsRequest, RpcError> {
+    let __result = {
+        let mut after: Option<Option<String>> = Some(None);
+        let mut limit: Option<Option<u32>> = Some(None);
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ExportRecordsRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => {
+                        limit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u32()?))
+                        }
+                    }
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "after" => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "limit" => {
+                        limit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u32()?))
+                        }
+                    }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ExportRecordsRequest {
+            after: after.unwrap(),
+            limit: limit.unwrap(),
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Record of an export, `kind` telling which member holds it
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ExportedRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<Bucket>,
+    /// With its id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    /// Without its members, which are exported as member records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<CustomerGroup>,
+    /// customer, group, member, bucket or ledgerEntry
+    #[serde(default)]
+    pub kind: String,
+    #[serde(rename = "ledgerEntry")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger_entry: Option<GroupLedgerEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<Membership>,
+}
+
+// Encode ExportedRecord as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_exported_record<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportedRecord,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(6)?;
+    if let Some(val) = val.bucket.as_ref() {
+        e.str("bucket")?;
+        encode_bucket(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.customer.as_ref() {
+        e.str("customer")?;
+        encode_customer(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.group.as_ref() {
+        e.str("group")?;
+        encode_customer_group(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("kind")?;
+    e.str(&val.kind)?;
+    if let Some(val) = val.ledger_entry.as_ref() {
+        e.str("ledgerEntry")?;
+        encode_group_ledger_entry(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.member.as_ref() {
+        e.str("member")?;
+        encode_membership(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode ExportedRecord from cbor input stream
+#[doc(hidden)]
+pub fn decode_exported_record(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportedRecord, RpcError> {
+    let __result = {
+        let mut bucket: Option<Option<Bucket>> = Some(None);
+        let mut customer: Option<Option<Customer>> = Some(None);
+        let mut group: Option<Option<CustomerGroup>> = Some(None);
+        let mut kind: Option<String> = None;
+        let mut ledger_entry: Option<Option<GroupLedgerEntry>> = Some(None);
+        let mut member: Option<Option<Membership>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ExportedRecord, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        bucket = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_bucket(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Bucket': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            })?))
+                        }
+                    }
+                    2 => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer_group(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
+                            })?))
+                        }
+                    }
+                    3 => kind = Some(d.str()?.to_string()),
+                    4 => {
+                        ledger_entry = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_ledger_entry(d).map_err(|e| {
+                                format!(
+                                    "decoding 'com.orange.sharedbucket#GroupLedgerEntry': {}",
+                                    e
+                                )
+                            })?))
+                        }
+                    }
+                    5 => {
+                        member = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_membership(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Membership': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "bucket" => {
+                        bucket = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_bucket(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Bucket': {}", e)
+                            })?))
+                        }
+                    }
+                    "customer" => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            })?))
+                        }
+                    }
+                    "group" => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer_group(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
+                            })?))
+                        }
+                    }
+                    "kind" => kind = Some(d.str()?.to_string()),
+                    "ledgerEntry" => {
+                        ledger_entry = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_group_ledger_entry(d).map_err(|e| {
+                                format!(
+                                    "decoding 'com.orange.sharedbucket#GroupLedgerEntry': {}",
+                                    e
+                                )
+                            })?))
+                        }
+                    }
+                    "member" => {
+                        member = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_membership(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Membership': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ExportedRecord {
+            bucket: bucket.unwrap(),
+            customer: customer.unwrap(),
+            group: group.unwrap(),
+
+            kind: if let Some(__x) = kind {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ExportedRecord.kind (#3)".to_string(),
+                ));
+            },
+            ledger_entry: ledger_entry.unwrap(),
+            member: member.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type ExportedRecords = Vec<ExportedRecord>;
+
+// Encode ExportedRecords as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_exported_records<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ExportedRecords,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_exported_record(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode ExportedRecords from cbor input stream
+#[doc(hidden)]
+pub fn decode_exported_records(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ExportedRecords, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<ExportedRecord> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_exported_record(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#ExportedRecord': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<ExportedRecord> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_exported_record(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#ExportedRecord': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+pub type FieldNames = Vec<String>;
+
+// Encode FieldNames as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_field_names<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &FieldNames,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode FieldNames from cbor input stream
+#[doc(hidden)]
+pub fn decode_field_names(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<FieldNames, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FindCustomerGroupReply {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<CustomerGroup>,
+    /// Revision of the group, increased when it is renamed or its members change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+// Encode FindCustomerGroupReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_find_customer_group_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &FindCustomerGroupReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.group.as_ref() {
+        e.str("group")?;
+        encode_customer_group(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode FindCustomerGroupReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_find_customer_group_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<FindCustomerGroupReply, RpcError> {
+    let __result = {
+        let mut group: Option<Option<CustomerGroup>> = Some(None);
+        let mut revision: Option<Option<u64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct FindCustomerGroupReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer_group(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer_group(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CustomerGroup': {}", e)
+                            })?))
+                        }
+                    }
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        FindCustomerGroupReply {
+            group: group.unwrap(),
+            revision: revision.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FindCustomerGroupRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode FindCustomerGroupRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_find_customer_group_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &FindCustomerGroupRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode FindCustomerGroupRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_find_customer_group_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<FindCustomerGroupRequest, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct FindCustomerGroupRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        FindCustomerGroupRequest {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field FindCustomerGroupRequest.id (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FindCustomerReply {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    /// Revision of the customer, the ETag of the HTTP API
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+// Encode FindCustomerReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_find_customer_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &FindCustomerReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.customer.as_ref() {
+        e.str("customer")?;
+        encode_customer(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode FindCustomerReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_find_customer_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<FindCustomerReply, RpcError> {
+    let __result = {
+        let mut customer: Option<Option<Customer>> = Some(None);
+        let mut revision: Option<Option<u64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct FindCustomerReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "customer" => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_customer(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                            })?))
+                        }
+                    }
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        FindCustomerReply {
+            customer: customer.unwrap(),
+            revision: revision.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FindCustomerRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode FindCustomerRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_find_customer_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &FindCustomerRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode FindCustomerRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_find_customer_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<FindCustomerRequest, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct FindCustomerRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        FindCustomerRequest {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field FindCustomerRequest.id (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GetInvoiceReply {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Invoice>,
+}
+
+// Encode GetInvoiceReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_get_invoice_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GetInvoiceReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    if let Some(val) = val.invoice.as_ref() {
+        e.str("invoice")?;
+        encode_invoice(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode GetInvoiceReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_get_invoice_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<GetInvoiceReply, RpcError> {
+    let __result = {
+        let mut invoice: Option<Option<Invoice>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct GetInvoiceReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        invoice = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_invoice(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Invoice': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "invoice" => {
+                        invoice = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_invoice(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Invoice': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        GetInvoiceReply {
+            invoice: invoice.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GetServiceReply {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<CatalogService>,
+}
+
+// Encode GetServiceReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_get_service_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GetServiceReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    if let Some(val) = val.service.as_ref() {
+        e.str("service")?;
+        encode_catalog_service(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode GetServiceReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_get_service_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<GetServiceReply, RpcError> {
+    let __result = {
+        let mut service: Option<Option<CatalogService>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct GetServiceReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        service = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_catalog_service(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "service" => {
+                        service = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_catalog_service(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#CatalogService': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        GetServiceReply {
+            service: service.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type GroupCustomers = Vec<String>;
+
+// Encode GroupCustomers as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_group_customers<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GroupCustomers,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode GroupCustomers from cbor input stream
+#[doc(hidden)]
+pub fn decode_group_customers(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<GroupCustomers, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+pub type GroupIds = Vec<String>;
+
+// Encode GroupIds as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_group_ids<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GroupIds,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode GroupIds from cbor input stream
+#[doc(hidden)]
+pub fn decode_group_ids(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<GroupIds, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+/// Entry of the ledger of a group bucket
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GroupLedgerEntry {
+    pub entry: LedgerEntry,
+    #[serde(default)]
+    pub group: String,
+}
+
+// Encode GroupLedgerEntry as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_group_ledger_entry<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GroupLedgerEntry,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("entry")?;
+    encode_ledger_entry(e, &val.entry)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    Ok(())
+}
+
+// Decode GroupLedgerEntry from cbor input stream
+#[doc(hidden)]
+pub fn decode_group_ledger_entry(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<GroupLedgerEntry, RpcError> {
+    let __result = {
+        let mut entry: Option<LedgerEntry> = None;
+        let mut group: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct GroupLedgerEntry, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        entry = Some(decode_ledger_entry(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#LedgerEntry': {}", e)
+                        })?)
+                    }
+                    1 => group = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "entry" => {
+                        entry = Some(decode_ledger_entry(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#LedgerEntry': {}", e)
+                        })?)
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        GroupLedgerEntry {
+            entry: if let Some(__x) = entry {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field GroupLedgerEntry.entry (#0)".to_string(),
+                ));
+            },
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field GroupLedgerEntry.group (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GroupWebhooksRequest {
+    #[serde(default)]
+    pub group: String,
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode GroupWebhooksRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_group_webhooks_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &GroupWebhooksRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode GroupWebhooksRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_group_webhooks_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<GroupWebhooksRequest, RpcError> {
+    let __result = {
+        let mut group: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct GroupWebhooksRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => group = Some(d.str()?.to_string()),
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        GroupWebhooksRequest {
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field GroupWebhooksRequest.group (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HealthzReply {
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode HealthzReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_healthz_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &HealthzReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode HealthzReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_healthz_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<HealthzReply, RpcError> {
+    let __result = {
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct HealthzReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        HealthzReply {
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field HealthzReply.success (#0)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HealthzRequest {}
+
+// Encode HealthzRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_healthz_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    _val: &HealthzRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(0)?;
+    Ok(())
+}
+
+// Decode HealthzRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_healthz_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<HealthzRequest, RpcError> {
+    let __result = {
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct HealthzRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                d.skip()?;
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                d.str()?;
+                d.skip()?;
+            }
+        }
+        HealthzRequest {}
+    };
+    Ok(__result)
+}
+/// Rate from `fromHour` (included) to `toHour` (excluded), wrapping around midnight when `toHour` <= `fromHour`
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HourRate {
+    #[serde(rename = "fromHour")]
+    #[serde(default)]
+    pub from_hour: u8,
+    #[serde(default)]
+    pub rate: f64,
+    #[serde(rename = "toHour")]
+    #[serde(default)]
+    pub to_hour: u8,
+}
+
+// Encode HourRate as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_hour_rate<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &HourRate,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("fromHour")?;
+    e.u8(val.from_hour)?;
+    e.str("rate")?;
+    e.f64(val.rate)?;
+    e.str("toHour")?;
+    e.u8(val.to_hour)?;
+    Ok(())
+}
+
+// Decode HourRate from cbor input stream
+#[doc(hidden)]
+pub fn decode_hour_rate(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<HourRate, RpcError> {
+    let __result = {
+        let mut from_hour: Option<u8> = None;
+        let mut rate: Option<f64> = None;
+        let mut to_hour: Option<u8> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct HourRate, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => from_hour = Some(d.u8()?),
+                    1 => rate = Some(d.f64()?),
+                    2 => to_hour = Some(d.u8()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "fromHour" => from_hour = Some(d.u8()?),
+                    "rate" => rate = Some(d.f64()?),
+                    "toHour" => to_hour = Some(d.u8()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        HourRate {
+            from_hour: if let Some(__x) = from_hour {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field HourRate.from_hour (#0)".to_string(),
+                ));
+            },
+
+            rate: if let Some(__x) = rate {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field HourRate.rate (#1)".to_string(),
+                ));
+            },
+
+            to_hour: if let Some(__x) = to_hour {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field HourRate.to_hour (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type HourRates = Vec<HourRate>;
+
+// Encode HourRates as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_hour_rates<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &HourRates,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_hour_rate(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode HourRates from cbor input stream
+#[doc(hidden)]
+pub fn decode_hour_rates(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<HourRates, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<HourRate> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_hour_rate(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#HourRate': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<HourRate> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_hour_rate(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#HourRate': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+pub type ImportCustomersReply = Vec<ImportResult>;
+
+// Encode ImportCustomersReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_import_customers_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ImportCustomersReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_import_result(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode ImportCustomersReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_import_customers_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ImportCustomersReply, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<ImportResult> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_import_result(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#ImportResult': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<ImportResult> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_import_result(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#ImportResult': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ImportCustomersRequest {
+    /// The rows are validated and checked for duplicates, nothing is created
+    #[serde(rename = "dryRun")]
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    pub rows: ImportRows,
+}
+
+// Encode ImportCustomersRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_import_customers_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ImportCustomersRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("dryRun")?;
+    e.bool(val.dry_run)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("rows")?;
+    encode_import_rows(e, &val.rows)?;
+    Ok(())
+}
+
+// Decode ImportCustomersRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_import_customers_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ImportCustomersRequest, RpcError> {
+    let __result = {
+        let mut dry_run: Option<bool> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut rows: Option<ImportRows> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ImportCustomersRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => dry_run = Some(d.bool()?),
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    2 => {
+                        rows = Some(decode_import_rows(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ImportRows': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "dryRun" => dry_run = Some(d.bool()?),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "rows" => {
+                        rows = Some(decode_import_rows(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ImportRows': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ImportCustomersRequest {
+            dry_run: if let Some(__x) = dry_run {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportCustomersRequest.dry_run (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+
+            rows: if let Some(__x) = rows {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportCustomersRequest.rows (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ImportResult {
+    /// Id of the created customer, or of the customer holding the email of a duplicate. Absent from
+    /// the rows a dry run would create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub line: u32,
+    /// Why the row is invalid or failed, or what became of the group of a duplicate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// created, duplicate (the email is held by a customer or an earlier row), invalid, or failed
+    /// when the customer could not be stored
+    #[serde(default)]
+    pub status: String,
+}
+
+// Encode ImportResult as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_import_result<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ImportResult,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("line")?;
+    e.u32(val.line)?;
+    if let Some(val) = val.reason.as_ref() {
+        e.str("reason")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("status")?;
+    e.str(&val.status)?;
+    Ok(())
+}
+
+// Decode ImportResult from cbor input stream
+#[doc(hidden)]
+pub fn decode_import_result(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ImportResult, RpcError> {
+    let __result = {
+        let mut id: Option<Option<String>> = Some(None);
+        let mut line: Option<u32> = None;
+        let mut reason: Option<Option<String>> = Some(None);
+        let mut status: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ImportResult, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => line = Some(d.u32()?),
+                    2 => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => status = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "line" => line = Some(d.u32()?),
+                    "reason" => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "status" => status = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ImportResult {
+            id: id.unwrap(),
+
+            line: if let Some(__x) = line {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportResult.line (#1)".to_string(),
+                ));
+            },
+            reason: reason.unwrap(),
+
+            status: if let Some(__x) = status {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportResult.status (#3)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ImportRow {
+    /// The id is generated, the imported one is ignored
+    pub customer: Customer,
+    /// Id of the group the customer joins
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Line of the row in the imported file
+    #[serde(default)]
+    pub line: u32,
+}
+
+// Encode ImportRow as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_import_row<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ImportRow,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("customer")?;
+    encode_customer(e, &val.customer)?;
+    if let Some(val) = val.group.as_ref() {
+        e.str("group")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("line")?;
+    e.u32(val.line)?;
+    Ok(())
+}
+
+// Decode ImportRow from cbor input stream
+#[doc(hidden)]
+pub fn decode_import_row(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<ImportRow, RpcError> {
+    let __result = {
+        let mut customer: Option<Customer> = None;
+        let mut group: Option<Option<String>> = Some(None);
+        let mut line: Option<u32> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ImportRow, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        customer = Some(decode_customer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                        })?)
+                    }
+                    1 => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => line = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "customer" => {
+                        customer = Some(decode_customer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                        })?)
+                    }
+                    "group" => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "line" => line = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ImportRow {
+            customer: if let Some(__x) = customer {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportRow.customer (#0)".to_string(),
+                ));
+            },
+            group: group.unwrap(),
+
+            line: if let Some(__x) = line {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ImportRow.line (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type ImportRows = Vec<ImportRow>;
+
+// Encode ImportRows as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_import_rows<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ImportRows,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_import_row(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode ImportRows from cbor input stream
+#[doc(hidden)]
+pub fn decode_import_rows(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<ImportRows, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<ImportRow> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_import_row(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#ImportRow': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<ImportRow> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_import_row(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ImportRow': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Invoice {
+    #[serde(default)]
+    pub group: String,
+    /// Usage, one line per service and member
+    pub lines: InvoiceLines,
+    /// INV-YYYYMM-NNNNNN, sequential in the period
+    #[serde(default)]
+    pub number: String,
+    /// Bucket units bought in the period, and refunded (negative amounts)
+    pub orders: InvoiceOrders,
+    #[serde(default)]
+    pub period: String,
+    /// Sum of the line amounts
+    #[serde(default)]
+    pub subtotal: f64,
+    /// Sum of the line taxes
+    #[serde(default)]
+    pub taxes: f64,
+    #[serde(default)]
+    pub total: f64,
+}
+
+// Encode Invoice as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Invoice,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(8)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    e.str("lines")?;
+    encode_invoice_lines(e, &val.lines)?;
+    e.str("number")?;
+    e.str(&val.number)?;
+    e.str("orders")?;
+    encode_invoice_orders(e, &val.orders)?;
+    e.str("period")?;
+    e.str(&val.period)?;
+    e.str("subtotal")?;
+    e.f64(val.subtotal)?;
+    e.str("taxes")?;
+    e.f64(val.taxes)?;
+    e.str("total")?;
+    e.f64(val.total)?;
+    Ok(())
+}
+
+// Decode Invoice from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Invoice, RpcError> {
+    let __result = {
+        let mut group: Option<String> = None;
+        let mut lines: Option<InvoiceLines> = None;
+        let mut number: Option<String> = None;
+        let mut orders: Option<InvoiceOrders> = None;
+        let mut period: Option<String> = None;
+        let mut subtotal: Option<f64> = None;
+        let mut taxes: Option<f64> = None;
+        let mut total: Option<f64> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Invoice, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => group = Some(d.str()?.to_string()),
+                    1 => {
+                        lines = Some(decode_invoice_lines(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#InvoiceLines': {}", e)
+                        })?)
+                    }
+                    2 => number = Some(d.str()?.to_string()),
+                    3 => {
+                        orders = Some(decode_invoice_orders(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#InvoiceOrders': {}", e)
+                        })?)
+                    }
+                    4 => period = Some(d.str()?.to_string()),
+                    5 => subtotal = Some(d.f64()?),
+                    6 => taxes = Some(d.f64()?),
+                    7 => total = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "lines" => {
+                        lines = Some(decode_invoice_lines(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#InvoiceLines': {}", e)
+                        })?)
+                    }
+                    "number" => number = Some(d.str()?.to_string()),
+                    "orders" => {
+                        orders = Some(decode_invoice_orders(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#InvoiceOrders': {}", e)
+                        })?)
+                    }
+                    "period" => period = Some(d.str()?.to_string()),
+                    "subtotal" => subtotal = Some(d.f64()?),
+                    "taxes" => taxes = Some(d.f64()?),
+                    "total" => total = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Invoice {
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.group (#0)".to_string(),
+                ));
+            },
+
+            lines: if let Some(__x) = lines {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.lines (#1)".to_string(),
+                ));
+            },
+
+            number: if let Some(__x) = number {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.number (#2)".to_string(),
+                ));
+            },
+
+            orders: if let Some(__x) = orders {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.orders (#3)".to_string(),
+                ));
+            },
+
+            period: if let Some(__x) = period {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.period (#4)".to_string(),
+                ));
+            },
+
+            subtotal: if let Some(__x) = subtotal {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.subtotal (#5)".to_string(),
+                ));
+            },
+
+            taxes: if let Some(__x) = taxes {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.taxes (#6)".to_string(),
+                ));
+            },
+
+            total: if let Some(__x) = total {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Invoice.total (#7)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InvoiceLine {
+    /// quantity x unit price, rounded to the cent
+    #[serde(default)]
+    pub amount: f64,
+    /// Bucket units drawn
+    #[serde(rename = "bucketUnits")]
+    #[serde(default)]
+    pub bucket_units: f64,
+    #[serde(default)]
+    pub member: String,
+    #[serde(default)]
+    pub quantity: f64,
+    #[serde(default)]
+    pub service: String,
+    /// Tax rate (percentage) of the member location
+    #[serde(rename = "taxRate")]
+    #[serde(default)]
+    pub tax_rate: f64,
+    /// Taxes on the amount, rounded to the cent
+    #[serde(default)]
+    pub taxes: f64,
+    /// Unit the service is consumed in
+    #[serde(default)]
+    pub unit: String,
+    #[serde(rename = "unitPrice")]
+    #[serde(default)]
+    pub unit_price: f64,
+    /// The member is not a customer of the tenant : the line is taxed at the default rate
+    #[serde(rename = "unknownMember")]
+    #[serde(default)]
+    pub unknown_member: bool,
+}
+
+// Encode InvoiceLine as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice_line<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &InvoiceLine,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(10)?;
+    e.str("amount")?;
+    e.f64(val.amount)?;
+    e.str("bucketUnits")?;
+    e.f64(val.bucket_units)?;
+    e.str("member")?;
+    e.str(&val.member)?;
+    e.str("quantity")?;
+    e.f64(val.quantity)?;
+    e.str("service")?;
+    e.str(&val.service)?;
+    e.str("taxRate")?;
+    e.f64(val.tax_rate)?;
+    e.str("taxes")?;
+    e.f64(val.taxes)?;
+    e.str("unit")?;
+    e.str(&val.unit)?;
+    e.str("unitPrice")?;
+    e.f64(val.unit_price)?;
+    e.str("unknownMember")?;
+    e.bool(val.unknown_member)?;
+    Ok(())
+}
+
+// Decode InvoiceLine from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice_line(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<InvoiceLine, RpcError> {
+    let __result = {
+        let mut amount: Option<f64> = None;
+        let mut bucket_units: Option<f64> = None;
+        let mut member: Option<String> = None;
+        let mut quantity: Option<f64> = None;
+        let mut service: Option<String> = None;
+        let mut tax_rate: Option<f64> = None;
+        let mut taxes: Option<f64> = None;
+        let mut unit: Option<String> = None;
+        let mut unit_price: Option<f64> = None;
+        let mut unknown_member: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct InvoiceLine, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => amount = Some(d.f64()?),
+                    1 => bucket_units = Some(d.f64()?),
+                    2 => member = Some(d.str()?.to_string()),
+                    3 => quantity = Some(d.f64()?),
+                    4 => service = Some(d.str()?.to_string()),
+                    5 => tax_rate = Some(d.f64()?),
+                    6 => taxes = Some(d.f64()?),
+                    7 => unit = Some(d.str()?.to_string()),
+                    8 => unit_price = Some(d.f64()?),
+                    9 => unknown_member = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => amount = Some(d.f64()?),
+                    "bucketUnits" => bucket_units = Some(d.f64()?),
+                    "member" => member = Some(d.str()?.to_string()),
+                    "quantity" => quantity = Some(d.f64()?),
+                    "service" => service = Some(d.str()?.to_string()),
+                    "taxRate" => tax_rate = Some(d.f64()?),
+                    "taxes" => taxes = Some(d.f64()?),
+                    "unit" => unit = Some(d.str()?.to_string()),
+                    "unitPrice" => unit_price = Some(d.f64()?),
+                    "unknownMember" => unknown_member = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        InvoiceLine {
+            amount: if let Some(__x) = amount {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.amount (#0)".to_string(),
+                ));
+            },
+
+            bucket_units: if let Some(__x) = bucket_units {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.bucket_units (#1)".to_string(),
+                ));
+            },
+
+            member: if let Some(__x) = member {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.member (#2)".to_string(),
+                ));
+            },
+
+            quantity: if let Some(__x) = quantity {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.quantity (#3)".to_string(),
+                ));
+            },
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.service (#4)".to_string(),
+                ));
+            },
+
+            tax_rate: if let Some(__x) = tax_rate {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.tax_rate (#5)".to_string(),
+                ));
+            },
+
+            taxes: if let Some(__x) = taxes {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.taxes (#6)".to_string(),
+                ));
+            },
+
+            unit: if let Some(__x) = unit {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.unit (#7)".to_string(),
+                ));
+            },
+
+            unit_price: if let Some(__x) = unit_price {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.unit_price (#8)".to_string(),
+                ));
+            },
+
+            unknown_member: if let Some(__x) = unknown_member {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceLine.unknown_member (#9)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type InvoiceLines = Vec<InvoiceLine>;
+
+// Encode InvoiceLines as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice_lines<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &InvoiceLines,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_invoice_line(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode InvoiceLines from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice_lines(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<InvoiceLines, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<InvoiceLine> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_invoice_line(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#InvoiceLine': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<InvoiceLine> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_invoice_line(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#InvoiceLine': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InvoiceOrder {
+    /// Bucket units bought
+    #[serde(default)]
+    pub amount: f64,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub service: String,
+}
+
+// Encode InvoiceOrder as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice_order<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &InvoiceOrder,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("amount")?;
+    e.f64(val.amount)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("service")?;
+    e.str(&val.service)?;
+    Ok(())
+}
+
+// Decode InvoiceOrder from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice_order(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<InvoiceOrder, RpcError> {
+    let __result = {
+        let mut amount: Option<f64> = None;
+        let mut id: Option<String> = None;
+        let mut service: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct InvoiceOrder, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => amount = Some(d.f64()?),
+                    1 => id = Some(d.str()?.to_string()),
+                    2 => service = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => amount = Some(d.f64()?),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "service" => service = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        InvoiceOrder {
+            amount: if let Some(__x) = amount {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceOrder.amount (#0)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceOrder.id (#1)".to_string(),
+                ));
+            },
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceOrder.service (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type InvoiceOrders = Vec<InvoiceOrder>;
+
+// Encode InvoiceOrders as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice_orders<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &InvoiceOrders,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_invoice_order(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode InvoiceOrders from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice_orders(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<InvoiceOrders, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<InvoiceOrder> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_invoice_order(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#InvoiceOrder': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<InvoiceOrder> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_invoice_order(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#InvoiceOrder': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InvoiceRequest {
+    #[serde(default)]
+    pub group: String,
+    /// Billing period, YYYY-MM
+    #[serde(default)]
+    pub period: String,
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode InvoiceRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_invoice_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &InvoiceRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    e.str("period")?;
+    e.str(&val.period)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode InvoiceRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_invoice_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<InvoiceRequest, RpcError> {
+    let __result = {
+        let mut group: Option<String> = None;
+        let mut period: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct InvoiceRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => group = Some(d.str()?.to_string()),
+                    1 => period = Some(d.str()?.to_string()),
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "period" => period = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        InvoiceRequest {
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceRequest.group (#0)".to_string(),
+                ));
+            },
+
+            period: if let Some(__x) = period {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field InvoiceRequest.period (#1)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Movement of a group bucket, recorded by period
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct LedgerEntry {
+    /// Bucket units debited (usage, refund, transfer_out) or credited (order, transfer_in)
+    #[serde(rename = "bucketUnits")]
+    #[serde(default)]
+    pub bucket_units: f64,
+    /// Member using the service (usage)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// usage, order, refund, transfer_out or transfer_in
+    #[serde(default)]
+    pub kind: String,
+    /// Order id (order, refund)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    #[serde(default)]
+    pub service: String,
+    #[serde(default)]
+    pub timestamp: Timestamp,
+    /// Transfer id (transfer_out, transfer_in), the entry only counts when the transfer completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<String>,
+    /// Unit the service is consumed in (usage)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Price of a unit of service when used (usage)
+    #[serde(rename = "unitPrice")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<f64>,
+    /// Units of service (usage)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub units: Option<f64>,
+}
+
+// Encode LedgerEntry as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_ledger_entry<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &LedgerEntry,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(10)?;
+    e.str("bucketUnits")?;
+    e.f64(val.bucket_units)?;
+    if let Some(val) = val.client.as_ref() {
+        e.str("client")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("kind")?;
+    e.str(&val.kind)?;
+    if let Some(val) = val.order.as_ref() {
+        e.str("order")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("service")?;
+    e.str(&val.service)?;
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    if let Some(val) = val.transfer.as_ref() {
+        e.str("transfer")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.unit.as_ref() {
+        e.str("unit")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.unit_price.as_ref() {
+        e.str("unitPrice")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.units.as_ref() {
+        e.str("units")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode LedgerEntry from cbor input stream
+#[doc(hidden)]
+pub fn decode_ledger_entry(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<LedgerEntry, RpcError> {
+    let __result = {
+        let mut bucket_units: Option<f64> = None;
+        let mut client: Option<Option<String>> = Some(None);
+        let mut kind: Option<String> = None;
+        let mut order: Option<Option<String>> = Some(None);
+        let mut service: Option<String> = None;
+        let mut timestamp: Option<Timestamp> = None;
+        let mut transfer: Option<Option<String>> = Some(None);
+        let mut unit: Option<Option<String>> = Some(None);
+        let mut unit_price: Option<Option<f64>> = Some(None);
+        let mut units: Option<Option<f64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct LedgerEntry, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => bucket_units = Some(d.f64()?),
+                    1 => {
+                        client = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => kind = Some(d.str()?.to_string()),
+                    3 => {
+                        order = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    4 => service = Some(d.str()?.to_string()),
+                    5 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    6 => {
+                        transfer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    7 => {
+                        unit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    8 => {
+                        unit_price = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    9 => {
+                        units = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "bucketUnits" => bucket_units = Some(d.f64()?),
+                    "client" => {
+                        client = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "kind" => kind = Some(d.str()?.to_string()),
+                    "order" => {
+                        order = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "service" => service = Some(d.str()?.to_string()),
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    "transfer" => {
+                        transfer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "unit" => {
+                        unit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "unitPrice" => {
+                        unit_price = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "units" => {
+                        units = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        LedgerEntry {
+            bucket_units: if let Some(__x) = bucket_units {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field LedgerEntry.bucket_units (#0)".to_string(),
+                ));
+            },
+            client: client.unwrap(),
+
+            kind: if let Some(__x) = kind {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field LedgerEntry.kind (#2)".to_string(),
+                ));
+            },
+            order: order.unwrap(),
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field LedgerEntry.service (#4)".to_string(),
+                ));
+            },
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field LedgerEntry.timestamp (#5)".to_string(),
+                ));
+            },
+            transfer: transfer.unwrap(),
+            unit: unit.unwrap(),
+            unit_price: unit_price.unwrap(),
+            units: units.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type ListCustomersReply = Vec<Customer>;
+
+// Encode ListCustomersReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_list_customers_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ListCustomersReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_customer(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode ListCustomersReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_list_customers_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ListCustomersReply, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<Customer> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_customer(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<Customer> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_customer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Customer': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ListCustomersRequest {
+    /// Id of the last member of the previous page, the listing starts with the first member
+    /// when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Customer fields to return, by their JSON name, besides the id and the required ones.
+    /// Every field is returned when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<FieldNames>,
+    /// Id of the group
+    #[serde(default)]
+    pub group: String,
+    /// Most members listed, all of them when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode ListCustomersRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_list_customers_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ListCustomersRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(5)?;
+    if let Some(val) = val.after.as_ref() {
+        e.str("after")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.fields.as_ref() {
+        e.str("fields")?;
+        encode_field_names(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.limit.as_ref() {
+        e.str("limit")?;
+        e.u32(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode ListCustomersRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_list_customers_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ListCustomersRequest, RpcError> {
+    let __result = {
+        let mut after: Option<Option<String>> = Some(None);
+        let mut fields: Option<Option<FieldNames>> = Some(None);
+        let mut group: Option<String> = None;
+        let mut limit: Option<Option<u32>> = Some(None);
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ListCustomersRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => {
+                        fields = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_field_names(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#FieldNames': {}", e)
+                            })?))
+                        }
+                    }
+                    2 => group = Some(d.str()?.to_string()),
+                    3 => {
+                        limit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u32()?))
+                        }
+                    }
+                    4 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "after" => {
+                        after = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "fields" => {
+                        fields = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_field_names(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#FieldNames': {}", e)
+                            })?))
+                        }
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    "limit" => {
+                        limit = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u32()?))
+                        }
+                    }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ListCustomersRequest {
+            after: after.unwrap(),
+            fields: fields.unwrap(),
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ListCustomersRequest.group (#2)".to_string(),
+                ));
+            },
+            limit: limit.unwrap(),
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Membership {
+    #[serde(default)]
+    pub customer: String,
+    #[serde(default)]
+    pub group: String,
+}
+
+// Encode Membership as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_membership<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Membership,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("customer")?;
+    e.str(&val.customer)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    Ok(())
+}
+
+// Decode Membership from cbor input stream
+#[doc(hidden)]
+pub fn decode_membership(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Membership, RpcError> {
+    let __result = {
+        let mut customer: Option<String> = None;
+        let mut group: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Membership, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => customer = Some(d.str()?.to_string()),
+                    1 => group = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "customer" => customer = Some(d.str()?.to_string()),
+                    "group" => group = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Membership {
+            customer: if let Some(__x) = customer {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Membership.customer (#0)".to_string(),
+                ));
+            },
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Membership.group (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MigrateLedgersRequest {
+    pub groups: GroupIds,
+    /// The ledgers of the groups of the tenant of the principal, an operator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode MigrateLedgersRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_migrate_ledgers_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &MigrateLedgersRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("groups")?;
+    encode_group_ids(e, &val.groups)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode MigrateLedgersRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_migrate_ledgers_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<MigrateLedgersRequest, RpcError> {
+    let __result = {
+        let mut groups: Option<GroupIds> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct MigrateLedgersRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        groups = Some(decode_group_ids(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#GroupIds': {}", e)
+                        })?)
+                    }
+                    1 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "groups" => {
+                        groups = Some(decode_group_ids(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#GroupIds': {}", e)
+                        })?)
+                    }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        MigrateLedgersRequest {
+            groups: if let Some(__x) = groups {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field MigrateLedgersRequest.groups (#0)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MigrateRecordsReply {
+    /// Records already at the current version
+    #[serde(default)]
+    pub current: u32,
+    /// Records which could not be read or upgraded, they are left as they are
+    #[serde(default)]
+    pub failed: u32,
+    /// Records upgraded to the current version
+    #[serde(default)]
+    pub migrated: u32,
+}
+
+// Encode MigrateRecordsReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_migrate_records_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &MigrateRecordsReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("current")?;
+    e.u32(val.current)?;
+    e.str("failed")?;
+    e.u32(val.failed)?;
+    e.str("migrated")?;
+    e.u32(val.migrated)?;
+    Ok(())
+}
+
+// Decode MigrateRecordsReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_migrate_records_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<MigrateRecordsReply, RpcError> {
+    let __result = {
+        let mut current: Option<u32> = None;
+        let mut failed: Option<u32> = None;
+        let mut migrated: Option<u32> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct MigrateRecordsReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => current = Some(d.u32()?),
+                    1 => failed = Some(d.u32()?),
+                    2 => migrated = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "current" => current = Some(d.u32()?),
+                    "failed" => failed = Some(d.u32()?),
+                    "migrated" => migrated = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        MigrateRecordsReply {
+            current: if let Some(__x) = current {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field MigrateRecordsReply.current (#0)".to_string(),
+                ));
+            },
+
+            failed: if let Some(__x) = failed {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field MigrateRecordsReply.failed (#1)".to_string(),
+                ));
+            },
+
+            migrated: if let Some(__x) = migrated {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field MigrateRecordsReply.migrated (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MigrateRecordsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode MigrateRecordsRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_migrate_records_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &MigrateRecordsRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode MigrateRecordsRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_migrate_records_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<MigrateRecordsRequest, RpcError> {
+    let __result = {
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct MigrateRecordsRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        MigrateRecordsRequest {
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Authenticated caller of the HTTP API
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Principal {
+    /// Subject of the bearer token, or API key owner
+    #[serde(default)]
+    pub id: String,
+    /// user (bearer token) or service (API key)
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Roles>,
+    /// Operator (MVNO) whose customers and groups the principal works with, none for the platform
+    /// wide data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+// Encode Principal as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_principal<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Principal,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("kind")?;
+    e.str(&val.kind)?;
+    if let Some(val) = val.roles.as_ref() {
+        e.str("roles")?;
+        encode_roles(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.tenant.as_ref() {
+        e.str("tenant")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode Principal from cbor input stream
+#[doc(hidden)]
+pub fn decode_principal(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Principal, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut kind: Option<String> = None;
+        let mut roles: Option<Option<Roles>> = Some(None);
+        let mut tenant: Option<Option<String>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Principal, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => kind = Some(d.str()?.to_string()),
+                    2 => {
+                        roles = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_roles(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Roles': {}", e)
+                            })?))
+                        }
+                    }
+                    3 => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "kind" => kind = Some(d.str()?.to_string()),
+                    "roles" => {
+                        roles = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_roles(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Roles': {}", e)
+                            })?))
+                        }
+                    }
+                    "tenant" => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Principal {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Principal.id (#0)".to_string(),
+                ));
+            },
+
+            kind: if let Some(__x) = kind {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Principal.kind (#1)".to_string(),
+                ));
+            },
+            roles: roles.unwrap(),
+            tenant: tenant.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RefundReply {
+    #[serde(default)]
+    pub balance: f64,
+    /// Bucket units debited
+    #[serde(default)]
+    pub refunded: f64,
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode RefundReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_refund_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RefundReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("balance")?;
+    e.f64(val.balance)?;
+    e.str("refunded")?;
+    e.f64(val.refunded)?;
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode RefundReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_refund_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RefundReply, RpcError> {
+    let __result = {
+        let mut balance: Option<f64> = None;
+        let mut refunded: Option<f64> = None;
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RefundReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => balance = Some(d.f64()?),
+                    1 => refunded = Some(d.f64()?),
+                    2 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "balance" => balance = Some(d.f64()?),
+                    "refunded" => refunded = Some(d.f64()?),
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RefundReply {
+            balance: if let Some(__x) = balance {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundReply.balance (#0)".to_string(),
+                ));
+            },
+
+            refunded: if let Some(__x) = refunded {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundReply.refunded (#1)".to_string(),
+                ));
+            },
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundReply.success (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+/// Refunds debit the units still in the bucket (partial refund) unless `config:refund_policy` is `negative`.
+/// Chargebacks always debit all the units requested, the balance may become negative.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RefundRequest {
+    /// Bucket units to refund, all the units not refunded yet when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    pub credentials: VendorCredentials,
+    /// Client supplied token : a retried request with the same key gets the original reply
+    #[serde(rename = "idempotencyKey")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    #[serde(default)]
+    pub order: String,
+    /// refund or chargeback, refund when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Selects the period the refund is invoiced in
+    #[serde(default)]
+    pub timestamp: Timestamp,
+}
+
+// Encode RefundRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_refund_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RefundRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(6)?;
+    if let Some(val) = val.amount.as_ref() {
+        e.str("amount")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("credentials")?;
+    encode_vendor_credentials(e, &val.credentials)?;
+    if let Some(val) = val.idempotency_key.as_ref() {
+        e.str("idempotencyKey")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("order")?;
+    e.str(&val.order)?;
+    if let Some(val) = val.reason.as_ref() {
+        e.str("reason")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    Ok(())
+}
+
+// Decode RefundRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_refund_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RefundRequest, RpcError> {
+    let __result = {
+        let mut amount: Option<Option<f64>> = Some(None);
+        let mut credentials: Option<VendorCredentials> = None;
+        let mut idempotency_key: Option<Option<String>> = Some(None);
+        let mut order: Option<String> = None;
+        let mut reason: Option<Option<String>> = Some(None);
+        let mut timestamp: Option<Timestamp> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RefundRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        amount = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    1 => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    2 => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => order = Some(d.str()?.to_string()),
+                    4 => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    5 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => {
+                        amount = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "credentials" => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    "idempotencyKey" => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "order" => order = Some(d.str()?.to_string()),
+                    "reason" => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RefundRequest {
+            amount: amount.unwrap(),
+
+            credentials: if let Some(__x) = credentials {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundRequest.credentials (#1)".to_string(),
+                ));
+            },
+            idempotency_key: idempotency_key.unwrap(),
+
+            order: if let Some(__x) = order {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundRequest.order (#3)".to_string(),
+                ));
+            },
+            reason: reason.unwrap(),
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RefundRequest.timestamp (#5)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RegisterVendorReply {
+    #[serde(default)]
+    pub id: String,
+    /// Only returned at registration, the vendor keeps it to authenticate its calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode RegisterVendorReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_register_vendor_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RegisterVendorReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.secret.as_ref() {
+        e.str("secret")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode RegisterVendorReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_register_vendor_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RegisterVendorReply, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut secret: Option<Option<String>> = Some(None);
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RegisterVendorReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "secret" => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RegisterVendorReply {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RegisterVendorReply.id (#0)".to_string(),
+                ));
+            },
+            secret: secret.unwrap(),
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RegisterVendorReply.success (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RegisterWebhookReply {
+    #[serde(default)]
+    pub id: String,
+    /// Only returned on registration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode RegisterWebhookReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_register_webhook_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RegisterWebhookReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.secret.as_ref() {
+        e.str("secret")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode RegisterWebhookReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_register_webhook_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RegisterWebhookReply, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut secret: Option<Option<String>> = Some(None);
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RegisterWebhookReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "secret" => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RegisterWebhookReply {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RegisterWebhookReply.id (#0)".to_string(),
+                ));
+            },
+            secret: secret.unwrap(),
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RegisterWebhookReply.success (#2)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RegisterWebhookRequest {
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    pub webhook: Webhook,
+}
+
+// Encode RegisterWebhookRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_register_webhook_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RegisterWebhookRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("webhook")?;
+    encode_webhook(e, &val.webhook)?;
+    Ok(())
+}
+
+// Decode RegisterWebhookRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_register_webhook_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RegisterWebhookRequest, RpcError> {
+    let __result = {
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut webhook: Option<Webhook> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RegisterWebhookRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        webhook = Some(decode_webhook(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Webhook': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "webhook" => {
+                        webhook = Some(decode_webhook(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Webhook': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RegisterWebhookRequest {
+            principal: principal.unwrap(),
+
+            webhook: if let Some(__x) = webhook {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RegisterWebhookRequest.webhook (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RemoveCustomerReply {
+    /// Revision of the group
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+    /// Whether the customer was a member of the group
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode RemoveCustomerReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_remove_customer_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RemoveCustomerReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode RemoveCustomerReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_remove_customer_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RemoveCustomerReply, RpcError> {
+    let __result = {
+        let mut revision: Option<Option<u64>> = Some(None);
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RemoveCustomerReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    1 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RemoveCustomerReply {
+            revision: revision.unwrap(),
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RemoveCustomerReply.success (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RemoveCustomerRequest {
+    #[serde(default)]
+    pub customer: String,
+    /// Id of the group
+    #[serde(default)]
+    pub group: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    /// Revision the group must be at, the change is refused with a revision mismatch otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+// Encode RemoveCustomerRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_remove_customer_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RemoveCustomerRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    e.str("customer")?;
+    e.str(&val.customer)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode RemoveCustomerRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_remove_customer_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RemoveCustomerRequest, RpcError> {
+    let __result = {
+        let mut customer: Option<String> = None;
+        let mut group: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut revision: Option<Option<u64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RemoveCustomerRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => customer = Some(d.str()?.to_string()),
+                    1 => group = Some(d.str()?.to_string()),
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    3 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "customer" => customer = Some(d.str()?.to_string()),
+                    "group" => group = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RemoveCustomerRequest {
+            customer: if let Some(__x) = customer {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RemoveCustomerRequest.customer (#0)".to_string(),
+                ));
+            },
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RemoveCustomerRequest.group (#1)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+            revision: revision.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RenameCustomerGroupReply {
+    /// Revision of the renamed group
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode RenameCustomerGroupReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_rename_customer_group_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RenameCustomerGroupReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode RenameCustomerGroupReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_rename_customer_group_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RenameCustomerGroupReply, RpcError> {
+    let __result = {
+        let mut revision: Option<Option<u64>> = Some(None);
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RenameCustomerGroupReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    1 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RenameCustomerGroupReply {
+            revision: revision.unwrap(),
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RenameCustomerGroupReply.success (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RenameCustomerGroupRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    /// Revision the group must be at, the change is refused with a revision mismatch otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+// Encode RenameCustomerGroupRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_rename_customer_group_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RenameCustomerGroupRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("name")?;
+    e.str(&val.name)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.revision.as_ref() {
+        e.str("revision")?;
+        e.u64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode RenameCustomerGroupRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_rename_customer_group_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RenameCustomerGroupRequest, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+        let mut name: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut revision: Option<Option<u64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RenameCustomerGroupRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    1 => name = Some(d.str()?.to_string()),
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    3 => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    "name" => name = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "revision" => {
+                        revision = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RenameCustomerGroupRequest {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RenameCustomerGroupRequest.id (#0)".to_string(),
+                ));
+            },
+
+            name: if let Some(__x) = name {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RenameCustomerGroupRequest.name (#1)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+            revision: revision.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RestoreBucketsRequest {
+    /// The buckets are restored in the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    pub records: ExportedRecords,
+}
+
+// Encode RestoreBucketsRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_restore_buckets_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RestoreBucketsRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("records")?;
+    encode_exported_records(e, &val.records)?;
+    Ok(())
+}
+
+// Decode RestoreBucketsRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_restore_buckets_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RestoreBucketsRequest, RpcError> {
+    let __result = {
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut records: Option<ExportedRecords> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RestoreBucketsRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "records" => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RestoreBucketsRequest {
+            principal: principal.unwrap(),
+
+            records: if let Some(__x) = records {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RestoreBucketsRequest.records (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RestoreRecordsReply {
+    /// Records written
+    #[serde(default)]
+    pub restored: u32,
+}
+
+// Encode RestoreRecordsReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_restore_records_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RestoreRecordsReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    e.str("restored")?;
+    e.u32(val.restored)?;
+    Ok(())
+}
+
+// Decode RestoreRecordsReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_restore_records_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RestoreRecordsReply, RpcError> {
+    let __result = {
+        let mut restored: Option<u32> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RestoreRecordsReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => restored = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "restored" => restored = Some(d.u32()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RestoreRecordsReply {
+            restored: if let Some(__x) = restored {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RestoreRecordsReply.restored (#0)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RestoreRecordsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    pub records: ExportedRecords,
+}
+
+// Encode RestoreRecordsRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_restore_records_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &RestoreRecordsRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("records")?;
+    encode_exported_records(e, &val.records)?;
+    Ok(())
+}
+
+// Decode RestoreRecordsRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_restore_records_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<RestoreRecordsRequest, RpcError> {
+    let __result = {
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut records: Option<ExportedRecords> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct RestoreRecordsRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "records" => {
+                        records = Some(decode_exported_records(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ExportedRecords': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        RestoreRecordsRequest {
+            principal: principal.unwrap(),
+
+            records: if let Some(__x) = records {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field RestoreRecordsRequest.records (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type Roles = Vec<String>;
+
+// Encode Roles as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_roles<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Roles,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode Roles from cbor input stream
+#[doc(hidden)]
+pub fn decode_roles(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Roles, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Service {
+    #[serde(default)]
+    pub id: String,
+}
+
+// Encode Service as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_service<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Service,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    Ok(())
+}
+
+// Decode Service from cbor input stream
+#[doc(hidden)]
+pub fn decode_service(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Service, RpcError> {
+    let __result = {
+        let mut id: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Service, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => id = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => id = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Service {
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser("missing field Service.id (#0)".to_string()));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type ServiceIds = Vec<String>;
+
+// Encode ServiceIds as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_service_ids<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ServiceIds,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        e.str(item)?;
+    }
+    Ok(())
+}
+
+// Decode ServiceIds from cbor input stream
+#[doc(hidden)]
+pub fn decode_service_ids(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<ServiceIds, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<String> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(d.str()?.to_string())
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<String> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(d.str()?.to_string()),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ServiceOrder {
+    /// Bucket units bought
+    #[serde(default)]
+    pub amount: f64,
+    pub credentials: VendorCredentials,
+    /// Customer buying the units, their location selects the taxes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<String>,
+    #[serde(default)]
+    pub group: String,
+    /// Client supplied token : a retried request with the same key gets the original reply
+    #[serde(rename = "idempotencyKey")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    pub service: Service,
+    /// When the order was placed, selects the period it is invoiced in
+    #[serde(default)]
+    pub timestamp: Timestamp,
+}
+
+// Encode ServiceOrder as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_service_order<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ServiceOrder,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(7)?;
+    e.str("amount")?;
+    e.f64(val.amount)?;
+    e.str("credentials")?;
+    encode_vendor_credentials(e, &val.credentials)?;
+    if let Some(val) = val.customer.as_ref() {
+        e.str("customer")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.idempotency_key.as_ref() {
+        e.str("idempotencyKey")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("service")?;
+    encode_service(e, &val.service)?;
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    Ok(())
+}
+
+// Decode ServiceOrder from cbor input stream
+#[doc(hidden)]
+pub fn decode_service_order(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ServiceOrder, RpcError> {
+    let __result = {
+        let mut amount: Option<f64> = None;
+        let mut credentials: Option<VendorCredentials> = None;
+        let mut customer: Option<Option<String>> = Some(None);
+        let mut group: Option<String> = None;
+        let mut idempotency_key: Option<Option<String>> = Some(None);
+        let mut service: Option<Service> = None;
+        let mut timestamp: Option<Timestamp> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ServiceOrder, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => amount = Some(d.f64()?),
+                    1 => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    2 => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => group = Some(d.str()?.to_string()),
+                    4 => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    5 => {
+                        service = Some(decode_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                        })?)
+                    }
+                    6 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => amount = Some(d.f64()?),
+                    "credentials" => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    "customer" => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    "idempotencyKey" => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "service" => {
+                        service = Some(decode_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                        })?)
+                    }
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ServiceOrder {
+            amount: if let Some(__x) = amount {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceOrder.amount (#0)".to_string(),
+                ));
+            },
+
+            credentials: if let Some(__x) = credentials {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceOrder.credentials (#1)".to_string(),
+                ));
+            },
+            customer: customer.unwrap(),
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceOrder.group (#3)".to_string(),
+                ));
+            },
+            idempotency_key: idempotency_key.unwrap(),
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceOrder.service (#5)".to_string(),
+                ));
+            },
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceOrder.timestamp (#6)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServiceRef {
+    pub credentials: VendorCredentials,
+    #[serde(default)]
+    pub id: String,
+}
+
+// Encode ServiceRef as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_service_ref<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ServiceRef,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("credentials")?;
+    encode_vendor_credentials(e, &val.credentials)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    Ok(())
+}
+
+// Decode ServiceRef from cbor input stream
+#[doc(hidden)]
+pub fn decode_service_ref(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<ServiceRef, RpcError> {
+    let __result = {
+        let mut credentials: Option<VendorCredentials> = None;
+        let mut id: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ServiceRef, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    1 => id = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "credentials" => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    "id" => id = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ServiceRef {
+            credentials: if let Some(__x) = credentials {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceRef.credentials (#0)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceRef.id (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ServiceUsage {
+    #[serde(default)]
+    pub client: String,
+    pub credentials: VendorCredentials,
+    #[serde(default)]
+    pub group: String,
+    /// Client supplied token : a retried request with the same key gets the original reply
+    #[serde(rename = "idempotencyKey")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    pub service: Service,
+    /// When the service was used, also selects the period thresholds are evaluated in
+    #[serde(default)]
+    pub timestamp: Timestamp,
+    /// Units of service consumed, converted to bucket units with the catalog conversion rate
+    #[serde(default)]
+    pub units: f64,
+}
+
+// Encode ServiceUsage as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_service_usage<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ServiceUsage,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(7)?;
+    e.str("client")?;
+    e.str(&val.client)?;
+    e.str("credentials")?;
+    encode_vendor_credentials(e, &val.credentials)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.idempotency_key.as_ref() {
+        e.str("idempotencyKey")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("service")?;
+    encode_service(e, &val.service)?;
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    e.str("units")?;
+    e.f64(val.units)?;
+    Ok(())
+}
+
+// Decode ServiceUsage from cbor input stream
+#[doc(hidden)]
+pub fn decode_service_usage(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ServiceUsage, RpcError> {
+    let __result = {
+        let mut client: Option<String> = None;
+        let mut credentials: Option<VendorCredentials> = None;
+        let mut group: Option<String> = None;
+        let mut idempotency_key: Option<Option<String>> = Some(None);
+        let mut service: Option<Service> = None;
+        let mut timestamp: Option<Timestamp> = None;
+        let mut units: Option<f64> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct ServiceUsage, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => client = Some(d.str()?.to_string()),
+                    1 => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    2 => group = Some(d.str()?.to_string()),
+                    3 => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    4 => {
+                        service = Some(decode_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                        })?)
+                    }
+                    5 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    6 => units = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "client" => client = Some(d.str()?.to_string()),
+                    "credentials" => {
+                        credentials = Some(decode_vendor_credentials(d).map_err(|e| {
+                            format!(
+                                "decoding 'com.orange.sharedbucket#VendorCredentials': {}",
+                                e
+                            )
+                        })?)
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    "idempotencyKey" => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "service" => {
+                        service = Some(decode_service(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Service': {}", e)
+                        })?)
+                    }
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    "units" => units = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        ServiceUsage {
+            client: if let Some(__x) = client {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.client (#0)".to_string(),
+                ));
+            },
+
+            credentials: if let Some(__x) = credentials {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.credentials (#1)".to_string(),
+                ));
+            },
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.group (#2)".to_string(),
+                ));
+            },
+            idempotency_key: idempotency_key.unwrap(),
+
+            service: if let Some(__x) = service {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.service (#4)".to_string(),
+                ));
+            },
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.timestamp (#5)".to_string(),
+                ));
+            },
+
+            units: if let Some(__x) = units {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field ServiceUsage.units (#6)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SetBucketThresholdsReply {
+    #[serde(default)]
+    pub success: bool,
+}
+
+// Encode SetBucketThresholdsReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_set_bucket_thresholds_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &SetBucketThresholdsReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(1)?;
+    e.str("success")?;
+    e.bool(val.success)?;
+    Ok(())
+}
+
+// Decode SetBucketThresholdsReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_set_bucket_thresholds_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<SetBucketThresholdsReply, RpcError> {
+    let __result = {
+        let mut success: Option<bool> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct SetBucketThresholdsReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "success" => success = Some(d.bool()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        SetBucketThresholdsReply {
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field SetBucketThresholdsReply.success (#0)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SetBucketThresholdsRequest {
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    pub thresholds: BucketThresholds,
+}
+
+// Encode SetBucketThresholdsRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_set_bucket_thresholds_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &SetBucketThresholdsRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("thresholds")?;
+    encode_bucket_thresholds(e, &val.thresholds)?;
+    Ok(())
+}
+
+// Decode SetBucketThresholdsRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_set_bucket_thresholds_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<SetBucketThresholdsRequest, RpcError> {
+    let __result = {
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut thresholds: Option<BucketThresholds> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct SetBucketThresholdsRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        thresholds = Some(decode_bucket_thresholds(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#BucketThresholds': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "thresholds" => {
+                        thresholds = Some(decode_bucket_thresholds(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#BucketThresholds': {}", e)
+                        })?)
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        SetBucketThresholdsRequest {
+            principal: principal.unwrap(),
+
+            thresholds: if let Some(__x) = thresholds {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field SetBucketThresholdsRequest.thresholds (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+/// Rating of the usage of a service into bucket units, rates are bucket units per unit of service
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Tariff {
+    /// Discounts by cumulated units of service in the period (volume)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<VolumeDiscounts>,
+    /// Rates by hour of the day, UTC (time_of_day)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<HourRates>,
+    /// flat, tiered, time_of_day or volume
+    #[serde(default)]
+    pub kind: String,
+    /// Rate of flat tariffs, and default rate of the others
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+    /// Rates by cumulated units of service in the period (tiered)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<TariffTiers>,
+}
+
+// Encode Tariff as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_tariff<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Tariff,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(5)?;
+    if let Some(val) = val.discounts.as_ref() {
+        e.str("discounts")?;
+        encode_volume_discounts(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.hours.as_ref() {
+        e.str("hours")?;
+        encode_hour_rates(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("kind")?;
+    e.str(&val.kind)?;
+    if let Some(val) = val.rate.as_ref() {
+        e.str("rate")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.tiers.as_ref() {
+        e.str("tiers")?;
+        encode_tariff_tiers(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode Tariff from cbor input stream
+#[doc(hidden)]
+pub fn decode_tariff(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Tariff, RpcError> {
+    let __result = {
+        let mut discounts: Option<Option<VolumeDiscounts>> = Some(None);
+        let mut hours: Option<Option<HourRates>> = Some(None);
+        let mut kind: Option<String> = None;
+        let mut rate: Option<Option<f64>> = Some(None);
+        let mut tiers: Option<Option<TariffTiers>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Tariff, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        discounts = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_volume_discounts(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#VolumeDiscounts': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => {
+                        hours = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_hour_rates(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#HourRates': {}", e)
+                            })?))
+                        }
+                    }
+                    2 => kind = Some(d.str()?.to_string()),
+                    3 => {
+                        rate = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    4 => {
+                        tiers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_tariff_tiers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#TariffTiers': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "discounts" => {
+                        discounts = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_volume_discounts(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#VolumeDiscounts': {}", e)
+                            })?))
+                        }
+                    }
+                    "hours" => {
+                        hours = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_hour_rates(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#HourRates': {}", e)
+                            })?))
+                        }
+                    }
+                    "kind" => kind = Some(d.str()?.to_string()),
+                    "rate" => {
+                        rate = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "tiers" => {
+                        tiers = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_tariff_tiers(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#TariffTiers': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Tariff {
+            discounts: discounts.unwrap(),
+            hours: hours.unwrap(),
+
+            kind: if let Some(__x) = kind {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Tariff.kind (#2)".to_string(),
+                ));
+            },
+            rate: rate.unwrap(),
+            tiers: tiers.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Rate of the units of service up to `upTo` units in the period, the last tier has no limit
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TariffTier {
+    #[serde(default)]
+    pub rate: f64,
+    #[serde(rename = "upTo")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub up_to: Option<f64>,
+}
+
+// Encode TariffTier as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_tariff_tier<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TariffTier,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("rate")?;
+    e.f64(val.rate)?;
+    if let Some(val) = val.up_to.as_ref() {
+        e.str("upTo")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode TariffTier from cbor input stream
+#[doc(hidden)]
+pub fn decode_tariff_tier(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<TariffTier, RpcError> {
+    let __result = {
+        let mut rate: Option<f64> = None;
+        let mut up_to: Option<Option<f64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct TariffTier, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => rate = Some(d.f64()?),
+                    1 => {
+                        up_to = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "rate" => rate = Some(d.f64()?),
+                    "upTo" => {
+                        up_to = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        TariffTier {
+            rate: if let Some(__x) = rate {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TariffTier.rate (#0)".to_string(),
+                ));
+            },
+            up_to: up_to.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type TariffTiers = Vec<TariffTier>;
+
+// Encode TariffTiers as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_tariff_tiers<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TariffTiers,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_tariff_tier(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode TariffTiers from cbor input stream
+#[doc(hidden)]
+pub fn decode_tariff_tiers(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<TariffTiers, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<TariffTier> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_tariff_tier(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#TariffTier': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<TariffTier> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_tariff_tier(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#TariffTier': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+/// Tax rate of a location, rules are read from the `config:tax_rules` key as a JSON list.
+/// The rule of the region applies over the one of the country, the `*` country rule applies elsewhere.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TaxRule {
+    /// ISO 3166-1 alpha-2 code, or `*`
+    #[serde(default)]
+    pub country: String,
+    /// Percentage
+    #[serde(default)]
+    pub rate: f64,
+    /// ISO 3166-2 subdivision code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+// Encode TaxRule as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_tax_rule<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TaxRule,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("country")?;
+    e.str(&val.country)?;
+    e.str("rate")?;
+    e.f64(val.rate)?;
+    if let Some(val) = val.region.as_ref() {
+        e.str("region")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode TaxRule from cbor input stream
+#[doc(hidden)]
+pub fn decode_tax_rule(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<TaxRule, RpcError> {
+    let __result = {
+        let mut country: Option<String> = None;
+        let mut rate: Option<f64> = None;
+        let mut region: Option<Option<String>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct TaxRule, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => country = Some(d.str()?.to_string()),
+                    1 => rate = Some(d.f64()?),
+                    2 => {
+                        region = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "country" => country = Some(d.str()?.to_string()),
+                    "rate" => rate = Some(d.f64()?),
+                    "region" => {
+                        region = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        TaxRule {
+            country: if let Some(__x) = country {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TaxRule.country (#0)".to_string(),
+                ));
+            },
+
+            rate: if let Some(__x) = rate {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TaxRule.rate (#1)".to_string(),
+                ));
+            },
+            region: region.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type TaxRules = Vec<TaxRule>;
+
+// Encode TaxRules as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_tax_rules<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TaxRules,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_tax_rule(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode TaxRules from cbor input stream
+#[doc(hidden)]
+pub fn decode_tax_rules(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<TaxRules, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<TaxRule> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_tax_rule(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#TaxRule': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<TaxRule> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_tax_rule(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#TaxRule': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+/// Alert fired at most once per period (calendar month) when reached on a debit.
+/// Either `usedPercentage` or `remaining` should be set
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Threshold {
+    /// Last period (YYYY-MM) the threshold fired in, maintained by the actor
+    #[serde(rename = "firedPeriod")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fired_period: Option<String>,
+    #[serde(default)]
+    pub id: String,
+    /// Reached once the balance is at or below this number of units
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<f64>,
+    /// Reached once this percentage of the credited units has been used
+    #[serde(rename = "usedPercentage")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_percentage: Option<f64>,
+}
+
+// Encode Threshold as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_threshold<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Threshold,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    if let Some(val) = val.fired_period.as_ref() {
+        e.str("firedPeriod")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.remaining.as_ref() {
+        e.str("remaining")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.used_percentage.as_ref() {
+        e.str("usedPercentage")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode Threshold from cbor input stream
+#[doc(hidden)]
+pub fn decode_threshold(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Threshold, RpcError> {
+    let __result = {
+        let mut fired_period: Option<Option<String>> = Some(None);
+        let mut id: Option<String> = None;
+        let mut remaining: Option<Option<f64>> = Some(None);
+        let mut used_percentage: Option<Option<f64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Threshold, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        fired_period = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => id = Some(d.str()?.to_string()),
+                    2 => {
+                        remaining = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    3 => {
+                        used_percentage = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "firedPeriod" => {
+                        fired_period = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "id" => id = Some(d.str()?.to_string()),
+                    "remaining" => {
+                        remaining = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "usedPercentage" => {
+                        used_percentage = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Threshold {
+            fired_period: fired_period.unwrap(),
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Threshold.id (#1)".to_string(),
+                ));
+            },
+            remaining: remaining.unwrap(),
+            used_percentage: used_percentage.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+pub type ThresholdList = Vec<Threshold>;
+
+// Encode ThresholdList as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_threshold_list<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &ThresholdList,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_threshold(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode ThresholdList from cbor input stream
+#[doc(hidden)]
+pub fn decode_threshold_list(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<ThresholdList, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<Threshold> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_threshold(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#Threshold': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<Threshold> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_threshold(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Threshold': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+/// Audit trail entry of a transfer
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Transfer {
+    #[serde(default)]
+    pub amount: f64,
+    /// Id of the principal who made the transfer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    #[serde(default)]
+    pub from: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// pending, completed or failed
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub timestamp: Timestamp,
+    #[serde(default)]
+    pub to: String,
+}
+
+// Encode Transfer as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_transfer<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Transfer,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(8)?;
+    e.str("amount")?;
+    e.f64(val.amount)?;
+    if let Some(val) = val.by.as_ref() {
+        e.str("by")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("from")?;
+    e.str(&val.from)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.reason.as_ref() {
+        e.str("reason")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("status")?;
+    e.str(&val.status)?;
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    e.str("to")?;
+    e.str(&val.to)?;
+    Ok(())
+}
+
+// Decode Transfer from cbor input stream
+#[doc(hidden)]
+pub fn decode_transfer(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Transfer, RpcError> {
+    let __result = {
+        let mut amount: Option<f64> = None;
+        let mut by: Option<Option<String>> = Some(None);
+        let mut from: Option<String> = None;
+        let mut id: Option<String> = None;
+        let mut reason: Option<Option<String>> = Some(None);
+        let mut status: Option<String> = None;
+        let mut timestamp: Option<Timestamp> = None;
+        let mut to: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Transfer, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => amount = Some(d.f64()?),
+                    1 => {
+                        by = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => from = Some(d.str()?.to_string()),
+                    3 => id = Some(d.str()?.to_string()),
+                    4 => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    5 => status = Some(d.str()?.to_string()),
+                    6 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    7 => to = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => amount = Some(d.f64()?),
+                    "by" => {
+                        by = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "from" => from = Some(d.str()?.to_string()),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "reason" => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "status" => status = Some(d.str()?.to_string()),
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    "to" => to = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Transfer {
+            amount: if let Some(__x) = amount {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.amount (#0)".to_string(),
+                ));
+            },
+            by: by.unwrap(),
+
+            from: if let Some(__x) = from {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.from (#2)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.id (#3)".to_string(),
+                ));
+            },
+            reason: reason.unwrap(),
+
+            status: if let Some(__x) = status {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.status (#5)".to_string(),
+                ));
+            },
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.timestamp (#6)".to_string(),
+                ));
+            },
+
+            to: if let Some(__x) = to {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Transfer.to (#7)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TransferReply {
+    /// Balance of the source bucket
+    #[serde(rename = "fromBalance")]
+    #[serde(default)]
+    pub from_balance: f64,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub success: bool,
+    /// Balance of the destination bucket
+    #[serde(rename = "toBalance")]
+    #[serde(default)]
+    pub to_balance: f64,
+}
+
+// Encode TransferReply as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_transfer_reply<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TransferReply,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    e.str("fromBalance")?;
+    e.f64(val.from_balance)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("success")?;
+    e.bool(val.success)?;
+    e.str("toBalance")?;
+    e.f64(val.to_balance)?;
+    Ok(())
+}
+
+// Decode TransferReply from cbor input stream
+#[doc(hidden)]
+pub fn decode_transfer_reply(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<TransferReply, RpcError> {
+    let __result = {
+        let mut from_balance: Option<f64> = None;
+        let mut id: Option<String> = None;
+        let mut success: Option<bool> = None;
+        let mut to_balance: Option<f64> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct TransferReply, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => from_balance = Some(d.f64()?),
+                    1 => id = Some(d.str()?.to_string()),
+                    2 => success = Some(d.bool()?),
+                    3 => to_balance = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "fromBalance" => from_balance = Some(d.f64()?),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "success" => success = Some(d.bool()?),
+                    "toBalance" => to_balance = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        TransferReply {
+            from_balance: if let Some(__x) = from_balance {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferReply.from_balance (#0)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferReply.id (#1)".to_string(),
+                ));
+            },
+
+            success: if let Some(__x) = success {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferReply.success (#2)".to_string(),
+                ));
+            },
+
+            to_balance: if let Some(__x) = to_balance {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferReply.to_balance (#3)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+/// Transfers are limited to `config:transfer_max_units` units (1000 by default) unless made by an operator
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TransferRequest {
+    #[serde(default)]
+    pub amount: f64,
+    /// Source group
+    #[serde(default)]
+    pub from: String,
+    /// Client supplied token : a retried request with the same key gets the original reply
+    #[serde(rename = "idempotencyKey")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// Caller, recorded in the audit trail. Both groups are ones of its tenant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Selects the period the transfer is recorded in
+    #[serde(default)]
+    pub timestamp: Timestamp,
+    /// Destination group
+    #[serde(default)]
+    pub to: String,
+}
+
+// Encode TransferRequest as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_transfer_request<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &TransferRequest,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(7)?;
+    e.str("amount")?;
+    e.f64(val.amount)?;
+    e.str("from")?;
+    e.str(&val.from)?;
+    if let Some(val) = val.idempotency_key.as_ref() {
+        e.str("idempotencyKey")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.reason.as_ref() {
+        e.str("reason")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("timestamp")?;
+    e.i64(val.timestamp.sec)?;
+    e.u32(val.timestamp.nsec)?;
+    e.str("to")?;
+    e.str(&val.to)?;
+    Ok(())
+}
+
+// Decode TransferRequest from cbor input stream
+#[doc(hidden)]
+pub fn decode_transfer_request(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<TransferRequest, RpcError> {
+    let __result = {
+        let mut amount: Option<f64> = None;
+        let mut from: Option<String> = None;
+        let mut idempotency_key: Option<Option<String>> = Some(None);
+        let mut principal: Option<Option<Principal>> = Some(None);
+        let mut reason: Option<Option<String>> = Some(None);
+        let mut timestamp: Option<Timestamp> = None;
+        let mut to: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct TransferRequest, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => amount = Some(d.f64()?),
+                    1 => from = Some(d.str()?.to_string()),
+                    2 => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    4 => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    5 => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    6 => to = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "amount" => amount = Some(d.f64()?),
+                    "from" => from = Some(d.str()?.to_string()),
+                    "idempotencyKey" => {
+                        idempotency_key = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    "reason" => {
+                        reason = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "timestamp" => {
+                        timestamp = Some(wasmbus_rpc::Timestamp {
+                            sec: d.i64()?,
+                            nsec: d.u32()?,
+                        })
+                    }
+                    "to" => to = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        TransferRequest {
+            amount: if let Some(__x) = amount {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferRequest.amount (#0)".to_string(),
+                ));
+            },
+
+            from: if let Some(__x) = from {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferRequest.from (#1)".to_string(),
+                ));
+            },
+            idempotency_key: idempotency_key.unwrap(),
+            principal: principal.unwrap(),
+            reason: reason.unwrap(),
+
+            timestamp: if let Some(__x) = timestamp {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferRequest.timestamp (#5)".to_string(),
+                ));
+            },
+
+            to: if let Some(__x) = to {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field TransferRequest.to (#6)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type Transfers = Vec<Transfer>;
+
+// Encode Transfers as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_transfers<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Transfers,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_transfer(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode Transfers from cbor input stream
+#[doc(hidden)]
+pub fn decode_transfers(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Transfers, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<Transfer> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_transfer(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#Transfer': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<Transfer> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_transfer(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Transfer': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+/// Usage event published on the `sharedbucket.events.<eventType>` subject.
+/// The JSON form is described by usage-event.v1.schema.json
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct UsageEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<String>,
+    /// One of customer.created, group.member_added, group.member_removed, bucket.debited,
+    /// bucket.low, bucket.exhausted, bucket.threshold_reached, order.confirmed, order.refunded
+    #[serde(rename = "eventType")]
+    #[serde(default)]
+    pub event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    #[serde(rename = "schemaVersion")]
+    #[serde(default)]
+    pub schema_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Tenant of the customer and group, absent for the platform wide ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub units: Option<f64>,
+}
+
+// Encode UsageEvent as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_usage_event<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &UsageEvent,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(11)?;
+    if let Some(val) = val.balance.as_ref() {
+        e.str("balance")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.customer.as_ref() {
+        e.str("customer")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("eventType")?;
+    e.str(&val.event_type)?;
+    if let Some(val) = val.group.as_ref() {
+        e.str("group")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.order.as_ref() {
+        e.str("order")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("schemaVersion")?;
+    e.str(&val.schema_version)?;
+    if let Some(val) = val.service.as_ref() {
+        e.str("service")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.tenant.as_ref() {
+        e.str("tenant")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.threshold.as_ref() {
+        e.str("threshold")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.units.as_ref() {
+        e.str("units")?;
+        e.f64(*val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode UsageEvent from cbor input stream
+#[doc(hidden)]
+pub fn decode_usage_event(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<UsageEvent, RpcError> {
+    let __result = {
+        let mut balance: Option<Option<f64>> = Some(None);
+        let mut customer: Option<Option<String>> = Some(None);
+        let mut event_type: Option<String> = None;
+        let mut group: Option<Option<String>> = Some(None);
+        let mut id: Option<String> = None;
+        let mut order: Option<Option<String>> = Some(None);
+        let mut schema_version: Option<String> = None;
+        let mut service: Option<Option<String>> = Some(None);
+        let mut tenant: Option<Option<String>> = Some(None);
+        let mut threshold: Option<Option<String>> = Some(None);
+        let mut units: Option<Option<f64>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct UsageEvent, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        balance = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    1 => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    2 => event_type = Some(d.str()?.to_string()),
+                    3 => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    4 => id = Some(d.str()?.to_string()),
+                    5 => {
+                        order = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    6 => schema_version = Some(d.str()?.to_string()),
+                    7 => {
+                        service = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    8 => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    9 => {
+                        threshold = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    10 => {
+                        units = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "balance" => {
+                        balance = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    "customer" => {
+                        customer = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "eventType" => event_type = Some(d.str()?.to_string()),
+                    "group" => {
+                        group = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "id" => id = Some(d.str()?.to_string()),
+                    "order" => {
+                        order = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "schemaVersion" => schema_version = Some(d.str()?.to_string()),
+                    "service" => {
+                        service = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "tenant" => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "threshold" => {
+                        threshold = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "units" => {
+                        units = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.f64()?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        UsageEvent {
+            balance: balance.unwrap(),
+            customer: customer.unwrap(),
+
+            event_type: if let Some(__x) = event_type {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field UsageEvent.event_type (#2)".to_string(),
+                ));
+            },
+            group: group.unwrap(),
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field UsageEvent.id (#4)".to_string(),
+                ));
+            },
+            order: order.unwrap(),
+
+            schema_version: if let Some(__x) = schema_version {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field UsageEvent.schema_version (#6)".to_string(),
+                ));
+            },
+            service: service.unwrap(),
+            tenant: tenant.unwrap(),
+            threshold: threshold.unwrap(),
+            units: units.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Third-party selling services, a service being owned by a single vendor
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Vendor {
+    /// Generated when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub name: String,
+    pub services: ServiceIds,
+    /// Tenant of the groups and customers the vendor serves, the one of the principal registering
+    /// it, absent for the platform wide ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+// Encode Vendor as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_vendor<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Vendor,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(4)?;
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("name")?;
+    e.str(&val.name)?;
+    e.str("services")?;
+    encode_service_ids(e, &val.services)?;
+    if let Some(val) = val.tenant.as_ref() {
+        e.str("tenant")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode Vendor from cbor input stream
+#[doc(hidden)]
+pub fn decode_vendor(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Vendor, RpcError> {
+    let __result = {
+        let mut id: Option<Option<String>> = Some(None);
+        let mut name: Option<String> = None;
+        let mut services: Option<ServiceIds> = None;
+        let mut tenant: Option<Option<String>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Vendor, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    1 => name = Some(d.str()?.to_string()),
+                    2 => {
+                        services = Some(decode_service_ids(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ServiceIds': {}", e)
+                        })?)
+                    }
+                    3 => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "name" => name = Some(d.str()?.to_string()),
+                    "services" => {
+                        services = Some(decode_service_ids(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#ServiceIds': {}", e)
+                        })?)
+                    }
+                    "tenant" => {
+                        tenant = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Vendor {
+            id: id.unwrap(),
+
+            name: if let Some(__x) = name {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Vendor.name (#1)".to_string(),
+                ));
+            },
+
+            services: if let Some(__x) = services {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Vendor.services (#2)".to_string(),
+                ));
+            },
+            tenant: tenant.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// Vendor calling AuthorizeServiceUsage or BuyService, it may only use the services it owns
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct VendorCredentials {
+    #[serde(default)]
+    pub secret: String,
+    #[serde(default)]
+    pub vendor: String,
+}
+
+// Encode VendorCredentials as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_vendor_credentials<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &VendorCredentials,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("secret")?;
+    e.str(&val.secret)?;
+    e.str("vendor")?;
+    e.str(&val.vendor)?;
+    Ok(())
+}
+
+// Decode VendorCredentials from cbor input stream
+#[doc(hidden)]
+pub fn decode_vendor_credentials(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<VendorCredentials, RpcError> {
+    let __result = {
+        let mut secret: Option<String> = None;
+        let mut vendor: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct VendorCredentials, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => secret = Some(d.str()?.to_string()),
+                    1 => vendor = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "secret" => secret = Some(d.str()?.to_string()),
+                    "vendor" => vendor = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        VendorCredentials {
+            secret: if let Some(__x) = secret {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field VendorCredentials.secret (#0)".to_string(),
+                ));
+            },
+
+            vendor: if let Some(__x) = vendor {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field VendorCredentials.vendor (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+/// Discount (percentage) of the whole usage once `from` units are cumulated in the period
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct VolumeDiscount {
+    #[serde(default)]
+    pub from: f64,
+    #[serde(default)]
+    pub percentage: f64,
+}
+
+// Encode VolumeDiscount as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_volume_discount<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &VolumeDiscount,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(2)?;
+    e.str("from")?;
+    e.f64(val.from)?;
+    e.str("percentage")?;
+    e.f64(val.percentage)?;
+    Ok(())
+}
+
+// Decode VolumeDiscount from cbor input stream
+#[doc(hidden)]
+pub fn decode_volume_discount(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<VolumeDiscount, RpcError> {
+    let __result = {
+        let mut from: Option<f64> = None;
+        let mut percentage: Option<f64> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct VolumeDiscount, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => from = Some(d.f64()?),
+                    1 => percentage = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "from" => from = Some(d.f64()?),
+                    "percentage" => percentage = Some(d.f64()?),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        VolumeDiscount {
+            from: if let Some(__x) = from {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field VolumeDiscount.from (#0)".to_string(),
+                ));
+            },
+
+            percentage: if let Some(__x) = percentage {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field VolumeDiscount.percentage (#1)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type VolumeDiscounts = Vec<VolumeDiscount>;
+
+// Encode VolumeDiscounts as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_volume_discounts<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &VolumeDiscounts,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_volume_discount(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode VolumeDiscounts from cbor input stream
+#[doc(hidden)]
+pub fn decode_volume_discounts(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<VolumeDiscounts, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<VolumeDiscount> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_volume_discount(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#VolumeDiscount': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<VolumeDiscount> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_volume_discount(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#VolumeDiscount': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Webhook {
+    /// Event types delivered, all the group events when empty
+    #[serde(rename = "eventTypes")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<EventTypes>,
+    #[serde(default)]
+    pub group: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Key of the HMAC-SHA256 body signature, generated when not provided
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub url: String,
+}
+
+// Encode Webhook as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_webhook<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &Webhook,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(5)?;
+    if let Some(val) = val.event_types.as_ref() {
+        e.str("eventTypes")?;
+        encode_event_types(e, val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("group")?;
+    e.str(&val.group)?;
+    if let Some(val) = val.id.as_ref() {
+        e.str("id")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    if let Some(val) = val.secret.as_ref() {
+        e.str("secret")?;
+        e.str(val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("url")?;
+    e.str(&val.url)?;
+    Ok(())
+}
+
+// Decode Webhook from cbor input stream
+#[doc(hidden)]
+pub fn decode_webhook(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<Webhook, RpcError> {
+    let __result = {
+        let mut event_types: Option<Option<EventTypes>> = Some(None);
+        let mut group: Option<String> = None;
+        let mut id: Option<Option<String>> = Some(None);
+        let mut secret: Option<Option<String>> = Some(None);
+        let mut url: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct Webhook, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => {
+                        event_types = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_event_types(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#EventTypes': {}", e)
+                            })?))
+                        }
+                    }
+                    1 => group = Some(d.str()?.to_string()),
+                    2 => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    3 => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    4 => url = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "eventTypes" => {
+                        event_types = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_event_types(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#EventTypes': {}", e)
+                            })?))
+                        }
+                    }
+                    "group" => group = Some(d.str()?.to_string()),
+                    "id" => {
+                        id = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "secret" => {
+                        secret = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.str()?.to_string()))
+                        }
+                    }
+                    "url" => url = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        Webhook {
+            event_types: event_types.unwrap(),
+
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Webhook.group (#1)".to_string(),
+                ));
+            },
+            id: id.unwrap(),
+            secret: secret.unwrap(),
+
+            url: if let Some(__x) = url {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field Webhook.url (#4)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WebhookDelivery {
+    #[serde(default)]
+    pub attempt: u32,
+    #[serde(default)]
+    pub event: String,
+    #[serde(rename = "eventType")]
+    #[serde(default)]
+    pub event_type: String,
+    #[serde(default)]
+    pub id: String,
+    /// delivered, retrying or failed
+    #[serde(default)]
+    pub status: String,
+    /// HTTP status returned by the webhook, absent when the request failed
+    #[serde(rename = "statusCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    #[serde(default)]
+    pub webhook: String,
+}
+
+// Encode WebhookDelivery as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_webhook_delivery<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &WebhookDelivery,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(7)?;
+    e.str("attempt")?;
+    e.u32(val.attempt)?;
+    e.str("event")?;
+    e.str(&val.event)?;
+    e.str("eventType")?;
+    e.str(&val.event_type)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    e.str("status")?;
+    e.str(&val.status)?;
+    if let Some(val) = val.status_code.as_ref() {
+        e.str("statusCode")?;
+        e.u16(*val)?;
+    } else {
+        e.null()?;
+    }
+    e.str("webhook")?;
+    e.str(&val.webhook)?;
+    Ok(())
+}
+
+// Decode WebhookDelivery from cbor input stream
+#[doc(hidden)]
+pub fn decode_webhook_delivery(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<WebhookDelivery, RpcError> {
+    let __result = {
+        let mut attempt: Option<u32> = None;
+        let mut event: Option<String> = None;
+        let mut event_type: Option<String> = None;
+        let mut id: Option<String> = None;
+        let mut status: Option<String> = None;
+        let mut status_code: Option<Option<u16>> = Some(None);
+        let mut webhook: Option<String> = None;
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct WebhookDelivery, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => attempt = Some(d.u32()?),
+                    1 => event = Some(d.str()?.to_string()),
+                    2 => event_type = Some(d.str()?.to_string()),
+                    3 => id = Some(d.str()?.to_string()),
+                    4 => status = Some(d.str()?.to_string()),
+                    5 => {
+                        status_code = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u16()?))
+                        }
+                    }
+                    6 => webhook = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "attempt" => attempt = Some(d.u32()?),
+                    "event" => event = Some(d.str()?.to_string()),
+                    "eventType" => event_type = Some(d.str()?.to_string()),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "status" => status = Some(d.str()?.to_string()),
+                    "statusCode" => {
+                        status_code = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(d.u16()?))
+                        }
+                    }
+                    "webhook" => webhook = Some(d.str()?.to_string()),
+                    _ => d.skip()?,
+                }
+            }
+        }
+        WebhookDelivery {
+            attempt: if let Some(__x) = attempt {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.attempt (#0)".to_string(),
+                ));
+            },
+
+            event: if let Some(__x) = event {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.event (#1)".to_string(),
+                ));
+            },
+
+            event_type: if let Some(__x) = event_type {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.event_type (#2)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.id (#3)".to_string(),
+                ));
+            },
+
+            status: if let Some(__x) = status {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.status (#4)".to_string(),
+                ));
+            },
+            status_code: status_code.unwrap(),
+
+            webhook: if let Some(__x) = webhook {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookDelivery.webhook (#6)".to_string(),
+                ));
+            },
+        }
+    };
+    Ok(__result)
+}
+pub type WebhookDeliveryList = Vec<WebhookDelivery>;
+
+// Encode WebhookDeliveryList as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_webhook_delivery_list<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &WebhookDeliveryList,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_webhook_delivery(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode WebhookDeliveryList from cbor input stream
+#[doc(hidden)]
+pub fn decode_webhook_delivery_list(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<WebhookDeliveryList, RpcError> {
+    let __result = {
+        if let Some(n) = d.array()? {
+            let mut arr: Vec<WebhookDelivery> = Vec::with_capacity(n as usize);
+            for _ in 0..(n as usize) {
+                arr.push(decode_webhook_delivery(d).map_err(|e| {
+                    format!("decoding 'com.orange.sharedbucket#WebhookDelivery': {}", e)
+                })?)
+            }
+            arr
+        } else {
+            // indefinite array
+            let mut arr: Vec<WebhookDelivery> = Vec::new();
+            loop {
+                match d.datatype() {
+                    Err(_) => break,
+                    Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                    Ok(_) => arr.push(decode_webhook_delivery(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#WebhookDelivery': {}", e)
+                    })?),
+                }
+            }
+            arr
+        }
+    };
+    Ok(__result)
+}
+pub type WebhookList = Vec<Webhook>;
+
+// Encode WebhookList as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_webhook_list<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &WebhookList,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.array(val.len() as u64)?;
+    for item in val.iter() {
+        encode_webhook(e, item)?;
+    }
+    Ok(())
+}
+
+// Decode WebhookList from cbor input stream
+#[doc(hidden)]
+pub fn decode_webhook_list(
+    d: &mut wasmbus_rpc::cbor::Decoder<'_>,
+) -> Result<WebhookList, RpcError> {
+    let __result =
+        {
+            if let Some(n) = d.array()? {
+                let mut arr: Vec<Webhook> = Vec::with_capacity(n as usize);
+                for _ in 0..(n as usize) {
+                    arr.push(decode_webhook(d).map_err(|e| {
+                        format!("decoding 'com.orange.sharedbucket#Webhook': {}", e)
+                    })?)
+                }
+                arr
+            } else {
+                // indefinite array
+                let mut arr: Vec<Webhook> = Vec::new();
+                loop {
+                    match d.datatype() {
+                        Err(_) => break,
+                        Ok(wasmbus_rpc::cbor::Type::Break) => break,
+                        Ok(_) => arr.push(decode_webhook(d).map_err(|e| {
+                            format!("decoding 'com.orange.sharedbucket#Webhook': {}", e)
+                        })?),
+                    }
+                }
+                arr
+            }
+        };
+    Ok(__result)
+}
+/// Webhook of a group
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WebhookRef {
+    #[serde(default)]
+    pub group: String,
+    #[serde(default)]
+    pub id: String,
+    /// The group is one of the tenant of the principal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+}
+
+// Encode WebhookRef as CBOR and append to output stream
+#[doc(hidden)]
+#[allow(unused_mut)]
+pub fn encode_webhook_ref<W: wasmbus_rpc::cbor::Write>(
+    mut e: &mut wasmbus_rpc::cbor::Encoder<W>,
+    val: &WebhookRef,
+) -> RpcResult<()>
+where
+    <W as wasmbus_rpc::cbor::Write>::Error: std::fmt::Display,
+{
+    e.map(3)?;
+    e.str("group")?;
+    e.str(&val.group)?;
+    e.str("id")?;
+    e.str(&val.id)?;
+    if let Some(val) = val.principal.as_ref() {
+        e.str("principal")?;
+        encode_principal(e, val)?;
+    } else {
+        e.null()?;
+    }
+    Ok(())
+}
+
+// Decode WebhookRef from cbor input stream
+#[doc(hidden)]
+pub fn decode_webhook_ref(d: &mut wasmbus_rpc::cbor::Decoder<'_>) -> Result<WebhookRef, RpcError> {
+    let __result = {
+        let mut group: Option<String> = None;
+        let mut id: Option<String> = None;
+        let mut principal: Option<Option<Principal>> = Some(None);
+
+        let is_array = match d.datatype()? {
+            wasmbus_rpc::cbor::Type::Array => true,
+            wasmbus_rpc::cbor::Type::Map => false,
+            _ => {
+                return Err(RpcError::Deser(
+                    "decoding struct WebhookRef, expected array or map".to_string(),
+                ))
+            }
+        };
+        if is_array {
+            let len = d.fixed_array()?;
+            for __i in 0..(len as usize) {
+                match __i {
+                    0 => group = Some(d.str()?.to_string()),
+                    1 => id = Some(d.str()?.to_string()),
+                    2 => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+
+                    _ => d.skip()?,
+                }
+            }
+        } else {
+            let len = d.fixed_map()?;
+            for __i in 0..(len as usize) {
+                match d.str()? {
+                    "group" => group = Some(d.str()?.to_string()),
+                    "id" => id = Some(d.str()?.to_string()),
+                    "principal" => {
+                        principal = if wasmbus_rpc::cbor::Type::Null == d.datatype()? {
+                            d.skip()?;
+                            Some(None)
+                        } else {
+                            Some(Some(decode_principal(d).map_err(|e| {
+                                format!("decoding 'com.orange.sharedbucket#Principal': {}", e)
+                            })?))
+                        }
+                    }
+                    _ => d.skip()?,
+                }
+            }
+        }
+        WebhookRef {
+            group: if let Some(__x) = group {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookRef.group (#0)".to_string(),
+                ));
+            },
+
+            id: if let Some(__x) = id {
+                __x
+            } else {
+                return Err(RpcError::Deser(
+                    "missing field WebhookRef.id (#1)".to_string(),
+                ));
+            },
+            principal: principal.unwrap(),
+        }
+    };
+    Ok(__result)
+}
+/// wasmbus.actorReceive
+#[async_trait]
+pub trait CustomerGroups {
+    async fn create_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &CreateCustomerGroupRequest,
+    ) -> RpcResult<CreateCustomerGroupReply>;
+    /// Group readable by the principal (members, admins and operators)
+    async fn find_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &FindCustomerGroupRequest,
+    ) -> RpcResult<FindCustomerGroupReply>;
+    /// Changes the display name of a group, for its admins and the operators
+    async fn rename_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &RenameCustomerGroupRequest,
+    ) -> RpcResult<RenameCustomerGroupReply>;
+    async fn add_customer(
+        &self,
+        ctx: &Context,
+        arg: &AddCustomerRequest,
+    ) -> RpcResult<AddCustomerReply>;
+    async fn remove_customer(
+        &self,
+        ctx: &Context,
+        arg: &RemoveCustomerRequest,
+    ) -> RpcResult<RemoveCustomerReply>;
+    /// Members of a group by increasing id, a page at a time
+    async fn list_customers(
+        &self,
+        ctx: &Context,
+        arg: &ListCustomersRequest,
+    ) -> RpcResult<ListCustomersReply>;
+}
+
+/// CustomerGroupsReceiver receives messages defined in the CustomerGroups service trait
+#[doc(hidden)]
+#[async_trait]
+pub trait CustomerGroupsReceiver: MessageDispatch + CustomerGroups {
+    async fn dispatch<'disp__, 'ctx__, 'msg__>(
+        &'disp__ self,
+        ctx: &'ctx__ Context,
+        message: &Message<'msg__>,
+    ) -> Result<Message<'msg__>, RpcError> {
+        match message.method {
+            "CreateCustomerGroup" => {
+                let value: CreateCustomerGroupRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg).map_err(|e| {
+                        RpcError::Deser(format!("'CreateCustomerGroupRequest': {}", e))
+                    })?;
+
+                let resp = CustomerGroups::create_customer_group(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.CreateCustomerGroup",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "FindCustomerGroup" => {
+                let value: FindCustomerGroupRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg).map_err(|e| {
+                        RpcError::Deser(format!("'FindCustomerGroupRequest': {}", e))
+                    })?;
+
+                let resp = CustomerGroups::find_customer_group(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.FindCustomerGroup",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RenameCustomerGroup" => {
+                let value: RenameCustomerGroupRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg).map_err(|e| {
+                        RpcError::Deser(format!("'RenameCustomerGroupRequest': {}", e))
+                    })?;
+
+                let resp = CustomerGroups::rename_customer_group(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.RenameCustomerGroup",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "AddCustomer" => {
+                let value: AddCustomerRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'AddCustomerRequest': {}", e)))?;
+
+                let resp = CustomerGroups::add_customer(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.AddCustomer",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RemoveCustomer" => {
+                let value: RemoveCustomerRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'RemoveCustomerRequest': {}", e)))?;
+
+                let resp = CustomerGroups::remove_customer(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.RemoveCustomer",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ListCustomers" => {
+                let value: ListCustomersRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ListCustomersRequest': {}", e)))?;
+
+                let resp = CustomerGroups::list_customers(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "CustomerGroups.ListCustomers",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            _ => Err(RpcError::MethodNotHandled(format!(
+                "CustomerGroups::{}",
+                message.method
+            ))),
+        }
+    }
+}
+
+/// CustomerGroupsSender sends messages to a CustomerGroups service
+/// client for sending CustomerGroups messages
+#[derive(Debug)]
+pub struct CustomerGroupsSender<T: Transport> {
+    transport: T,
+}
+
+impl<T: Transport> CustomerGroupsSender<T> {
+    /// Constructs a CustomerGroupsSender with the specified transport
+    pub fn via(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn set_timeout(&self, interval: std::time::Duration) {
+        self.transport.set_timeout(interval);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'send> CustomerGroupsSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
+    /// Constructs a Sender using an actor's LinkDefinition,
+    /// Uses the provider's HostBridge for rpc
+    pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
+        Self {
+            transport: wasmbus_rpc::provider::ProviderTransport::new(ld, None),
+        }
+    }
+}
+#[cfg(target_arch = "wasm32")]
+impl CustomerGroupsSender<wasmbus_rpc::actor::prelude::WasmHost> {
+    /// Constructs a client for actor-to-actor messaging
+    /// using the recipient actor's public key
+    pub fn to_actor(actor_id: &str) -> Self {
+        let transport =
+            wasmbus_rpc::actor::prelude::WasmHost::to_actor(actor_id.to_string()).unwrap();
+        Self { transport }
+    }
+}
+#[async_trait]
+impl<T: Transport + std::marker::Sync + std::marker::Send> CustomerGroups
+    for CustomerGroupsSender<T>
+{
+    #[allow(unused)]
+    async fn create_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &CreateCustomerGroupRequest,
+    ) -> RpcResult<CreateCustomerGroupReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.CreateCustomerGroup",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CreateCustomerGroupReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CreateCustomerGroupReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Group readable by the principal (members, admins and operators)
+    async fn find_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &FindCustomerGroupRequest,
+    ) -> RpcResult<FindCustomerGroupReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.FindCustomerGroup",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: FindCustomerGroupReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': FindCustomerGroupReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Changes the display name of a group, for its admins and the operators
+    async fn rename_customer_group(
+        &self,
+        ctx: &Context,
+        arg: &RenameCustomerGroupRequest,
+    ) -> RpcResult<RenameCustomerGroupReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.RenameCustomerGroup",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: RenameCustomerGroupReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RenameCustomerGroupReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn add_customer(
+        &self,
+        ctx: &Context,
+        arg: &AddCustomerRequest,
+    ) -> RpcResult<AddCustomerReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.AddCustomer",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: AddCustomerReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': AddCustomerReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn remove_customer(
+        &self,
+        ctx: &Context,
+        arg: &RemoveCustomerRequest,
+    ) -> RpcResult<RemoveCustomerReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.RemoveCustomer",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: RemoveCustomerReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RemoveCustomerReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Members of a group by increasing id, a page at a time
+    async fn list_customers(
+        &self,
+        ctx: &Context,
+        arg: &ListCustomersRequest,
+    ) -> RpcResult<ListCustomersReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "CustomerGroups.ListCustomers",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: ListCustomersReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': ListCustomersReply", e)))?;
+        Ok(value)
+    }
+}
+
+/// Description of SharedBucket service
+/// wasmbus.actorReceive
+#[async_trait]
+pub trait Customers {
+    async fn create_customer(
+        &self,
+        ctx: &Context,
+        arg: &CreateCustomerRequest,
+    ) -> RpcResult<CreateCustomerReply>;
+    async fn find_customer(
+        &self,
+        ctx: &Context,
+        arg: &FindCustomerRequest,
+    ) -> RpcResult<FindCustomerReply>;
+    /// Creates customers and their group memberships in bulk, with a result for each row
+    async fn import_customers(
+        &self,
+        ctx: &Context,
+        arg: &ImportCustomersRequest,
+    ) -> RpcResult<ImportCustomersReply>;
+    async fn healthz(&self, ctx: &Context, arg: &HealthzRequest) -> RpcResult<HealthzReply>;
+    /// Upgrades the stored customers and groups to the current version of their schema
+    async fn migrate_records(
+        &self,
+        ctx: &Context,
+        arg: &MigrateRecordsRequest,
+    ) -> RpcResult<MigrateRecordsReply>;
+    /// Customers, groups and memberships of the tenant, a page at a time
+    async fn export_records(
+        &self,
+        ctx: &Context,
+        arg: &ExportRecordsRequest,
+    ) -> RpcResult<ExportRecordsReply>;
+    /// Writes the customers, groups and memberships of an export
+    async fn restore_records(
+        &self,
+        ctx: &Context,
+        arg: &RestoreRecordsRequest,
+    ) -> RpcResult<RestoreRecordsReply>;
+}
+
+/// CustomersReceiver receives messages defined in the Customers service trait
+/// Description of SharedBucket service
+#[doc(hidden)]
+#[async_trait]
+pub trait CustomersReceiver: MessageDispatch + Customers {
+    async fn dispatch<'disp__, 'ctx__, 'msg__>(
+        &'disp__ self,
+        ctx: &'ctx__ Context,
+        message: &Message<'msg__>,
+    ) -> Result<Message<'msg__>, RpcError> {
+        match message.method {
+            "CreateCustomer" => {
+                let value: CreateCustomerRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'CreateCustomerRequest': {}", e)))?;
+
+                let resp = Customers::create_customer(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.CreateCustomer",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "FindCustomer" => {
+                let value: FindCustomerRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'FindCustomerRequest': {}", e)))?;
+
+                let resp = Customers::find_customer(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.FindCustomer",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ImportCustomers" => {
+                let value: ImportCustomersRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ImportCustomersRequest': {}", e)))?;
+
+                let resp = Customers::import_customers(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.ImportCustomers",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "Healthz" => {
+                let value: HealthzRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'HealthzRequest': {}", e)))?;
+
+                let resp = Customers::healthz(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.Healthz",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "MigrateRecords" => {
+                let value: MigrateRecordsRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'MigrateRecordsRequest': {}", e)))?;
+
+                let resp = Customers::migrate_records(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.MigrateRecords",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ExportRecords" => {
+                let value: ExportRecordsRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ExportRecordsRequest': {}", e)))?;
+
+                let resp = Customers::export_records(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.ExportRecords",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RestoreRecords" => {
+                let value: RestoreRecordsRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'RestoreRecordsRequest': {}", e)))?;
+
+                let resp = Customers::restore_records(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Customers.RestoreRecords",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            _ => Err(RpcError::MethodNotHandled(format!(
+                "Customers::{}",
+                message.method
+            ))),
+        }
+    }
+}
+
+/// CustomersSender sends messages to a Customers service
+/// Description of SharedBucket service
+/// client for sending Customers messages
+#[derive(Debug)]
+pub struct CustomersSender<T: Transport> {
+    transport: T,
+}
+
+impl<T: Transport> CustomersSender<T> {
+    /// Constructs a CustomersSender with the specified transport
+    pub fn via(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn set_timeout(&self, interval: std::time::Duration) {
+        self.transport.set_timeout(interval);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'send> CustomersSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
+    /// Constructs a Sender using an actor's LinkDefinition,
+    /// Uses the provider's HostBridge for rpc
+    pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
+        Self {
+            transport: wasmbus_rpc::provider::ProviderTransport::new(ld, None),
+        }
+    }
+}
+#[cfg(target_arch = "wasm32")]
+impl CustomersSender<wasmbus_rpc::actor::prelude::WasmHost> {
+    /// Constructs a client for actor-to-actor messaging
+    /// using the recipient actor's public key
+    pub fn to_actor(actor_id: &str) -> Self {
+        let transport =
+            wasmbus_rpc::actor::prelude::WasmHost::to_actor(actor_id.to_string()).unwrap();
+        Self { transport }
+    }
+}
+#[async_trait]
+impl<T: Transport + std::marker::Sync + std::marker::Send> Customers for CustomersSender<T> {
+    #[allow(unused)]
+    async fn create_customer(
+        &self,
+        ctx: &Context,
+        arg: &CreateCustomerRequest,
+    ) -> RpcResult<CreateCustomerReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.CreateCustomer",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CreateCustomerReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CreateCustomerReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn find_customer(
+        &self,
+        ctx: &Context,
+        arg: &FindCustomerRequest,
+    ) -> RpcResult<FindCustomerReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.FindCustomer",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: FindCustomerReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': FindCustomerReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Creates customers and their group memberships in bulk, with a result for each row
+    async fn import_customers(
+        &self,
+        ctx: &Context,
+        arg: &ImportCustomersRequest,
+    ) -> RpcResult<ImportCustomersReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.ImportCustomers",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: ImportCustomersReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': ImportCustomersReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn healthz(&self, ctx: &Context, arg: &HealthzRequest) -> RpcResult<HealthzReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.Healthz",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: HealthzReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': HealthzReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Upgrades the stored customers and groups to the current version of their schema
+    async fn migrate_records(
+        &self,
+        ctx: &Context,
+        arg: &MigrateRecordsRequest,
+    ) -> RpcResult<MigrateRecordsReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.MigrateRecords",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: MigrateRecordsReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': MigrateRecordsReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Customers, groups and memberships of the tenant, a page at a time
+    async fn export_records(
+        &self,
+        ctx: &Context,
+        arg: &ExportRecordsRequest,
+    ) -> RpcResult<ExportRecordsReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.ExportRecords",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: ExportRecordsReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': ExportRecordsReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Writes the customers, groups and memberships of an export
+    async fn restore_records(
+        &self,
+        ctx: &Context,
+        arg: &RestoreRecordsRequest,
+    ) -> RpcResult<RestoreRecordsReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Customers.RestoreRecords",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: RestoreRecordsReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RestoreRecordsReply", e)))?;
+        Ok(value)
+    }
+}
+
+/// Monthly invoices of the customer groups, built from the bucket ledger
+/// wasmbus.actorReceive
+#[async_trait]
+pub trait Invoices {
+    /// Builds (or rebuilds) the invoice of a group for a period, an invoice keeps its number
+    async fn generate_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<Invoice>;
+    async fn get_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<GetInvoiceReply>;
+}
+
+/// InvoicesReceiver receives messages defined in the Invoices service trait
+/// Monthly invoices of the customer groups, built from the bucket ledger
+#[doc(hidden)]
+#[async_trait]
+pub trait InvoicesReceiver: MessageDispatch + Invoices {
+    async fn dispatch<'disp__, 'ctx__, 'msg__>(
+        &'disp__ self,
+        ctx: &'ctx__ Context,
+        message: &Message<'msg__>,
+    ) -> Result<Message<'msg__>, RpcError> {
+        match message.method {
+            "GenerateInvoice" => {
+                let value: InvoiceRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'InvoiceRequest': {}", e)))?;
+
+                let resp = Invoices::generate_invoice(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Invoices.GenerateInvoice",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "GetInvoice" => {
+                let value: InvoiceRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'InvoiceRequest': {}", e)))?;
+
+                let resp = Invoices::get_invoice(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Invoices.GetInvoice",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            _ => Err(RpcError::MethodNotHandled(format!(
+                "Invoices::{}",
+                message.method
+            ))),
+        }
+    }
+}
+
+/// InvoicesSender sends messages to a Invoices service
+/// Monthly invoices of the customer groups, built from the bucket ledger
+/// client for sending Invoices messages
+#[derive(Debug)]
+pub struct InvoicesSender<T: Transport> {
+    transport: T,
+}
+
+impl<T: Transport> InvoicesSender<T> {
+    /// Constructs a InvoicesSender with the specified transport
+    pub fn via(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn set_timeout(&self, interval: std::time::Duration) {
+        self.transport.set_timeout(interval);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'send> InvoicesSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
+    /// Constructs a Sender using an actor's LinkDefinition,
+    /// Uses the provider's HostBridge for rpc
+    pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
+        Self {
+            transport: wasmbus_rpc::provider::ProviderTransport::new(ld, None),
+        }
+    }
+}
+#[cfg(target_arch = "wasm32")]
+impl InvoicesSender<wasmbus_rpc::actor::prelude::WasmHost> {
+    /// Constructs a client for actor-to-actor messaging
+    /// using the recipient actor's public key
+    pub fn to_actor(actor_id: &str) -> Self {
+        let transport =
+            wasmbus_rpc::actor::prelude::WasmHost::to_actor(actor_id.to_string()).unwrap();
+        Self { transport }
+    }
+}
+#[async_trait]
+impl<T: Transport + std::marker::Sync + std::marker::Send> Invoices for InvoicesSender<T> {
+    #[allow(unused)]
+    /// Builds (or rebuilds) the invoice of a group for a period, an invoice keeps its number
+    async fn generate_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<Invoice> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Invoices.GenerateInvoice",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: Invoice = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': Invoice", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn get_invoice(&self, ctx: &Context, arg: &InvoiceRequest) -> RpcResult<GetInvoiceReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Invoices.GetInvoice",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: GetInvoiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': GetInvoiceReply", e)))?;
+        Ok(value)
+    }
+}
+
+/// Description of SharedBucket service
+/// wasmbus.actorReceive
+#[async_trait]
+pub trait ServiceVendors {
+    /// Registers a vendor with the services it owns, the reply holds the vendor credentials
+    async fn register_vendor(&self, ctx: &Context, arg: &Vendor) -> RpcResult<RegisterVendorReply>;
+    /// Adds a service to the catalog, the vendor must own the service id or it must be unowned
+    async fn register_service(
+        &self,
+        ctx: &Context,
+        arg: &CatalogServiceRequest,
+    ) -> RpcResult<CatalogServiceReply>;
+    /// Replaces a catalog service of the vendor
+    async fn update_service(
+        &self,
+        ctx: &Context,
+        arg: &CatalogServiceRequest,
+    ) -> RpcResult<CatalogServiceReply>;
+    /// Removes a catalog service of the vendor
+    async fn delete_service(
+        &self,
+        ctx: &Context,
+        arg: &ServiceRef,
+    ) -> RpcResult<CatalogServiceReply>;
+    async fn get_service<TS: ToString + ?Sized + std::marker::Sync>(
+        &self,
+        ctx: &Context,
+        arg: &TS,
+    ) -> RpcResult<GetServiceReply>;
+    async fn list_services(&self, ctx: &Context) -> RpcResult<CatalogServices>;
+    /// Debits the group bucket for the units consumed by a service
+    async fn authorize_service_usage(
+        &self,
+        ctx: &Context,
+        arg: &ServiceUsage,
+    ) -> RpcResult<AuthorizeServiceUsageReply>;
+    /// Credits the group bucket with the units bought
+    async fn buy_service(&self, ctx: &Context, arg: &ServiceOrder) -> RpcResult<BuyServiceReply>;
+    /// Debits the group bucket for units of an order given back, in full or in part
+    async fn refund_order(&self, ctx: &Context, arg: &RefundRequest) -> RpcResult<RefundReply>;
+    /// Moves units from a group bucket to another one : both buckets and ledgers are updated or none
+    async fn transfer_units(
+        &self,
+        ctx: &Context,
+        arg: &TransferRequest,
+    ) -> RpcResult<TransferReply>;
+    /// Transfers from and to a group bucket, most recent last
+    async fn list_transfers(&self, ctx: &Context, arg: &BucketRef) -> RpcResult<Transfers>;
+    /// Replaces the alert thresholds of a group bucket
+    async fn set_bucket_thresholds(
+        &self,
+        ctx: &Context,
+        arg: &SetBucketThresholdsRequest,
+    ) -> RpcResult<SetBucketThresholdsReply>;
+    async fn get_bucket_thresholds(
+        &self,
+        ctx: &Context,
+        arg: &BucketRef,
+    ) -> RpcResult<BucketThresholds>;
+    /// Bucket of a group followed by its ledger entries, by period, a page at a time for the exports
+    /// The ledgers written before their periods were kept are looked for once, from `config:ledger_start`
+    /// (2020-01 by default) to `config:ledger_end` (the latest period kept by default)
+    async fn export_bucket(
+        &self,
+        ctx: &Context,
+        arg: &ExportBucketRequest,
+    ) -> RpcResult<ExportBucketReply>;
+    /// Writes the buckets and ledger entries of an export, the reply is the number of records written
+    async fn restore_buckets(&self, ctx: &Context, arg: &RestoreBucketsRequest) -> RpcResult<u32>;
+    /// Keeps the periods of the ledgers of groups written before their periods were kept, so that
+    /// they are exported. They are looked for from `config:ledger_start` to `config:ledger_end` (the
+    /// latest period kept when not set).
+    async fn migrate_ledgers(
+        &self,
+        ctx: &Context,
+        arg: &MigrateLedgersRequest,
+    ) -> RpcResult<MigrateRecordsReply>;
+}
+
+/// ServiceVendorsReceiver receives messages defined in the ServiceVendors service trait
+/// Description of SharedBucket service
+#[doc(hidden)]
+#[async_trait]
+pub trait ServiceVendorsReceiver: MessageDispatch + ServiceVendors {
+    async fn dispatch<'disp__, 'ctx__, 'msg__>(
+        &'disp__ self,
+        ctx: &'ctx__ Context,
+        message: &Message<'msg__>,
+    ) -> Result<Message<'msg__>, RpcError> {
+        match message.method {
+            "RegisterVendor" => {
+                let value: Vendor = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'Vendor': {}", e)))?;
+
+                let resp = ServiceVendors::register_vendor(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.RegisterVendor",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RegisterService" => {
+                let value: CatalogServiceRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'CatalogServiceRequest': {}", e)))?;
+
+                let resp = ServiceVendors::register_service(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.RegisterService",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "UpdateService" => {
+                let value: CatalogServiceRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'CatalogServiceRequest': {}", e)))?;
+
+                let resp = ServiceVendors::update_service(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.UpdateService",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "DeleteService" => {
+                let value: ServiceRef = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ServiceRef': {}", e)))?;
+
+                let resp = ServiceVendors::delete_service(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.DeleteService",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "GetService" => {
+                let value: String = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'String': {}", e)))?;
+
+                let resp = ServiceVendors::get_service(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.GetService",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ListServices" => {
+                let resp = ServiceVendors::list_services(self, ctx).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.ListServices",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "AuthorizeServiceUsage" => {
+                let value: ServiceUsage = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ServiceUsage': {}", e)))?;
+
+                let resp = ServiceVendors::authorize_service_usage(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.AuthorizeServiceUsage",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "BuyService" => {
+                let value: ServiceOrder = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ServiceOrder': {}", e)))?;
+
+                let resp = ServiceVendors::buy_service(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.BuyService",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RefundOrder" => {
+                let value: RefundRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'RefundRequest': {}", e)))?;
+
+                let resp = ServiceVendors::refund_order(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.RefundOrder",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "TransferUnits" => {
+                let value: TransferRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'TransferRequest': {}", e)))?;
+
+                let resp = ServiceVendors::transfer_units(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.TransferUnits",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ListTransfers" => {
+                let value: BucketRef = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'BucketRef': {}", e)))?;
+
+                let resp = ServiceVendors::list_transfers(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.ListTransfers",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "SetBucketThresholds" => {
+                let value: SetBucketThresholdsRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg).map_err(|e| {
+                        RpcError::Deser(format!("'SetBucketThresholdsRequest': {}", e))
+                    })?;
+
+                let resp = ServiceVendors::set_bucket_thresholds(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.SetBucketThresholds",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "GetBucketThresholds" => {
+                let value: BucketRef = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'BucketRef': {}", e)))?;
+
+                let resp = ServiceVendors::get_bucket_thresholds(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.GetBucketThresholds",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ExportBucket" => {
+                let value: ExportBucketRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'ExportBucketRequest': {}", e)))?;
+
+                let resp = ServiceVendors::export_bucket(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.ExportBucket",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "RestoreBuckets" => {
+                let value: RestoreBucketsRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'RestoreBucketsRequest': {}", e)))?;
+
+                let resp = ServiceVendors::restore_buckets(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.RestoreBuckets",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "MigrateLedgers" => {
+                let value: MigrateLedgersRequest =
+                    wasmbus_rpc::common::deserialize(&message.arg)
+                        .map_err(|e| RpcError::Deser(format!("'MigrateLedgersRequest': {}", e)))?;
+
+                let resp = ServiceVendors::migrate_ledgers(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "ServiceVendors.MigrateLedgers",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            _ => Err(RpcError::MethodNotHandled(format!(
+                "ServiceVendors::{}",
+                message.method
+            ))),
+        }
+    }
+}
+
+/// ServiceVendorsSender sends messages to a ServiceVendors service
+/// Description of SharedBucket service
+/// client for sending ServiceVendors messages
+#[derive(Debug)]
+pub struct ServiceVendorsSender<T: Transport> {
+    transport: T,
+}
+
+impl<T: Transport> ServiceVendorsSender<T> {
+    /// Constructs a ServiceVendorsSender with the specified transport
+    pub fn via(transport: T) -> Self {
+        Self { transport }
+    }
 
     pub fn set_timeout(&self, interval: std::time::Duration) {
         self.transport.set_timeout(interval);
@@ -1273,7 +12335,7 @@ impl<T: Transport> CustomerGroupsSender<T> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-impl<'send> CustomerGroupsSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
+impl<'send> ServiceVendorsSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
     /// Constructs a Sender using an actor's LinkDefinition,
     /// Uses the provider's HostBridge for rpc
     pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
@@ -1283,7 +12345,7 @@ impl<'send> CustomerGroupsSender<wasmbus_rpc::provider::ProviderTransport<'send>
     }
 }
 #[cfg(target_arch = "wasm32")]
-impl CustomerGroupsSender<wasmbus_rpc::actor::prelude::WasmHost> {
+impl ServiceVendorsSender<wasmbus_rpc::actor::prelude::WasmHost> {
     /// Constructs a client for actor-to-actor messaging
     /// using the recipient actor's public key
     pub fn to_actor(actor_id: &str) -> Self {
@@ -1291,17 +12353,224 @@ impl CustomerGroupsSender<wasmbus_rpc::actor::prelude::WasmHost> {
             wasmbus_rpc::actor::prelude::WasmHost::to_actor(actor_id.to_string()).unwrap();
         Self { transport }
     }
-}
-#[async_trait]
-impl<T: Transport + std::marker::Sync + std::marker::Send> CustomerGroups
-    for CustomerGroupsSender<T>
-{
+}
+#[async_trait]
+impl<T: Transport + std::marker::Sync + std::marker::Send> ServiceVendors
+    for ServiceVendorsSender<T>
+{
+    #[allow(unused)]
+    /// Registers a vendor with the services it owns, the reply holds the vendor credentials
+    async fn register_vendor(&self, ctx: &Context, arg: &Vendor) -> RpcResult<RegisterVendorReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.RegisterVendor",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: RegisterVendorReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RegisterVendorReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Adds a service to the catalog, the vendor must own the service id or it must be unowned
+    async fn register_service(
+        &self,
+        ctx: &Context,
+        arg: &CatalogServiceRequest,
+    ) -> RpcResult<CatalogServiceReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.RegisterService",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CatalogServiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CatalogServiceReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Replaces a catalog service of the vendor
+    async fn update_service(
+        &self,
+        ctx: &Context,
+        arg: &CatalogServiceRequest,
+    ) -> RpcResult<CatalogServiceReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.UpdateService",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CatalogServiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CatalogServiceReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Removes a catalog service of the vendor
+    async fn delete_service(
+        &self,
+        ctx: &Context,
+        arg: &ServiceRef,
+    ) -> RpcResult<CatalogServiceReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.DeleteService",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CatalogServiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CatalogServiceReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn get_service<TS: ToString + ?Sized + std::marker::Sync>(
+        &self,
+        ctx: &Context,
+        arg: &TS,
+    ) -> RpcResult<GetServiceReply> {
+        let buf = wasmbus_rpc::common::serialize(&arg.to_string())?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.GetService",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: GetServiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': GetServiceReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn list_services(&self, ctx: &Context) -> RpcResult<CatalogServices> {
+        let buf = *b"";
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.ListServices",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: CatalogServices = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': CatalogServices", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Debits the group bucket for the units consumed by a service
+    async fn authorize_service_usage(
+        &self,
+        ctx: &Context,
+        arg: &ServiceUsage,
+    ) -> RpcResult<AuthorizeServiceUsageReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.AuthorizeServiceUsage",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: AuthorizeServiceUsageReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': AuthorizeServiceUsageReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Credits the group bucket with the units bought
+    async fn buy_service(&self, ctx: &Context, arg: &ServiceOrder) -> RpcResult<BuyServiceReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.BuyService",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: BuyServiceReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': BuyServiceReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Debits the group bucket for units of an order given back, in full or in part
+    async fn refund_order(&self, ctx: &Context, arg: &RefundRequest) -> RpcResult<RefundReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.RefundOrder",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: RefundReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RefundReply", e)))?;
+        Ok(value)
+    }
     #[allow(unused)]
-    async fn create_customer_group(
+    /// Moves units from a group bucket to another one : both buckets and ledgers are updated or none
+    async fn transfer_units(
         &self,
         ctx: &Context,
-        arg: &CustomerGroup,
-    ) -> RpcResult<CreateCustomerGroupReply> {
+        arg: &TransferRequest,
+    ) -> RpcResult<TransferReply> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1309,23 +12578,20 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> CustomerGroups
             .send(
                 ctx,
                 Message {
-                    method: "CustomerGroups.CreateCustomerGroup",
+                    method: "ServiceVendors.TransferUnits",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: CreateCustomerGroupReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': CreateCustomerGroupReply", e)))?;
+        let value: TransferReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': TransferReply", e)))?;
         Ok(value)
     }
     #[allow(unused)]
-    async fn add_customer(
-        &self,
-        ctx: &Context,
-        arg: &AddCustomerRequest,
-    ) -> RpcResult<AddCustomerReply> {
+    /// Transfers from and to a group bucket, most recent last
+    async fn list_transfers(&self, ctx: &Context, arg: &BucketRef) -> RpcResult<Transfers> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1333,162 +12599,75 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> CustomerGroups
             .send(
                 ctx,
                 Message {
-                    method: "CustomerGroups.AddCustomer",
+                    method: "ServiceVendors.ListTransfers",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: AddCustomerReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': AddCustomerReply", e)))?;
+        let value: Transfers = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': Transfers", e)))?;
         Ok(value)
     }
     #[allow(unused)]
-    async fn list_customers<TS: ToString + ?Sized + std::marker::Sync>(
+    /// Replaces the alert thresholds of a group bucket
+    async fn set_bucket_thresholds(
         &self,
         ctx: &Context,
-        arg: &TS,
-    ) -> RpcResult<ListCustomersReply> {
-        let buf = wasmbus_rpc::common::serialize(&arg.to_string())?;
+        arg: &SetBucketThresholdsRequest,
+    ) -> RpcResult<SetBucketThresholdsReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
             .transport
             .send(
                 ctx,
                 Message {
-                    method: "CustomerGroups.ListCustomers",
+                    method: "ServiceVendors.SetBucketThresholds",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: ListCustomersReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': ListCustomersReply", e)))?;
+        let value: SetBucketThresholdsReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': SetBucketThresholdsReply", e)))?;
         Ok(value)
     }
-}
-
-/// Description of SharedBucket service
-/// wasmbus.actorReceive
-#[async_trait]
-pub trait Customers {
-    async fn create_customer(
-        &self,
-        ctx: &Context,
-        arg: &Customer,
-    ) -> RpcResult<CreateCustomerReply>;
-    async fn find_customer<TS: ToString + ?Sized + std::marker::Sync>(
+    #[allow(unused)]
+    async fn get_bucket_thresholds(
         &self,
         ctx: &Context,
-        arg: &TS,
-    ) -> RpcResult<FindCustomerReply>;
-    async fn healthz(&self, ctx: &Context, arg: &HealthzRequest) -> RpcResult<HealthzReply>;
-}
-
-/// CustomersReceiver receives messages defined in the Customers service trait
-/// Description of SharedBucket service
-#[doc(hidden)]
-#[async_trait]
-pub trait CustomersReceiver: MessageDispatch + Customers {
-    async fn dispatch<'disp__, 'ctx__, 'msg__>(
-        &'disp__ self,
-        ctx: &'ctx__ Context,
-        message: &Message<'msg__>,
-    ) -> Result<Message<'msg__>, RpcError> {
-        match message.method {
-            "CreateCustomer" => {
-                let value: Customer = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'Customer': {}", e)))?;
-
-                let resp = Customers::create_customer(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
-
-                Ok(Message {
-                    method: "Customers.CreateCustomer",
-                    arg: Cow::Owned(buf),
-                })
-            }
-            "FindCustomer" => {
-                let value: String = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'String': {}", e)))?;
-
-                let resp = Customers::find_customer(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
-
-                Ok(Message {
-                    method: "Customers.FindCustomer",
-                    arg: Cow::Owned(buf),
-                })
-            }
-            "Healthz" => {
-                let value: HealthzRequest = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'HealthzRequest': {}", e)))?;
-
-                let resp = Customers::healthz(self, ctx, &value).await?;
-                let buf = wasmbus_rpc::common::serialize(&resp)?;
-
-                Ok(Message {
-                    method: "Customers.Healthz",
-                    arg: Cow::Owned(buf),
-                })
-            }
-            _ => Err(RpcError::MethodNotHandled(format!(
-                "Customers::{}",
-                message.method
-            ))),
-        }
-    }
-}
-
-/// CustomersSender sends messages to a Customers service
-/// Description of SharedBucket service
-/// client for sending Customers messages
-#[derive(Debug)]
-pub struct CustomersSender<T: Transport> {
-    transport: T,
-}
-
-impl<T: Transport> CustomersSender<T> {
-    /// Constructs a CustomersSender with the specified transport
-    pub fn via(transport: T) -> Self {
-        Self { transport }
-    }
+        arg: &BucketRef,
+    ) -> RpcResult<BucketThresholds> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
 
-    pub fn set_timeout(&self, interval: std::time::Duration) {
-        self.transport.set_timeout(interval);
-    }
-}
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "ServiceVendors.GetBucketThresholds",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
 
-#[cfg(not(target_arch = "wasm32"))]
-impl<'send> CustomersSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
-    /// Constructs a Sender using an actor's LinkDefinition,
-    /// Uses the provider's HostBridge for rpc
-    pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
-        Self {
-            transport: wasmbus_rpc::provider::ProviderTransport::new(ld, None),
-        }
-    }
-}
-#[cfg(target_arch = "wasm32")]
-impl CustomersSender<wasmbus_rpc::actor::prelude::WasmHost> {
-    /// Constructs a client for actor-to-actor messaging
-    /// using the recipient actor's public key
-    pub fn to_actor(actor_id: &str) -> Self {
-        let transport =
-            wasmbus_rpc::actor::prelude::WasmHost::to_actor(actor_id.to_string()).unwrap();
-        Self { transport }
+        let value: BucketThresholds = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': BucketThresholds", e)))?;
+        Ok(value)
     }
-}
-#[async_trait]
-impl<T: Transport + std::marker::Sync + std::marker::Send> Customers for CustomersSender<T> {
     #[allow(unused)]
-    async fn create_customer(
+    /// Bucket of a group followed by its ledger entries, by period, a page at a time for the exports
+    /// The ledgers written before their periods were kept are looked for once, from `config:ledger_start`
+    /// (2020-01 by default) to `config:ledger_end` (the latest period kept by default)
+    async fn export_bucket(
         &self,
         ctx: &Context,
-        arg: &Customer,
-    ) -> RpcResult<CreateCustomerReply> {
+        arg: &ExportBucketRequest,
+    ) -> RpcResult<ExportBucketReply> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1496,43 +12675,47 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> Customers for Custome
             .send(
                 ctx,
                 Message {
-                    method: "Customers.CreateCustomer",
+                    method: "ServiceVendors.ExportBucket",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: CreateCustomerReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': CreateCustomerReply", e)))?;
+        let value: ExportBucketReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': ExportBucketReply", e)))?;
         Ok(value)
     }
     #[allow(unused)]
-    async fn find_customer<TS: ToString + ?Sized + std::marker::Sync>(
-        &self,
-        ctx: &Context,
-        arg: &TS,
-    ) -> RpcResult<FindCustomerReply> {
-        let buf = wasmbus_rpc::common::serialize(&arg.to_string())?;
+    /// Writes the buckets and ledger entries of an export, the reply is the number of records written
+    async fn restore_buckets(&self, ctx: &Context, arg: &RestoreBucketsRequest) -> RpcResult<u32> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
             .transport
             .send(
                 ctx,
                 Message {
-                    method: "Customers.FindCustomer",
+                    method: "ServiceVendors.RestoreBuckets",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: FindCustomerReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': FindCustomerReply", e)))?;
+        let value: u32 = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': U32", e)))?;
         Ok(value)
     }
     #[allow(unused)]
-    async fn healthz(&self, ctx: &Context, arg: &HealthzRequest) -> RpcResult<HealthzReply> {
+    /// Keeps the periods of the ledgers of groups written before their periods were kept, so that
+    /// they are exported. They are looked for from `config:ledger_start` to `config:ledger_end` (the
+    /// latest period kept when not set).
+    async fn migrate_ledgers(
+        &self,
+        ctx: &Context,
+        arg: &MigrateLedgersRequest,
+    ) -> RpcResult<MigrateRecordsReply> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1540,81 +12723,124 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> Customers for Custome
             .send(
                 ctx,
                 Message {
-                    method: "Customers.Healthz",
+                    method: "ServiceVendors.MigrateLedgers",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: HealthzReply = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': HealthzReply", e)))?;
+        let value: MigrateRecordsReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': MigrateRecordsReply", e)))?;
         Ok(value)
     }
 }
 
-/// Description of SharedBucket service
+/// HTTP callbacks delivering the usage events of a customer group
 /// wasmbus.actorReceive
 #[async_trait]
-pub trait ServiceVendors {
-    async fn authorize_service_usage(&self, ctx: &Context, arg: &ServiceUsage)
-        -> RpcResult<String>;
-    async fn buy_service(&self, ctx: &Context, arg: &ServiceOrder) -> RpcResult<String>;
+pub trait Webhooks {
+    async fn register_webhook(
+        &self,
+        ctx: &Context,
+        arg: &RegisterWebhookRequest,
+    ) -> RpcResult<RegisterWebhookReply>;
+    /// Lists the webhooks of a group, secrets are not returned
+    async fn list_webhooks(
+        &self,
+        ctx: &Context,
+        arg: &GroupWebhooksRequest,
+    ) -> RpcResult<WebhookList>;
+    async fn delete_webhook(
+        &self,
+        ctx: &Context,
+        arg: &WebhookRef,
+    ) -> RpcResult<DeleteWebhookReply>;
+    /// Lists the delivery attempts of a webhook, oldest first
+    async fn list_webhook_deliveries(
+        &self,
+        ctx: &Context,
+        arg: &WebhookRef,
+    ) -> RpcResult<WebhookDeliveryList>;
 }
 
-/// ServiceVendorsReceiver receives messages defined in the ServiceVendors service trait
-/// Description of SharedBucket service
+/// WebhooksReceiver receives messages defined in the Webhooks service trait
+/// HTTP callbacks delivering the usage events of a customer group
 #[doc(hidden)]
 #[async_trait]
-pub trait ServiceVendorsReceiver: MessageDispatch + ServiceVendors {
+pub trait WebhooksReceiver: MessageDispatch + Webhooks {
     async fn dispatch<'disp__, 'ctx__, 'msg__>(
         &'disp__ self,
         ctx: &'ctx__ Context,
         message: &Message<'msg__>,
     ) -> Result<Message<'msg__>, RpcError> {
         match message.method {
-            "AuthorizeServiceUsage" => {
-                let value: ServiceUsage = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'ServiceUsage': {}", e)))?;
+            "RegisterWebhook" => {
+                let value: RegisterWebhookRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'RegisterWebhookRequest': {}", e)))?;
 
-                let resp = ServiceVendors::authorize_service_usage(self, ctx, &value).await?;
+                let resp = Webhooks::register_webhook(self, ctx, &value).await?;
                 let buf = wasmbus_rpc::common::serialize(&resp)?;
 
                 Ok(Message {
-                    method: "ServiceVendors.AuthorizeServiceUsage",
+                    method: "Webhooks.RegisterWebhook",
                     arg: Cow::Owned(buf),
                 })
             }
-            "BuyService" => {
-                let value: ServiceOrder = wasmbus_rpc::common::deserialize(&message.arg)
-                    .map_err(|e| RpcError::Deser(format!("'ServiceOrder': {}", e)))?;
+            "ListWebhooks" => {
+                let value: GroupWebhooksRequest = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'GroupWebhooksRequest': {}", e)))?;
 
-                let resp = ServiceVendors::buy_service(self, ctx, &value).await?;
+                let resp = Webhooks::list_webhooks(self, ctx, &value).await?;
                 let buf = wasmbus_rpc::common::serialize(&resp)?;
 
                 Ok(Message {
-                    method: "ServiceVendors.BuyService",
+                    method: "Webhooks.ListWebhooks",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "DeleteWebhook" => {
+                let value: WebhookRef = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'WebhookRef': {}", e)))?;
+
+                let resp = Webhooks::delete_webhook(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Webhooks.DeleteWebhook",
+                    arg: Cow::Owned(buf),
+                })
+            }
+            "ListWebhookDeliveries" => {
+                let value: WebhookRef = wasmbus_rpc::common::deserialize(&message.arg)
+                    .map_err(|e| RpcError::Deser(format!("'WebhookRef': {}", e)))?;
+
+                let resp = Webhooks::list_webhook_deliveries(self, ctx, &value).await?;
+                let buf = wasmbus_rpc::common::serialize(&resp)?;
+
+                Ok(Message {
+                    method: "Webhooks.ListWebhookDeliveries",
                     arg: Cow::Owned(buf),
                 })
             }
             _ => Err(RpcError::MethodNotHandled(format!(
-                "ServiceVendors::{}",
+                "Webhooks::{}",
                 message.method
             ))),
         }
     }
 }
 
-/// ServiceVendorsSender sends messages to a ServiceVendors service
-/// Description of SharedBucket service
-/// client for sending ServiceVendors messages
+/// WebhooksSender sends messages to a Webhooks service
+/// HTTP callbacks delivering the usage events of a customer group
+/// client for sending Webhooks messages
 #[derive(Debug)]
-pub struct ServiceVendorsSender<T: Transport> {
+pub struct WebhooksSender<T: Transport> {
     transport: T,
 }
 
-impl<T: Transport> ServiceVendorsSender<T> {
-    /// Constructs a ServiceVendorsSender with the specified transport
+impl<T: Transport> WebhooksSender<T> {
+    /// Constructs a WebhooksSender with the specified transport
     pub fn via(transport: T) -> Self {
         Self { transport }
     }
@@ -1625,7 +12851,7 @@ impl<T: Transport> ServiceVendorsSender<T> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-impl<'send> ServiceVendorsSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
+impl<'send> WebhooksSender<wasmbus_rpc::provider::ProviderTransport<'send>> {
     /// Constructs a Sender using an actor's LinkDefinition,
     /// Uses the provider's HostBridge for rpc
     pub fn for_actor(ld: &'send wasmbus_rpc::core::LinkDefinition) -> Self {
@@ -1635,7 +12861,7 @@ impl<'send> ServiceVendorsSender<wasmbus_rpc::provider::ProviderTransport<'send>
     }
 }
 #[cfg(target_arch = "wasm32")]
-impl ServiceVendorsSender<wasmbus_rpc::actor::prelude::WasmHost> {
+impl WebhooksSender<wasmbus_rpc::actor::prelude::WasmHost> {
     /// Constructs a client for actor-to-actor messaging
     /// using the recipient actor's public key
     pub fn to_actor(actor_id: &str) -> Self {
@@ -1645,15 +12871,13 @@ impl ServiceVendorsSender<wasmbus_rpc::actor::prelude::WasmHost> {
     }
 }
 #[async_trait]
-impl<T: Transport + std::marker::Sync + std::marker::Send> ServiceVendors
-    for ServiceVendorsSender<T>
-{
+impl<T: Transport + std::marker::Sync + std::marker::Send> Webhooks for WebhooksSender<T> {
     #[allow(unused)]
-    async fn authorize_service_usage(
+    async fn register_webhook(
         &self,
         ctx: &Context,
-        arg: &ServiceUsage,
-    ) -> RpcResult<String> {
+        arg: &RegisterWebhookRequest,
+    ) -> RpcResult<RegisterWebhookReply> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1661,19 +12885,24 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> ServiceVendors
             .send(
                 ctx,
                 Message {
-                    method: "ServiceVendors.AuthorizeServiceUsage",
+                    method: "Webhooks.RegisterWebhook",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: String = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': String", e)))?;
+        let value: RegisterWebhookReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': RegisterWebhookReply", e)))?;
         Ok(value)
     }
     #[allow(unused)]
-    async fn buy_service(&self, ctx: &Context, arg: &ServiceOrder) -> RpcResult<String> {
+    /// Lists the webhooks of a group, secrets are not returned
+    async fn list_webhooks(
+        &self,
+        ctx: &Context,
+        arg: &GroupWebhooksRequest,
+    ) -> RpcResult<WebhookList> {
         let buf = wasmbus_rpc::common::serialize(arg)?;
 
         let resp = self
@@ -1681,15 +12910,64 @@ impl<T: Transport + std::marker::Sync + std::marker::Send> ServiceVendors
             .send(
                 ctx,
                 Message {
-                    method: "ServiceVendors.BuyService",
+                    method: "Webhooks.ListWebhooks",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: WebhookList = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': WebhookList", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    async fn delete_webhook(
+        &self,
+        ctx: &Context,
+        arg: &WebhookRef,
+    ) -> RpcResult<DeleteWebhookReply> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Webhooks.DeleteWebhook",
+                    arg: Cow::Borrowed(&buf),
+                },
+                None,
+            )
+            .await?;
+
+        let value: DeleteWebhookReply = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': DeleteWebhookReply", e)))?;
+        Ok(value)
+    }
+    #[allow(unused)]
+    /// Lists the delivery attempts of a webhook, oldest first
+    async fn list_webhook_deliveries(
+        &self,
+        ctx: &Context,
+        arg: &WebhookRef,
+    ) -> RpcResult<WebhookDeliveryList> {
+        let buf = wasmbus_rpc::common::serialize(arg)?;
+
+        let resp = self
+            .transport
+            .send(
+                ctx,
+                Message {
+                    method: "Webhooks.ListWebhookDeliveries",
                     arg: Cow::Borrowed(&buf),
                 },
                 None,
             )
             .await?;
 
-        let value: String = wasmbus_rpc::common::deserialize(&resp)
-            .map_err(|e| RpcError::Deser(format!("'{}': String", e)))?;
+        let value: WebhookDeliveryList = wasmbus_rpc::common::deserialize(&resp)
+            .map_err(|e| RpcError::Deser(format!("'{}': WebhookDeliveryList", e)))?;
         Ok(value)
     }
 }
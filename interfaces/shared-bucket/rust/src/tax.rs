@@ -0,0 +1,84 @@
+//! Tax rates by location and rounding of the amounts charged
+
+use crate::TaxRule;
+
+/// Any country
+pub const ANY_COUNTRY: &str = "*";
+
+/// Tax rate (percentage) of a location : the rule of the region, else the one of the country,
+/// else the `*` rule, else no tax. Codes are compared case insensitively.
+pub fn rate(rules: &[TaxRule], country: Option<&str>, region: Option<&str>) -> f64 {
+    let matches = |rule: &TaxRule| {
+        rule.country.eq_ignore_ascii_case(country.unwrap_or_default())
+    };
+
+    let regional = rules.iter().find(|rule| {
+        matches(rule) && region.is_some_and(|region| rule.region.as_deref().is_some_and(|r| r.eq_ignore_ascii_case(region)))
+    });
+    let national = || rules.iter().find(|rule| matches(rule) && rule.region.is_none());
+    let any = || rules.iter().find(|rule| rule.country == ANY_COUNTRY && rule.region.is_none());
+
+    regional.or_else(national).or_else(any).map_or(0.0, |rule| rule.rate)
+}
+
+/// Rounds an amount to the cent, halves away from zero.
+/// Amounts are first rounded to 1e-9 so that decimal halves (1.005) which are not exact in binary round up.
+pub fn round(amount: f64) -> f64 {
+    ((amount * 1e9).round() / 1e7).round() / 100.0
+}
+
+/// Taxes on an amount, rounded to the cent
+pub fn taxes(amount: f64, rate: f64) -> f64 {
+    round(amount * rate / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(country: &str, region: Option<&str>, rate: f64) -> TaxRule {
+        TaxRule { country: country.to_string(), region: region.map(|r| r.to_string()), rate }
+    }
+
+    fn rules() -> Vec<TaxRule> {
+        vec![
+            rule("FR", None, 20.0),
+            rule("ES", None, 21.0),
+            rule("ES", Some("ES-CN"), 7.0),
+            rule("*", None, 10.0),
+        ]
+    }
+
+    #[test]
+    fn region_rule_applies_over_country_rule() {
+        assert_eq!(rate(&rules(), Some("ES"), Some("ES-CN")), 7.0);
+        assert_eq!(rate(&rules(), Some("ES"), Some("ES-MD")), 21.0);
+        assert_eq!(rate(&rules(), Some("es"), Some("es-cn")), 7.0);
+    }
+
+    #[test]
+    fn any_country_rule_applies_elsewhere() {
+        assert_eq!(rate(&rules(), Some("FR"), None), 20.0);
+        assert_eq!(rate(&rules(), Some("DE"), None), 10.0);
+        assert_eq!(rate(&rules(), None, None), 10.0);
+        assert_eq!(rate(&rules()[..3], Some("DE"), None), 0.0);
+    }
+
+    #[test]
+    fn rounds_to_the_cent_halves_away_from_zero() {
+        assert_eq!(round(1.004), 1.0);
+        assert_eq!(round(1.005), 1.01);
+        assert_eq!(round(2.675), 2.68);
+        assert_eq!(round(0.125), 0.13);
+        assert_eq!(round(-1.005), -1.01);
+        assert_eq!(round(10.0 / 3.0), 3.33);
+    }
+
+    #[test]
+    fn taxes_are_rounded() {
+        assert_eq!(taxes(19.99, 20.0), 4.0);
+        assert_eq!(taxes(0.05, 21.0), 0.01);
+        assert_eq!(taxes(0.02, 21.0), 0.0);
+        assert_eq!(taxes(100.0, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+// Types generated in `shared_bucket.rs`, shared by the tests and the fuzz target
+
+/// Calls `$m!(Type, encode_type, decode_type)` for every type of the interface. The `codecs_are_listed`
+/// test fails when a type is missing, after the smithy models changed.
+macro_rules! for_each_codec {
+    ($m:ident) => {
+        $m!(AddCustomerReply, encode_add_customer_reply, decode_add_customer_reply);
+        $m!(AddCustomerRequest, encode_add_customer_request, decode_add_customer_request);
+        $m!(AuthorizeServiceUsageReply, encode_authorize_service_usage_reply, decode_authorize_service_usage_reply);
+        $m!(Bucket, encode_bucket, decode_bucket);
+        $m!(BucketRef, encode_bucket_ref, decode_bucket_ref);
+        $m!(BucketThresholds, encode_bucket_thresholds, decode_bucket_thresholds);
+        $m!(BuyServiceReply, encode_buy_service_reply, decode_buy_service_reply);
+        $m!(CatalogService, encode_catalog_service, decode_catalog_service);
+        $m!(CatalogServiceReply, encode_catalog_service_reply, decode_catalog_service_reply);
+        $m!(CatalogServiceRequest, encode_catalog_service_request, decode_catalog_service_request);
+        $m!(CatalogServices, encode_catalog_services, decode_catalog_services);
+        $m!(CreateCustomerGroupReply, encode_create_customer_group_reply, decode_create_customer_group_reply);
+        $m!(CreateCustomerGroupRequest, encode_create_customer_group_request, decode_create_customer_group_request);
+        $m!(CreateCustomerReply, encode_create_customer_reply, decode_create_customer_reply);
+        $m!(CreateCustomerRequest, encode_create_customer_request, decode_create_customer_request);
+        $m!(Customer, encode_customer, decode_customer);
+        $m!(CustomerGroup, encode_customer_group, decode_customer_group);
+        $m!(DeleteWebhookReply, encode_delete_webhook_reply, decode_delete_webhook_reply);
+        $m!(EventTypes, encode_event_types, decode_event_types);
+        $m!(ExportBucketReply, encode_export_bucket_reply, decode_export_bucket_reply);
+        $m!(ExportBucketRequest, encode_export_bucket_request, decode_export_bucket_request);
+        $m!(ExportRecordsReply, encode_export_records_reply, decode_export_records_reply);
+        $m!(ExportRecordsRequest, encode_export_records_request, decode_export_records_request);
+        $m!(ExportedRecord, encode_exported_record, decode_exported_record);
+        $m!(ExportedRecords, encode_exported_records, decode_exported_records);
+        $m!(FieldNames, encode_field_names, decode_field_names);
+        $m!(FindCustomerGroupReply, encode_find_customer_group_reply, decode_find_customer_group_reply);
+        $m!(FindCustomerGroupRequest, encode_find_customer_group_request, decode_find_customer_group_request);
+        $m!(FindCustomerReply, encode_find_customer_reply, decode_find_customer_reply);
+        $m!(FindCustomerRequest, encode_find_customer_request, decode_find_customer_request);
+        $m!(GetInvoiceReply, encode_get_invoice_reply, decode_get_invoice_reply);
+        $m!(GetServiceReply, encode_get_service_reply, decode_get_service_reply);
+        $m!(GroupCustomers, encode_group_customers, decode_group_customers);
+        $m!(GroupIds, encode_group_ids, decode_group_ids);
+        $m!(GroupLedgerEntry, encode_group_ledger_entry, decode_group_ledger_entry);
+        $m!(GroupWebhooksRequest, encode_group_webhooks_request, decode_group_webhooks_request);
+        $m!(HealthzReply, encode_healthz_reply, decode_healthz_reply);
+        $m!(HealthzRequest, encode_healthz_request, decode_healthz_request);
+        $m!(HourRate, encode_hour_rate, decode_hour_rate);
+        $m!(HourRates, encode_hour_rates, decode_hour_rates);
+        $m!(ImportCustomersReply, encode_import_customers_reply, decode_import_customers_reply);
+        $m!(ImportCustomersRequest, encode_import_customers_request, decode_import_customers_request);
+        $m!(ImportResult, encode_import_result, decode_import_result);
+        $m!(ImportRow, encode_import_row, decode_import_row);
+        $m!(ImportRows, encode_import_rows, decode_import_rows);
+        $m!(Invoice, encode_invoice, decode_invoice);
+        $m!(InvoiceLine, encode_invoice_line, decode_invoice_line);
+        $m!(InvoiceLines, encode_invoice_lines, decode_invoice_lines);
+        $m!(InvoiceOrder, encode_invoice_order, decode_invoice_order);
+        $m!(InvoiceOrders, encode_invoice_orders, decode_invoice_orders);
+        $m!(InvoiceRequest, encode_invoice_request, decode_invoice_request);
+        $m!(LedgerEntry, encode_ledger_entry, decode_ledger_entry);
+        $m!(ListCustomersReply, encode_list_customers_reply, decode_list_customers_reply);
+        $m!(ListCustomersRequest, encode_list_customers_request, decode_list_customers_request);
+        $m!(Membership, encode_membership, decode_membership);
+        $m!(MigrateLedgersRequest, encode_migrate_ledgers_request, decode_migrate_ledgers_request);
+        $m!(MigrateRecordsReply, encode_migrate_records_reply, decode_migrate_records_reply);
+        $m!(MigrateRecordsRequest, encode_migrate_records_request, decode_migrate_records_request);
+        $m!(Principal, encode_principal, decode_principal);
+        $m!(RefundReply, encode_refund_reply, decode_refund_reply);
+        $m!(RefundRequest, encode_refund_request, decode_refund_request);
+        $m!(RegisterVendorReply, encode_register_vendor_reply, decode_register_vendor_reply);
+        $m!(RegisterWebhookReply, encode_register_webhook_reply, decode_register_webhook_reply);
+        $m!(RegisterWebhookRequest, encode_register_webhook_request, decode_register_webhook_request);
+        $m!(RemoveCustomerReply, encode_remove_customer_reply, decode_remove_customer_reply);
+        $m!(RemoveCustomerRequest, encode_remove_customer_request, decode_remove_customer_request);
+        $m!(RenameCustomerGroupReply, encode_rename_customer_group_reply, decode_rename_customer_group_reply);
+        $m!(RenameCustomerGroupRequest, encode_rename_customer_group_request, decode_rename_customer_group_request);
+        $m!(RestoreBucketsRequest, encode_restore_buckets_request, decode_restore_buckets_request);
+        $m!(RestoreRecordsReply, encode_restore_records_reply, decode_restore_records_reply);
+        $m!(RestoreRecordsRequest, encode_restore_records_request, decode_restore_records_request);
+        $m!(Roles, encode_roles, decode_roles);
+        $m!(Service, encode_service, decode_service);
+        $m!(ServiceIds, encode_service_ids, decode_service_ids);
+        $m!(ServiceOrder, encode_service_order, decode_service_order);
+        $m!(ServiceRef, encode_service_ref, decode_service_ref);
+        $m!(ServiceUsage, encode_service_usage, decode_service_usage);
+        $m!(SetBucketThresholdsReply, encode_set_bucket_thresholds_reply, decode_set_bucket_thresholds_reply);
+        $m!(SetBucketThresholdsRequest, encode_set_bucket_thresholds_request, decode_set_bucket_thresholds_request);
+        $m!(Tariff, encode_tariff, decode_tariff);
+        $m!(TariffTier, encode_tariff_tier, decode_tariff_tier);
+        $m!(TariffTiers, encode_tariff_tiers, decode_tariff_tiers);
+        $m!(TaxRule, encode_tax_rule, decode_tax_rule);
+        $m!(TaxRules, encode_tax_rules, decode_tax_rules);
+        $m!(Threshold, encode_threshold, decode_threshold);
+        $m!(ThresholdList, encode_threshold_list, decode_threshold_list);
+        $m!(Transfer, encode_transfer, decode_transfer);
+        $m!(TransferReply, encode_transfer_reply, decode_transfer_reply);
+        $m!(TransferRequest, encode_transfer_request, decode_transfer_request);
+        $m!(Transfers, encode_transfers, decode_transfers);
+        $m!(UsageEvent, encode_usage_event, decode_usage_event);
+        $m!(Vendor, encode_vendor, decode_vendor);
+        $m!(VendorCredentials, encode_vendor_credentials, decode_vendor_credentials);
+        $m!(VolumeDiscount, encode_volume_discount, decode_volume_discount);
+        $m!(VolumeDiscounts, encode_volume_discounts, decode_volume_discounts);
+        $m!(Webhook, encode_webhook, decode_webhook);
+        $m!(WebhookDelivery, encode_webhook_delivery, decode_webhook_delivery);
+        $m!(WebhookDeliveryList, encode_webhook_delivery_list, decode_webhook_delivery_list);
+        $m!(WebhookList, encode_webhook_list, decode_webhook_list);
+        $m!(WebhookRef, encode_webhook_ref, decode_webhook_ref);
+    };
+}
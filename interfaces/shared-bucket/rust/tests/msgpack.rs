@@ -0,0 +1,252 @@
+//! Round-trips of the MessagePack serialization the actors and providers exchange messages with,
+//! with arbitrary values of every type
+
+use std::fmt;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use wasmbus_rpc::common::{deserialize, serialize};
+
+#[macro_use]
+mod codecs;
+
+/// Deserializes a value out of arbitrary bytes, reading zeroes past their end: as proptest shrinks
+/// the bytes, it shrinks the value.
+struct Entropy<'a>(&'a [u8]);
+
+type Error = de::value::Error;
+
+impl Entropy<'_> {
+    fn byte(&mut self) -> u8 {
+        match self.0.split_first() {
+            Some((byte, rest)) => {
+                self.0 = rest;
+                *byte
+            },
+            None => 0,
+        }
+    }
+
+    fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+        bytes.iter_mut().for_each(|byte| *byte = self.byte());
+        bytes
+    }
+
+    /// ASCII characters, and a few ones encoded on several bytes
+    fn char(&mut self) -> char {
+        match self.byte() {
+            byte @ 0..=0x7f => byte as char,
+            byte => ['é', '€', '𝄞'][byte as usize % 3],
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Entropy<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(de::Error::custom("only the types of the interface are supported"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_bool(self.byte() % 2 == 1)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u8(self.byte())
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u16(u16::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u32(u32::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u64(u64::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i8(self.byte() as i8)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i16(i16::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i32(i32::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i64(i64::from_le_bytes(self.bytes()))
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = f32::from_le_bytes(self.bytes());
+        visitor.visit_f32(if value.is_nan() { 0.0 } else { value })
+    }
+
+    /// NaN is never equal to itself, the round-trips could not be compared
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = f64::from_le_bytes(self.bytes());
+        visitor.visit_f64(if value.is_nan() { 0.0 } else { value })
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_char(self.char())
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.byte() % 16;
+        visitor.visit_string((0..len).map(|_| self.char()).collect())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.byte() % 2 {
+            0 => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.byte() % 4;
+        visitor.visit_seq(Items { entropy: self, len })
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(Fields { entropy: self, fields: fields.iter() })
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct Items<'a, 'b> {
+    entropy: &'a mut Entropy<'b>,
+    len: u8,
+}
+
+impl<'de> SeqAccess<'de> for Items<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.entropy).map(Some)
+    }
+}
+
+/// Every field of a struct
+struct Fields<'a, 'b> {
+    entropy: &'a mut Entropy<'b>,
+    fields: std::slice::Iter<'static, &'static str>,
+}
+
+impl<'de> MapAccess<'de> for Fields<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        self.fields.next().map(|field| seed.deserialize((*field).into_deserializer())).transpose()
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.entropy)
+    }
+}
+
+/// Arbitrary values of a type of the interface
+fn arbitrary<T: DeserializeOwned + fmt::Debug>() -> impl Strategy<Value = T> {
+    vec(any::<u8>(), 0..512).prop_map(|bytes| T::deserialize(&mut Entropy(&bytes)).unwrap())
+}
+
+/// Serialized values deserialize to the same value. Failing messages are printed in base64, to be
+/// added to the corpus of the fuzz target.
+macro_rules! round_trip {
+    ($type:ident, $encode:ident, $decode:ident) => {
+        proptest! {
+            #[test]
+            fn $decode(value in arbitrary::<shared_bucket::$type>()) {
+                let message = serialize(&value).unwrap();
+                let decoded = deserialize::<shared_bucket::$type>(&message);
+                prop_assert_eq!(decoded.ok(), Some(value), "message: {}", base64::encode(&message));
+            }
+        }
+    };
+}
+
+/// Truncated or altered messages fail to deserialize, without panicking nor hanging
+macro_rules! malformed {
+    ($type:ident, $encode:ident, $decode:ident) => {
+        proptest! {
+            #[test]
+            fn $decode(value in arbitrary::<shared_bucket::$type>(), cut in any::<usize>(), changes in vec(any::<(usize, u8)>(), 0..4)) {
+                let mut message = serialize(&value).unwrap();
+                message.truncate(cut % (message.len() + 1));
+                for (index, byte) in changes {
+                    if !message.is_empty() {
+                        let len = message.len();
+                        message[index % len] ^= byte;
+                    }
+                }
+                let _ = deserialize::<shared_bucket::$type>(&message);
+            }
+        }
+    };
+}
+
+mod round_trip {
+    use super::*;
+
+    for_each_codec!(round_trip);
+}
+
+mod malformed {
+    use super::*;
+
+    for_each_codec!(malformed);
+}
+
+#[test]
+fn codecs_are_listed() {
+    let mut listed = Vec::new();
+    macro_rules! list {
+        ($type:ident, $encode:ident, $decode:ident) => {
+            listed.push(stringify!($decode));
+        };
+    }
+    for_each_codec!(list);
+
+    let mut generated: Vec<_> = include_str!("../src/shared_bucket.rs").lines()
+        .filter_map(|line| line.strip_prefix("pub fn decode_"))
+        .map(|line| line.split('(').next().unwrap())
+        .collect();
+    generated.sort_unstable();
+    listed.sort_unstable();
+    assert_eq!(listed.iter().map(|decode| &decode["decode_".len()..]).collect::<Vec<_>>(), generated);
+}
+
+/// Lengths are announced before the items : the items must be read rather than the announced
+/// length allocated up front
+#[test]
+fn announced_lengths_are_not_trusted() {
+    let message = [0xdd, 0xff, 0xff, 0xff, 0xff, 0xa1, b'a'];
+    assert!(deserialize::<shared_bucket::GroupCustomers>(&message).is_err());
+    assert!(deserialize::<shared_bucket::ExportedRecords>(&message).is_err());
+}
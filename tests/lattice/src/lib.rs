@@ -0,0 +1,82 @@
+//! Links actors and capability providers natively, in process, without a wasmCloud host nor NATS.
+//!
+//! The actors and capability providers are linked by name in a `TestLattice` : the messages
+//! the actors send through their senders are serialized and dispatched to the receiver, as
+//! the host would do. The `providers` keep their data in memory.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::channel::oneshot;
+use shared_bucket::lattice::{Lattice, Link};
+use wasmbus_rpc::common::{Context, Message, MessageDispatch, SendOpts, Transport};
+use wasmbus_rpc::error::{RpcError, RpcResult};
+
+pub mod providers;
+
+/// Actors and providers, by call alias or contract id
+#[derive(Clone, Debug, Default)]
+pub struct TestLattice {
+    links: Arc<RwLock<HashMap<String, Link>>>,
+}
+
+impl TestLattice {
+    /// Links the receiver of the messages sent to `name`
+    pub fn link<D: MessageDispatch + Send + Sync + 'static>(&self, name: &str, receiver: D) -> Arc<D> {
+        self.link_with_latency(name, receiver, Duration::ZERO)
+    }
+
+    /// Links a receiver answering each message after a delay, as it would across a lattice
+    pub fn link_with_latency<D: MessageDispatch + Send + Sync + 'static>(&self, name: &str, receiver: D, latency: Duration) -> Arc<D> {
+        let receiver = Arc::new(receiver);
+        self.links.write().unwrap().insert(name.to_string(), Link::new(Dispatcher { receiver: receiver.clone(), latency }));
+        receiver
+    }
+
+    fn find(&self, name: &str) -> RpcResult<Link> {
+        self.links.read().unwrap().get(name).cloned()
+            .ok_or_else(|| RpcError::Other(format!("nothing is linked as '{}'", name)))
+    }
+}
+
+impl Lattice for TestLattice {
+    fn actor(&self, alias: &str) -> RpcResult<Link> {
+        self.find(alias)
+    }
+
+    fn provider(&self, contract_id: &str) -> RpcResult<Link> {
+        self.find(contract_id)
+    }
+}
+
+/// Transport dispatching the messages to their receiver
+struct Dispatcher<D> {
+    receiver: Arc<D>,
+    latency: Duration,
+}
+
+/// Completes after the duration, without blocking the executor
+async fn delay(duration: Duration) {
+    let (sender, receiver) = oneshot::channel();
+    thread::spawn(move || {
+        thread::sleep(duration);
+        let _ = sender.send(());
+    });
+    let _ = receiver.await;
+}
+
+#[async_trait]
+impl<D: MessageDispatch + Send + Sync> Transport for Dispatcher<D> {
+    async fn send(&self, ctx: &Context, req: Message<'_>, _opts: Option<SendOpts>) -> RpcResult<Vec<u8>> {
+        if !self.latency.is_zero() {
+            delay(self.latency).await;
+        }
+        let reply = self.receiver.dispatch(ctx, req).await?;
+        Ok(reply.arg.into_owned())
+    }
+
+    fn set_timeout(&self, _interval: Duration) {}
+}
@@ -0,0 +1,263 @@
+//! In memory capability providers
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+
+use wasmbus_rpc::provider::prelude::*;
+use wasmcloud_interface_httpclient::{HttpClient, HttpClientReceiver, HttpRequest, HttpResponse};
+use wasmcloud_interface_keyvalue::{GetResponse, IncrementRequest, KeyValue, KeyValueReceiver, ListAddRequest, ListDelRequest, ListRangeRequest, SetAddRequest, SetDelRequest, SetRequest, StringList};
+use wasmcloud_interface_messaging::{Messaging, MessagingReceiver, PubMessage, ReplyMessage, RequestMessage};
+use wasmcloud_interface_numbergen::{NumberGen, NumberGenReceiver, RangeLimit};
+
+/// KeyValue provider keeping the values in memory. Expiration is ignored.
+#[derive(Debug, Default, Provider)]
+#[services(KeyValue)]
+pub struct MemoryKeyValue {
+    values: Mutex<HashMap<String, String>>,
+    lists: Mutex<HashMap<String, Vec<String>>>,
+    sets: Mutex<HashMap<String, BTreeSet<String>>>,
+    failing: Mutex<HashMap<String, u32>>,
+}
+
+impl MemoryKeyValue {
+    /// Value of a key, as the actors would read it
+    pub fn value(&self, key: &str) -> Option<String> {
+        self.values.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: &str, value: String) {
+        self.values.lock().unwrap().insert(key.to_string(), value);
+    }
+
+    /// Makes the next write to a key, list or set fail, as a provider losing its connection would.
+    /// Each call fails one more write.
+    pub fn fail_next_write(&self, key: &str) {
+        *self.failing.lock().unwrap().entry(key.to_string()).or_default() += 1;
+    }
+
+    fn write(&self, key: &str) -> RpcResult<()> {
+        let mut failing = self.failing.lock().unwrap();
+        match failing.get_mut(key) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    failing.remove(key);
+                }
+                Err(RpcError::Other(format!("write to '{}' failed", key)))
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// Appends to a list, as the actors would
+    pub fn push(&self, name: &str, value: String) {
+        self.lists.lock().unwrap().entry(name.to_string()).or_default().push(value);
+    }
+
+    /// Elements of a list, none when the list does not exist
+    pub fn list(&self, name: &str) -> Vec<String> {
+        self.lists.lock().unwrap().get(name).cloned().unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl KeyValue for MemoryKeyValue {
+    async fn increment(&self, _ctx: &Context, arg: &IncrementRequest) -> RpcResult<i32> {
+        let mut values = self.values.lock().unwrap();
+        let value = values.get(&arg.key).and_then(|value| value.parse::<i32>().ok()).unwrap_or(0) + arg.value;
+        values.insert(arg.key.clone(), value.to_string());
+        Ok(value)
+    }
+
+    async fn contains<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<bool> {
+        Ok(self.values.lock().unwrap().contains_key(&arg.to_string()))
+    }
+
+    async fn del<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<bool> {
+        Ok(self.values.lock().unwrap().remove(&arg.to_string()).is_some())
+    }
+
+    async fn get<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<GetResponse> {
+        Ok(match self.value(&arg.to_string()) {
+            Some(value) => GetResponse { value, exists: true },
+            None => GetResponse { value: "".to_string(), exists: false },
+        })
+    }
+
+    async fn list_add(&self, _ctx: &Context, arg: &ListAddRequest) -> RpcResult<u32> {
+        self.write(&arg.list_name)?;
+        let mut lists = self.lists.lock().unwrap();
+        let list = lists.entry(arg.list_name.clone()).or_default();
+        list.push(arg.value.clone());
+        Ok(list.len() as u32)
+    }
+
+    async fn list_clear<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<bool> {
+        Ok(self.lists.lock().unwrap().remove(&arg.to_string()).is_some())
+    }
+
+    async fn list_del(&self, _ctx: &Context, arg: &ListDelRequest) -> RpcResult<bool> {
+        let mut lists = self.lists.lock().unwrap();
+        let list = lists.entry(arg.list_name.clone()).or_default();
+        match list.iter().position(|value| *value == arg.value) {
+            Some(index) => {
+                list.remove(index);
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Elements from `start` to `stop` included, negative indexes counting from the end of the list
+    async fn list_range(&self, _ctx: &Context, arg: &ListRangeRequest) -> RpcResult<StringList> {
+        let lists = self.lists.lock().unwrap();
+        let list = match lists.get(&arg.list_name) {
+            Some(list) => list,
+            None => return Ok(Vec::new()),
+        };
+        let index = |i: i32| if i < 0 { list.len() as i32 + i } else { i };
+        let (start, stop) = (index(arg.start).max(0), index(arg.stop).min(list.len() as i32 - 1));
+        if start > stop {
+            return Ok(Vec::new());
+        }
+
+        Ok(list[start as usize..=stop as usize].to_vec())
+    }
+
+    async fn set(&self, _ctx: &Context, arg: &SetRequest) -> RpcResult<()> {
+        self.write(&arg.key)?;
+        self.insert(&arg.key, arg.value.clone());
+        Ok(())
+    }
+
+    async fn set_add(&self, _ctx: &Context, arg: &SetAddRequest) -> RpcResult<u32> {
+        self.write(&arg.set_name)?;
+        let mut sets = self.sets.lock().unwrap();
+        Ok(sets.entry(arg.set_name.clone()).or_default().insert(arg.value.clone()) as u32)
+    }
+
+    async fn set_del(&self, _ctx: &Context, arg: &SetDelRequest) -> RpcResult<u32> {
+        let mut sets = self.sets.lock().unwrap();
+        Ok(sets.entry(arg.set_name.clone()).or_default().remove(&arg.value) as u32)
+    }
+
+    async fn set_intersection(&self, _ctx: &Context, arg: &StringList) -> RpcResult<StringList> {
+        let sets = self.sets.lock().unwrap();
+        let mut names = arg.iter();
+        let first = names.next().and_then(|name| sets.get(name)).cloned().unwrap_or_default();
+        let intersection = names.fold(first, |result, name| {
+            result.intersection(&sets.get(name).cloned().unwrap_or_default()).cloned().collect()
+        });
+
+        Ok(intersection.into_iter().collect())
+    }
+
+    async fn set_query<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<StringList> {
+        let sets = self.sets.lock().unwrap();
+        Ok(sets.get(&arg.to_string()).map(|set| set.iter().cloned().collect()).unwrap_or_default())
+    }
+
+    async fn set_union(&self, _ctx: &Context, arg: &StringList) -> RpcResult<StringList> {
+        let sets = self.sets.lock().unwrap();
+        let union: BTreeSet<String> = arg.iter().filter_map(|name| sets.get(name)).flatten().cloned().collect();
+
+        Ok(union.into_iter().collect())
+    }
+
+    async fn set_clear<TS: ToString + ?Sized + Sync>(&self, _ctx: &Context, arg: &TS) -> RpcResult<bool> {
+        Ok(self.sets.lock().unwrap().remove(&arg.to_string()).is_some())
+    }
+}
+
+/// Messaging provider recording the published messages
+#[derive(Debug, Default, Provider)]
+#[services(Messaging)]
+pub struct RecordedMessages {
+    published: Mutex<Vec<PubMessage>>,
+}
+
+impl RecordedMessages {
+    /// Subjects of the messages published so far
+    pub fn subjects(&self) -> Vec<String> {
+        self.published.lock().unwrap().iter().map(|message| message.subject.clone()).collect()
+    }
+
+    /// Messages published so far
+    pub fn published(&self) -> Vec<PubMessage> {
+        self.published.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Messaging for RecordedMessages {
+    async fn publish(&self, _ctx: &Context, arg: &PubMessage) -> RpcResult<()> {
+        self.published.lock().unwrap().push(arg.clone());
+        Ok(())
+    }
+
+    async fn request(&self, _ctx: &Context, arg: &RequestMessage) -> RpcResult<ReplyMessage> {
+        Err(RpcError::Other(format!("nobody replies on '{}'", arg.subject)))
+    }
+}
+
+/// HttpClient provider recording the requests, answered with the status set by `answer_with`
+/// (200 by default)
+#[derive(Debug, Default, Provider)]
+#[services(HttpClient)]
+pub struct RecordedRequests {
+    requests: Mutex<Vec<HttpRequest>>,
+    status_code: Mutex<Option<u16>>,
+}
+
+impl RecordedRequests {
+    /// Requests sent so far
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Status code of the responses to the next requests
+    pub fn answer_with(&self, status_code: u16) {
+        *self.status_code.lock().unwrap() = Some(status_code);
+    }
+}
+
+#[async_trait]
+impl HttpClient for RecordedRequests {
+    async fn request(&self, _ctx: &Context, arg: &HttpRequest) -> RpcResult<HttpResponse> {
+        self.requests.lock().unwrap().push(arg.clone());
+        Ok(HttpResponse {
+            status_code: self.status_code.lock().unwrap().unwrap_or(200),
+            ..Default::default()
+        })
+    }
+}
+
+/// Number generator with predictable, sequential, guids
+#[derive(Debug, Default, Provider)]
+#[services(NumberGen)]
+pub struct SequentialNumbers {
+    sequence: Mutex<u32>,
+}
+
+impl SequentialNumbers {
+    fn next(&self) -> u32 {
+        let mut sequence = self.sequence.lock().unwrap();
+        *sequence += 1;
+        *sequence
+    }
+}
+
+#[async_trait]
+impl NumberGen for SequentialNumbers {
+    async fn generate_guid(&self, _ctx: &Context) -> RpcResult<String> {
+        Ok(format!("00000000-0000-4000-8000-{:012}", self.next()))
+    }
+
+    async fn random_in_range(&self, _ctx: &Context, arg: &RangeLimit) -> RpcResult<u32> {
+        Ok(arg.min + self.next() % (arg.max - arg.min).saturating_add(1))
+    }
+
+    async fn random_32(&self, _ctx: &Context) -> RpcResult<u32> {
+        Ok(self.next())
+    }
+}
@@ -0,0 +1,151 @@
+//! Runs the actors natively, in process, without a wasmCloud host nor NATS.
+//!
+//! `Platform` links the customers, service vendors, webhooks and API actors in a `TestLattice`
+//! with in memory providers and sends them HTTP requests.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use customers::CustomersActor;
+use futures::executor::block_on;
+use service_vendors::ServiceVendorsActor;
+use sha2::{Digest, Sha256};
+use shared_bucket::lattice::{HTTPCLIENT, KEYVALUE, MESSAGING, NUMBERGEN};
+use shared_bucket::Principal;
+use shared_bucket_api::SharedBucketAPIActor;
+use wasmbus_rpc::common::Context;
+use wasmcloud_interface_httpserver::{HttpRequest, HttpResponse, HttpServer};
+use wasmcloud_interface_messaging::{MessageSubscriber, SubMessage};
+use webhooks::{WebhooksActor, RETRY_SUBJECT};
+
+pub use test_lattice::{providers, TestLattice};
+
+use providers::{MemoryKeyValue, RecordedMessages, RecordedRequests, SequentialNumbers};
+
+/// Call aliases of the actors (see their Makefile)
+pub const CUSTOMERS_ACTOR: &str = "customers";
+pub const SERVICE_VENDORS_ACTOR: &str = "service-vendors";
+pub const WEBHOOKS_ACTOR: &str = "webhooks";
+
+/// The actors behind the API, linked together with in memory providers
+pub struct Platform {
+    pub api: SharedBucketAPIActor,
+    pub lattice: TestLattice,
+    pub keyvalue: Arc<MemoryKeyValue>,
+    pub messages: Arc<RecordedMessages>,
+    pub requests: Arc<RecordedRequests>,
+    webhooks: Arc<WebhooksActor>,
+    /// Published messages already handed to the webhooks actor
+    delivered: AtomicUsize,
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        let lattice = TestLattice::default();
+        let keyvalue = lattice.link(KEYVALUE, MemoryKeyValue::default());
+        let messages = lattice.link(MESSAGING, RecordedMessages::default());
+        let requests = lattice.link(HTTPCLIENT, RecordedRequests::default());
+        lattice.link(NUMBERGEN, SequentialNumbers::default());
+        lattice.link(CUSTOMERS_ACTOR, CustomersActor::new(lattice.clone()));
+        lattice.link(SERVICE_VENDORS_ACTOR, ServiceVendorsActor::new(lattice.clone()));
+        let webhooks = lattice.link(WEBHOOKS_ACTOR, WebhooksActor::new(lattice.clone()));
+
+        Platform {
+            api: SharedBucketAPIActor::new(lattice.clone()),
+            lattice,
+            keyvalue,
+            messages,
+            requests,
+            webhooks,
+            delivered: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl Platform {
+    /// Links a new instance of the service vendors actor, as a host restarting it would
+    pub fn restart_service_vendors(&self) {
+        self.lattice.link(SERVICE_VENDORS_ACTOR, ServiceVendorsActor::new(self.lattice.clone()));
+    }
+
+    /// Hands the messages published since the last call to the webhooks actor, as its subscription
+    /// to the usage events would
+    pub fn deliver_events(&self) {
+        let published = self.messages.published();
+        for message in &published[self.delivered.swap(published.len(), Ordering::SeqCst)..] {
+            self.subscribe(&message.subject, message.body.clone());
+        }
+    }
+
+    /// Advances the webhooks retry clock by one tick
+    pub fn tick_webhooks(&self) {
+        self.subscribe(RETRY_SUBJECT, Vec::new());
+    }
+
+    /// Advances the webhooks retry clock by one tick telling the Unix time, as the cron job does
+    pub fn tick_webhooks_at(&self, time: i64) {
+        self.subscribe(RETRY_SUBJECT, time.to_string().into_bytes());
+    }
+
+    fn subscribe(&self, subject: &str, body: Vec<u8>) {
+        let message = SubMessage { subject: subject.to_string(), reply_to: None, body };
+        block_on(self.webhooks.handle_message(&Context::default(), &message)).unwrap();
+    }
+
+    /// Registers an API key authenticating as the principal
+    pub fn api_key(&self, key: &str, principal: &Principal) {
+        let hash = hex::encode(Sha256::digest(key.as_bytes()));
+        self.keyvalue.insert(&format!("api_key:{}", hash), serde_json::to_string(principal).unwrap());
+    }
+
+    /// Sends a request to the API with an API key, the path may end with a query string.
+    /// As with the httpserver provider, errors of the actor are answered with a 500.
+    pub fn request(&self, method: &str, path: &str, api_key: &str, body: Option<serde_json::Value>) -> HttpResponse {
+        self.request_with_headers(method, path, api_key, body, &[])
+    }
+
+    /// Sends a request as `request` does, with more headers
+    pub fn request_with_headers(&self, method: &str, path: &str, api_key: &str, body: Option<serde_json::Value>, headers: &[(&str, &str)]) -> HttpResponse {
+        let headers = [&[("X-API-Key", api_key)], headers].concat();
+        self.send(method, path, body.map(|body| body.to_string().into_bytes()).unwrap_or_default(), &headers)
+    }
+
+    /// Sends a request to a vendor route with the `Basic` credentials of a vendor, as `request` does
+    pub fn vendor_request(&self, method: &str, path: &str, vendor: &str, secret: &str, body: Option<serde_json::Value>, headers: &[(&str, &str)]) -> HttpResponse {
+        let authorization = format!("Basic {}", base64::encode(format!("{}:{}", vendor, secret)));
+        let headers = [&[("Authorization", authorization.as_str())], headers].concat();
+        self.send(method, path, body.map(|body| body.to_string().into_bytes()).unwrap_or_default(), &headers)
+    }
+
+    /// Posts a file of a content type, as `request` does
+    pub fn upload(&self, path: &str, api_key: &str, content_type: &str, body: &str) -> HttpResponse {
+        self.send("POST", path, body.as_bytes().to_vec(), &[("X-API-Key", api_key), ("Content-Type", content_type)])
+    }
+
+    fn send(&self, method: &str, path: &str, body: Vec<u8>, headers: &[(&str, &str)]) -> HttpResponse {
+        let (path, query_string) = path.split_once('?').unwrap_or((path, ""));
+        let header = headers.iter().map(|(name, value)| (name.to_string(), vec![value.to_string()])).collect();
+        let request = HttpRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query_string: query_string.to_string(),
+            header,
+            body,
+        };
+
+        match block_on(self.api.handle_request(&Context::default(), &request)) {
+            Ok(response) => response,
+            Err(e) => HttpResponse::internal_server_error(e.to_string()),
+        }
+    }
+}
+
+/// ETag header of a response
+pub fn etag(response: &HttpResponse) -> Option<&str> {
+    response.header.get("ETag").and_then(|values| values.first()).map(|value| value.as_str())
+}
+
+/// JSON body of a response
+pub fn json(response: &HttpResponse) -> serde_json::Value {
+    serde_json::from_slice(&response.body).unwrap()
+}
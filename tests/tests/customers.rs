@@ -0,0 +1,463 @@
+use integration_tests::{etag, json, Platform};
+use serde_json::json;
+use shared_bucket::policy::OPERATOR_ROLE;
+use shared_bucket::Principal;
+
+const OPERATOR_KEY: &str = "operator-key";
+
+fn platform() -> Platform {
+    let platform = Platform::default();
+    platform.api_key(OPERATOR_KEY, &Principal {
+        id: "ops".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![OPERATOR_ROLE.to_string()]),
+        tenant: None,
+    });
+    platform
+}
+
+fn customer_key(platform: &Platform, id: &str) -> String {
+    let key = format!("{}-key", id);
+    platform.api_key(&key, &Principal { id: id.to_string(), kind: "user".to_string(), roles: None, tenant: None });
+    key
+}
+
+fn create_customer(platform: &Platform, first_name: &str) -> String {
+    let body = json!({ "firstName": first_name, "lastName": "Doe", "email": format!("{}@example.com", first_name) });
+    let response = platform.request("POST", "/customers", OPERATOR_KEY, Some(body));
+    assert_eq!(response.status_code, 200);
+
+    json(&response)["id"].as_str().unwrap().to_string()
+}
+
+fn create_group(platform: &Platform, key: &str, group: serde_json::Value) -> String {
+    let response = platform.request("POST", "/customer-groups", key, Some(group));
+    assert_eq!(response.status_code, 200);
+
+    json(&response)["id"].as_str().unwrap().to_string()
+}
+
+#[test]
+fn customers_are_created_and_found() {
+    let platform = platform();
+    let id = create_customer(&platform, "alice");
+
+    let response = platform.request("GET", &format!("/customers/{}", id), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    assert_eq!(json(&response)["firstName"], "alice");
+
+    let response = platform.request("GET", "/customers/unknown", OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 404);
+}
+
+#[test]
+fn group_members_are_listed() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let bob = create_customer(&platform, "bob");
+
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family", "admins": [alice] }));
+
+    // the admin adds the members
+    let alice_key = customer_key(&platform, &alice);
+    for member in [&alice, &bob] {
+        let response = platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, member), &alice_key, None);
+        assert_eq!(response.status_code, 200);
+    }
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), &customer_key(&platform, &bob), None);
+    assert_eq!(response.status_code, 200);
+    let names: Vec<_> = json(&response).as_array().unwrap().iter().map(|customer| customer["firstName"].clone()).collect();
+    assert_eq!(names, vec!["alice", "bob"]);
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers?fields=email", family), OPERATOR_KEY, None);
+    let customer = &json(&response)[0];
+    assert_eq!((customer["email"].as_str(), customer.get("lastName")), (Some("alice@example.com"), None));
+}
+
+#[test]
+fn group_members_are_paginated_and_removed() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let bob = create_customer(&platform, "bob");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family", "admins": [alice], "customers": [alice, bob] }));
+    let (alice_key, bob_key) = (customer_key(&platform, &alice), customer_key(&platform, &bob));
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers?limit=1", family), &bob_key, None);
+    assert_eq!(json(&response)[0]["id"], alice.as_str());
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers?after={}&limit=1", family, alice), &bob_key, None);
+    assert_eq!(json(&response)[0]["id"], bob.as_str());
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers?limit=all", family), &bob_key, None);
+    assert_eq!(response.status_code, 400);
+
+    let response = platform.request("DELETE", &format!("/customer-groups/{}/customers/{}", family, alice), &bob_key, None);
+    assert_eq!(response.status_code, 403);
+    let response = platform.request("DELETE", &format!("/customer-groups/{}/customers/{}", family, bob), &alice_key, None);
+    assert_eq!(response.status_code, 200);
+    let response = platform.request("DELETE", &format!("/customer-groups/{}/customers/{}", family, bob), &alice_key, None);
+    assert_eq!(response.status_code, 404);
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), &bob_key, None);
+    assert_eq!(response.status_code, 403);
+}
+
+#[test]
+fn customers_are_imported_in_bulk() {
+    let platform = platform();
+    create_customer(&platform, "alice");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    let csv = format!("firstName,lastName,email,group\nbob,\"Doe, Jr\",bob@example.com,{}\nalice,,alice@example.com,\ncarol\n", family);
+
+    let response = platform.upload("/customers/imports?dryRun=true", OPERATOR_KEY, "text/csv", &csv);
+    assert_eq!(response.status_code, 200);
+    assert_eq!(json(&response)["totals"], json!({ "created": 1, "duplicate": 1, "invalid": 1 }));
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), OPERATOR_KEY, None);
+    assert_eq!(json(&response), json!([]));
+
+    let response = platform.upload("/customers/imports", OPERATOR_KEY, "text/csv", &csv);
+    let report = json(&response);
+    assert_eq!((report["dryRun"].as_bool(), report["rows"][0]["line"].as_u64(), report["rows"][2]["status"].as_str()), (Some(false), Some(2), Some("invalid")));
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), OPERATOR_KEY, None);
+    assert_eq!(json(&response)[0]["lastName"], "Doe, Jr");
+
+    let ndjson = "{\"firstName\":\"dave\",\"email\":\"dave@example.com\"}\n{\"firstName\":\"bob\",\"email\":\"BOB@example.com\"}\n";
+    let response = platform.upload("/customers/imports", OPERATOR_KEY, "application/x-ndjson", ndjson);
+    assert_eq!(json(&response)["totals"], json!({ "created": 1, "duplicate": 1 }));
+
+    // a duplicate row still adds the existing customer to its group
+    let response = platform.upload("/customers/imports", OPERATOR_KEY, "text/csv", &format!("firstName,email,group\nalice,alice@example.com,{}\n", family));
+    assert_eq!(json(&response)["rows"][0]["status"], "duplicate");
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), OPERATOR_KEY, None);
+    assert_eq!(json(&response).as_array().unwrap().len(), 2);
+
+    let response = platform.upload("/customers/imports", OPERATOR_KEY, "application/json", ndjson);
+    assert_eq!(response.status_code, 415);
+    let response = platform.upload("/customers/imports", OPERATOR_KEY, "text/csv", "firstName,phone\n");
+    assert_eq!(response.status_code, 400);
+    let response = platform.upload("/customers/imports", &customer_key(&platform, "alice"), "text/csv", &csv);
+    assert_eq!(response.status_code, 403);
+}
+
+#[test]
+fn large_imports_are_sent_in_batches() {
+    let platform = platform();
+    // the last row repeats the email of the first one, in another batch
+    let mut csv = "firstName,email\n".to_string();
+    for n in 0..600 {
+        csv.push_str(&format!("customer{},customer{}@example.com\n", n, n));
+    }
+    csv.push_str("again,CUSTOMER0@example.com\n");
+
+    for path in ["/customers/imports?dryRun=true", "/customers/imports"] {
+        let response = platform.upload(path, OPERATOR_KEY, "text/csv", &csv);
+        let report = json(&response);
+        assert_eq!(report["totals"], json!({ "created": 600, "duplicate": 1 }), "{}", path);
+        assert_eq!((report["rows"][600]["line"].as_u64(), report["rows"][600]["status"].as_str()), (Some(602), Some("duplicate")));
+    }
+}
+
+#[test]
+fn customer_events_are_published() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    let response = platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, alice), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+
+    let published = platform.messages.published();
+    let subjects: Vec<&str> = published.iter().map(|message| message.subject.as_str()).collect();
+    assert_eq!(subjects, ["sharedbucket.events.customer.created", "sharedbucket.events.group.member_added"]);
+    let events: Vec<serde_json::Value> = published.iter().map(|message| serde_json::from_slice(&message.body).unwrap()).collect();
+    assert_eq!((events[0]["schemaVersion"].as_str(), events[0]["eventType"].as_str()), (Some("1"), Some("customer.created")));
+    assert_eq!(events[0]["customer"], json!(alice));
+    assert_eq!(events[1]["eventType"], "group.member_added");
+    assert_eq!((&events[1]["customer"], &events[1]["group"]), (&json!(alice), &json!(family)));
+    assert_ne!(events[0]["id"], events[1]["id"]);
+}
+
+#[test]
+fn groups_are_created_once() {
+    let platform = platform();
+
+    create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    let response = platform.request("POST", "/customer-groups", OPERATOR_KEY, Some(json!({ "name": "Family" })));
+    assert_eq!(response.status_code, 500);
+}
+
+#[test]
+fn groups_are_renamed_by_their_admins() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family", "admins": [alice] }));
+    let alice_key = customer_key(&platform, &alice);
+
+    let response = platform.request("PATCH", &format!("/customer-groups/{}", family), &alice_key, Some(json!({ "name": "The Does / Paris" })));
+    assert_eq!(response.status_code, 200);
+    let response = platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, alice), &alice_key, None);
+    assert_eq!(response.status_code, 200);
+
+    let response = platform.request("PATCH", "/customer-groups/unknown", OPERATOR_KEY, Some(json!({ "name": "family" })));
+    assert_eq!(response.status_code, 404);
+    create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+}
+
+#[test]
+fn stale_group_changes_are_refused() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let bob = create_customer(&platform, "bob");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family", "admins": [alice, bob] }));
+    let (alice_key, bob_key) = (customer_key(&platform, &alice), customer_key(&platform, &bob));
+
+    // both admins read the group, then alice renames it
+    let response = platform.request("GET", &format!("/customer-groups/{}", family), &alice_key, None);
+    assert_eq!((response.status_code, json(&response)["name"].as_str()), (200, Some("family")));
+    let read = etag(&response).unwrap().to_string();
+    let response = platform.request("GET", &format!("/customer-groups/{}", family), &bob_key, None);
+    assert_eq!(etag(&response), Some(read.as_str()));
+
+    let path = format!("/customer-groups/{}", family);
+    let response = platform.request_with_headers("PATCH", &path, &alice_key, Some(json!({ "name": "home" })), &[("If-Match", &read)]);
+    assert_eq!(response.status_code, 200);
+    let renamed = etag(&response).unwrap().to_string();
+    assert_ne!(renamed, read);
+
+    // bob's changes were made on the former revision
+    let response = platform.request_with_headers("PATCH", &path, &bob_key, Some(json!({ "name": "house" })), &[("If-Match", &read)]);
+    assert_eq!(response.status_code, 412);
+    let response = platform.request_with_headers("POST", &format!("{}/customers/{}", path, bob), &bob_key, None, &[("If-Match", &read)]);
+    assert_eq!(response.status_code, 412);
+    let response = platform.request_with_headers("DELETE", &format!("{}/customers/{}", path, bob), &bob_key, None, &[("If-Match", "not an etag")]);
+    assert_eq!(response.status_code, 412);
+
+    let response = platform.request_with_headers("POST", &format!("{}/customers/{}", path, bob), &bob_key, None, &[("If-Match", &renamed)]);
+    assert_eq!(response.status_code, 200);
+    let response = platform.request_with_headers("DELETE", &format!("{}/customers/{}", path, bob), &bob_key, None, &[("If-Match", etag(&response).unwrap())]);
+    assert_eq!(response.status_code, 200);
+    let response = platform.request("GET", &path, &alice_key, None);
+    assert_eq!(json(&response)["name"], "home");
+
+    let response = platform.request("GET", &format!("/customers/{}", alice), &alice_key, None);
+    assert_eq!(etag(&response), Some("\"1\""));
+}
+
+#[test]
+fn customers_only_reach_their_groups() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    let mallory = create_customer(&platform, "mallory");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family", "admins": [alice] }));
+
+    let mallory_key = customer_key(&platform, &mallory);
+    let response = platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, mallory), &mallory_key, None);
+    assert_eq!(response.status_code, 403);
+    let response = platform.request("PATCH", &format!("/customer-groups/{}", family), &mallory_key, Some(json!({ "name": "mine" })));
+    assert_eq!(response.status_code, 403);
+    let response = platform.request("GET", &format!("/customer-groups/{}", family), &mallory_key, None);
+    assert_eq!(response.status_code, 403);
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), &mallory_key, None);
+    assert_eq!(response.status_code, 403);
+    let response = platform.request("GET", &format!("/customers/{}", alice), &mallory_key, None);
+    assert_eq!(response.status_code, 403);
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", family), "unknown-key", None);
+    assert_eq!(response.status_code, 401);
+}
+
+#[test]
+fn operators_migrate_records() {
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+
+    let response = platform.request("POST", "/admin/migrations", &customer_key(&platform, &alice), None);
+    assert_eq!(response.status_code, 403);
+
+    let response = platform.request("POST", "/admin/migrations", OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    assert_eq!(json(&response), json!({ "migrated": 0, "current": 1, "failed": 0 }));
+}
+
+#[test]
+fn tenants_share_group_names() {
+    let platform = platform();
+    let mut families = Vec::new();
+    for tenant in ["acme", "globex"] {
+        platform.api_key(&format!("{}-key", tenant), &Principal {
+            id: format!("{}-ops", tenant),
+            kind: "service".to_string(),
+            roles: Some(vec![OPERATOR_ROLE.to_string()]),
+            tenant: Some(tenant.to_string()),
+        });
+        families.push(create_group(&platform, &format!("{}-key", tenant), json!({ "name": "family" })));
+    }
+
+    let body = json!({ "firstName": "alice", "lastName": "Doe", "email": "alice@example.com" });
+    let response = platform.request("POST", "/customers", "acme-key", Some(body));
+    let alice = json(&response)["id"].as_str().unwrap().to_string();
+    let response = platform.request("POST", &format!("/customer-groups/{}/customers/{}", families[0], alice), "acme-key", None);
+    assert_eq!(response.status_code, 200);
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", families[1]), "globex-key", None);
+    assert_eq!(json(&response), json!([]));
+    let response = platform.request("GET", &format!("/customer-groups/{}/customers", families[0]), "globex-key", None);
+    assert_ne!(response.status_code, 200);
+    let response = platform.request("GET", &format!("/customers/{}", alice), "globex-key", None);
+    assert_eq!(response.status_code, 404);
+    let response = platform.request("GET", &format!("/customers/{}", alice), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 404);
+}
+
+/// Pages of an export, following the `Link` headers
+fn export_pages(platform: &Platform, limit: u32) -> Vec<String> {
+    let mut pages = Vec::new();
+    let mut path = Some(format!("/admin/export?limit={}", limit));
+    while let Some(page) = path {
+        let response = platform.request("GET", &page, OPERATOR_KEY, None);
+        assert_eq!(response.status_code, 200);
+        pages.push(String::from_utf8(response.body).unwrap());
+        path = response.header.get("Link")
+            .and_then(|values| values.first())
+            .and_then(|link| link.strip_prefix('<')?.split_once('>'))
+            .map(|(next, _)| next.to_string());
+    }
+    pages
+}
+
+fn export(platform: &Platform, limit: u32) -> String {
+    export_pages(platform, limit).concat()
+}
+
+#[test]
+fn exports_are_restored_into_an_empty_store() {
+    let restored = platform();
+    let platform = platform();
+    let alice = create_customer(&platform, "alice");
+    create_customer(&platform, "bob");
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, alice), OPERATOR_KEY, None);
+    platform.keyvalue.insert(&format!("bucket:{}", family), json!({ "group": family, "balance": 40.0, "credited": 50.0 }).to_string());
+
+    let exported = export(&platform, 1);
+    let mut records: Vec<serde_json::Value> = exported.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let kinds: Vec<&str> = records.iter().map(|record| record["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["customer", "customer", "group", "member", "bucket"]);
+    let entry = json!({ "kind": "ledgerEntry", "ledgerEntry": { "group": family, "entry": {
+        "kind": "usage", "service": "voice", "bucketUnits": -10.0, "timestamp": { "sec": 1_700_000_000, "nsec": 0 },
+    } } });
+    let backup = format!("{}{}\n", exported, entry);
+
+    let response = restored.upload("/admin/restore", &customer_key(&restored, &alice), "application/x-ndjson", &backup);
+    assert_eq!(response.status_code, 403);
+    let response = restored.upload("/admin/restore", OPERATOR_KEY, "application/x-ndjson", "{\"kind\":\"customer\"}\nnot json\n");
+    assert_eq!(response.status_code, 400);
+    let response = restored.upload("/admin/restore", OPERATOR_KEY, "application/x-ndjson", &backup);
+    assert_eq!(response.status_code, 200);
+    assert_eq!(json(&response), json!({ "restored": 6 }));
+
+    // the ledger entries follow the bucket of their group
+    records.push(entry);
+    let restored_records: Vec<serde_json::Value> = export(&restored, 100).lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(restored_records, records);
+    let response = restored.request("GET", &format!("/customer-groups/{}/customers", family), OPERATOR_KEY, None);
+    assert_eq!(json(&response)[0]["id"], json!(alice));
+    let response = restored.upload("/admin/restore", OPERATOR_KEY, "application/x-ndjson", &backup);
+    assert_eq!(response.status_code, 409);
+}
+
+/// Timestamps of the ledger entries of a group in an export
+fn ledger(export: &str, group: &str) -> Vec<i64> {
+    export.lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|record| record["kind"] == "ledgerEntry" && record["ledgerEntry"]["group"] == group)
+        .map(|record| record["ledgerEntry"]["entry"]["timestamp"]["sec"].as_i64().unwrap())
+        .collect()
+}
+
+/// Appends an entry to a ledger the way the former versions did, without keeping its period
+fn push_ledger_entry(platform: &Platform, group: &str, period: &str, sec: i64) {
+    let entry = json!({ "kind": "usage", "service": "voice", "bucketUnits": -1.0, "timestamp": { "sec": sec, "nsec": 0 } });
+    platform.keyvalue.push(&format!("ledger:{}:{}", group, period), entry.to_string());
+}
+
+#[test]
+fn ledgers_written_before_their_periods_are_exported() {
+    let platform = platform();
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    let friends = create_group(&platform, OPERATOR_KEY, json!({ "name": "friends" }));
+
+    // an order of January 2024 keeps its period, the ledgers of the former versions did not
+    let vendor = json(&platform.request("POST", "/vendors", OPERATOR_KEY, Some(json!({ "name": "Telco", "services": ["voice"] }))));
+    let (id, secret) = (vendor["id"].as_str().unwrap(), vendor["secret"].as_str().unwrap());
+    let service = json!({ "id": "voice", "name": "Voice", "unit": "minute", "unitPrice": 1.0, "bucketUnit": "credit", "conversionRate": 1.0 });
+    platform.vendor_request("POST", "/services", id, secret, Some(service), &[]);
+    let order = json!({ "group": family, "amount": 10.0, "timestamp": { "sec": 1_704_067_200, "nsec": 0 } });
+    let response = platform.vendor_request("POST", "/services/voice/orders", id, secret, Some(order), &[]);
+    assert_eq!(response.status_code, 200);
+    push_ledger_entry(&platform, &family, "2023-11", 1_700_000_000);
+    push_ledger_entry(&platform, &family, "2023-12", 1_702_000_000);
+    platform.keyvalue.insert(&format!("bucket:{}", friends), json!({ "group": friends, "balance": 40.0, "credited": 50.0 }).to_string());
+    push_ledger_entry(&platform, &friends, "2023-11", 1_700_000_000);
+
+    // exporting writes nothing : the ledgers are looked for by the migration
+    let exported = export(&platform, 100);
+    assert_eq!(ledger(&exported, &family), [1_704_067_200]);
+    assert!(platform.keyvalue.value(&format!("ledger_periods:{}", friends)).is_none());
+
+    // up to the latest period kept
+    let response = platform.request("POST", "/admin/migrations", OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    let exported = export(&platform, 100);
+    assert_eq!(ledger(&exported, &family), [1_700_000_000, 1_702_000_000, 1_704_067_200]);
+    assert!(ledger(&exported, &friends).is_empty());
+
+    // or up to the one set by the operator
+    platform.keyvalue.insert("config:ledger_end", "2023-12".to_string());
+    platform.request("POST", "/admin/migrations", OPERATOR_KEY, None);
+    let exported = export(&platform, 100);
+    assert_eq!(ledger(&exported, &family), [1_700_000_000, 1_702_000_000, 1_704_067_200]);
+    assert_eq!(ledger(&exported, &friends), [1_700_000_000]);
+}
+
+#[test]
+fn long_ledgers_are_exported_in_pages() {
+    let platform = platform();
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    let friends = create_group(&platform, OPERATOR_KEY, json!({ "name": "friends" }));
+    platform.keyvalue.insert("config:ledger_end", "2023-12".to_string());
+    for group in [&family, &friends] {
+        platform.keyvalue.insert(&format!("bucket:{}", group), json!({ "group": group, "balance": 40.0, "credited": 50.0 }).to_string());
+        for n in 0..700 {
+            push_ledger_entry(&platform, group, "2023-11", 1_700_000_000 + n);
+        }
+        push_ledger_entry(&platform, group, "2023-12", 1_702_000_000);
+    }
+    platform.request("POST", "/admin/migrations", OPERATOR_KEY, None);
+
+    let pages = export_pages(&platform, 100);
+
+    // 1000 entries a page, the ledger of a group going on in the next one
+    let entries: Vec<usize> = pages.iter().map(|page| page.lines().filter(|line| line.contains("\"ledgerEntry\"")).count()).collect();
+    assert_eq!(entries, [1000, 402]);
+    let exported = pages.concat();
+    let expected: Vec<i64> = (0..700).map(|n| 1_700_000_000 + n).chain([1_702_000_000]).collect();
+    assert_eq!((ledger(&exported, &family), ledger(&exported, &friends)), (expected.clone(), expected));
+    let response = platform.request("GET", "/admin/export?ledger=2023-11:0", OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 400);
+}
+
+#[test]
+fn members_of_a_group_span_the_pages() {
+    let platform = platform();
+    let family = create_group(&platform, OPERATOR_KEY, json!({ "name": "family" }));
+    for name in ["alice", "bob", "carol"] {
+        let customer = create_customer(&platform, name);
+        platform.request("POST", &format!("/customer-groups/{}/customers/{}", family, customer), OPERATOR_KEY, None);
+    }
+    platform.keyvalue.insert(&format!("bucket:{}", family), json!({ "group": family, "balance": 40.0, "credited": 50.0 }).to_string());
+
+    // the bucket follows the last member of the group
+    let kinds: Vec<Vec<String>> = export_pages(&platform, 2).iter()
+        .map(|page| page.lines().map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["kind"].as_str().unwrap().to_string()).collect())
+        .collect();
+    assert_eq!(kinds, [vec!["customer", "customer"], vec!["customer", "group"], vec!["member", "member"], vec!["member", "bucket"]]);
+    assert_eq!(export(&platform, 2), export(&platform, 100));
+}
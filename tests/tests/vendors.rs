@@ -0,0 +1,466 @@
+use integration_tests::{json, Platform};
+use serde_json::json;
+use shared_bucket::policy::OPERATOR_ROLE;
+use shared_bucket::Principal;
+use wasmcloud_interface_httpserver::HttpResponse;
+
+const OPERATOR_KEY: &str = "operator-key";
+
+/// January 2024
+const TIMESTAMP: i64 = 1_704_067_200;
+
+fn platform() -> Platform {
+    let platform = Platform::default();
+    platform.api_key(OPERATOR_KEY, &Principal {
+        id: "ops".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![OPERATOR_ROLE.to_string()]),
+        tenant: None,
+    });
+    platform
+}
+
+/// Registers a vendor and the catalog entry of its service, sold at 1 credit a unit.
+/// Returns the vendor id and secret.
+fn vendor(platform: &Platform, name: &str, service: &str) -> (String, String) {
+    tenant_vendor(platform, OPERATOR_KEY, name, service)
+}
+
+/// Registers a vendor of the tenant of the operator holding the API key
+fn tenant_vendor(platform: &Platform, api_key: &str, name: &str, service: &str) -> (String, String) {
+    let response = platform.request("POST", "/vendors", api_key, Some(json!({ "name": name, "services": [service] })));
+    assert_eq!(response.status_code, 200);
+    let vendor = json(&response);
+    let (id, secret) = (vendor["id"].as_str().unwrap().to_string(), vendor["secret"].as_str().unwrap().to_string());
+
+    let body = json!({ "id": service, "name": service, "unit": "unit", "unitPrice": 1.0, "bucketUnit": "credit", "conversionRate": 1.0 });
+    let response = platform.vendor_request("POST", "/services", &id, &secret, Some(body), &[]);
+    assert_eq!(response.status_code, 200);
+
+    (id, secret)
+}
+
+/// Creates a group of the tenant of the API key, returns its id
+fn group(platform: &Platform, api_key: &str, name: &str) -> String {
+    let response = platform.request("POST", "/customer-groups", api_key, Some(json!({ "name": name })));
+    assert_eq!(response.status_code, 200);
+    json(&response)["id"].as_str().unwrap().to_string()
+}
+
+fn order(group: &str, amount: f64) -> serde_json::Value {
+    json!({ "group": group, "amount": amount, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } })
+}
+
+fn usage(group: &str, units: f64) -> serde_json::Value {
+    json!({ "client": "alice", "group": group, "units": units, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } })
+}
+
+fn refund(amount: Option<f64>, reason: Option<&str>) -> serde_json::Value {
+    json!({ "amount": amount, "reason": reason, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } })
+}
+
+/// Balance of the bucket of a group
+fn balance(platform: &Platform, group: &str) -> f64 {
+    let bucket: serde_json::Value = serde_json::from_str(&platform.keyvalue.value(&format!("bucket:{}", group)).unwrap()).unwrap();
+    bucket["balance"].as_f64().unwrap()
+}
+
+/// Kind and bucket units of the ledger entries of a group in January 2024
+fn ledger(platform: &Platform, group: &str) -> Vec<(String, f64)> {
+    platform.keyvalue.list(&format!("ledger:{}:2024-01", group)).iter()
+        .map(|value| serde_json::from_str::<serde_json::Value>(value).unwrap())
+        .map(|entry| (entry["kind"].as_str().unwrap().to_string(), entry["bucketUnits"].as_f64().unwrap()))
+        .collect()
+}
+
+fn entries(expected: &[(&str, f64)]) -> Vec<(String, f64)> {
+    expected.iter().map(|(kind, units)| (kind.to_string(), *units)).collect()
+}
+
+#[test]
+fn operators_register_vendors() {
+    let platform = platform();
+
+    let response = platform.request("POST", "/vendors", OPERATOR_KEY, Some(json!({ "name": "Telco", "services": ["voice"] })));
+    assert_eq!(response.status_code, 200);
+    let vendor = json(&response);
+    assert!(vendor["secret"].is_string());
+    assert_eq!(platform.keyvalue.value("service_owner:voice"), vendor["id"].as_str().map(String::from));
+
+    let response = platform.request("POST", "/vendors", OPERATOR_KEY, Some(json!({ "name": "Other", "services": ["voice"] })));
+    assert_eq!(response.status_code, 400);
+
+    let response = platform.request("GET", "/services", OPERATOR_KEY, None);
+    assert_eq!(json(&response), json!([]));
+}
+
+#[test]
+fn vendors_only_use_the_services_they_own() {
+    let platform = platform();
+    let (telco, telco_secret) = vendor(&platform, "Telco", "voice");
+    let (texter, texter_secret) = vendor(&platform, "Texter", "sms");
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &telco_secret, Some(order("family", 10.0)), &[]);
+    let refunds = format!("/services/voice/orders/{}/refunds", json(&response)["id"].as_str().unwrap());
+
+    let response = platform.vendor_request("POST", "/services/voice/orders", &texter, &texter_secret, Some(order("family", 10.0)), &[]);
+    assert_eq!(response.status_code, 403);
+    let response = platform.vendor_request("POST", "/services/voice/authorizations", &texter, &texter_secret, Some(usage("family", 1.0)), &[]);
+    assert_eq!(response.status_code, 403);
+    let response = platform.vendor_request("POST", &refunds, &texter, &texter_secret, Some(refund(None, None)), &[]);
+    assert_eq!(response.status_code, 403);
+    let response = platform.vendor_request("DELETE", "/services/voice", &texter, &texter_secret, None, &[]);
+    assert_eq!(response.status_code, 403);
+
+    assert_eq!(balance(&platform, "family"), 10.0);
+    assert_eq!(ledger(&platform, "family"), entries(&[("order", 10.0)]));
+}
+
+#[test]
+fn vendors_with_bad_credentials_are_refused() {
+    let platform = platform();
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, "not-the-secret", Some(order("family", 10.0)), &[]);
+    assert_eq!(response.status_code, 403);
+    let response = platform.vendor_request("POST", "/services/voice/orders", "unknown", &secret, Some(order("family", 10.0)), &[]);
+    assert_eq!(response.status_code, 403);
+
+    for authorization in ["Basic not-base64", "Bearer token", &format!("Basic {}", base64::encode(&telco))] {
+        let response = platform.request_with_headers("POST", "/services/voice/orders", "", Some(order("family", 10.0)), &[("Authorization", authorization)]);
+        assert_eq!(response.status_code, 401, "{}", authorization);
+    }
+    let response = platform.request("POST", "/services/voice/orders", OPERATOR_KEY, Some(order("family", 10.0)));
+    assert_eq!(response.status_code, 401);
+
+    assert_eq!(platform.keyvalue.value("bucket:family"), None);
+}
+
+#[test]
+fn idempotency_keys_are_scoped_to_the_vendor() {
+    let platform = platform();
+    let (telco, telco_secret) = vendor(&platform, "Telco", "voice");
+    let (sms, sms_secret) = vendor(&platform, "Texter", "sms");
+    let headers = [("Idempotency-Key", "order-1")];
+
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &telco_secret, Some(order("family", 10.0)), &headers);
+    assert_eq!(response.status_code, 200);
+    let voice_order = json(&response);
+    let response = platform.vendor_request("POST", "/services/sms/orders", &sms, &sms_secret, Some(order("family", 5.0)), &headers);
+    assert_eq!(response.status_code, 200);
+    let sms_order = json(&response);
+
+    assert_ne!(voice_order["id"], sms_order["id"]);
+    assert_eq!(voice_order["balance"], 10.0);
+    assert_eq!(sms_order["balance"], 15.0);
+
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &telco_secret, Some(order("family", 10.0)), &headers);
+    assert_eq!(json(&response), voice_order);
+}
+
+#[test]
+fn orders_are_taxed_at_the_location_of_a_tenant_customer() {
+    let platform = platform();
+    platform.keyvalue.insert("config:tax_rules", json!([{ "country": "*", "rate": 20.0 }, { "country": "FR", "rate": 5.5 }]).to_string());
+    platform.api_key("acme-key", &Principal {
+        id: "acme-ops".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![OPERATOR_ROLE.to_string()]),
+        tenant: Some("acme".to_string()),
+    });
+    let body = json!({ "firstName": "alice", "lastName": "Doe", "email": "alice@example.com", "country": "FR" });
+    let response = platform.request("POST", "/customers", "acme-key", Some(body));
+    assert_eq!(response.status_code, 200);
+    let alice = json(&response)["id"].clone();
+    let (telco, secret) = tenant_vendor(&platform, "acme-key", "Telco", "voice");
+
+    // the tenant is the one of the vendor, the body may not choose another one
+    let body = json!({ "group": "family", "amount": 10.0, "timestamp": { "sec": TIMESTAMP, "nsec": 0 }, "customer": alice, "tenant": "globex" });
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(body), &[]);
+    assert_eq!(response.status_code, 200);
+    let order = json(&response);
+    assert_eq!((order["price"].as_f64(), order["taxes"].as_f64()), (Some(10.0), Some(0.55)));
+
+    // outside its tenant the customer is unknown : the order is refused rather than taxed at the `*` rule
+    let (platform_telco, platform_secret) = vendor(&platform, "Platform Telco", "sms");
+    let body = json!({ "group": "family", "amount": 10.0, "timestamp": { "sec": TIMESTAMP, "nsec": 0 }, "customer": alice });
+    let response = platform.vendor_request("POST", "/services/sms/orders", &platform_telco, &platform_secret, Some(body), &[]);
+    assert_eq!(response.status_code, 400);
+    assert!(platform.keyvalue.value("bucket:family").is_none());
+    assert!(platform.keyvalue.value("tenant:acme:bucket:family").is_some());
+}
+
+#[test]
+fn operators_only_reach_the_groups_of_their_tenant() {
+    let platform = platform();
+    for tenant in ["acme", "globex"] {
+        platform.api_key(&format!("{}-key", tenant), &Principal {
+            id: format!("{}-ops", tenant),
+            kind: "service".to_string(),
+            roles: Some(vec![OPERATOR_ROLE.to_string()]),
+            tenant: Some(tenant.to_string()),
+        });
+    }
+    let family = group(&platform, "acme-key", "family");
+    let transfers = format!("/customer-groups/{}/transfers", family);
+
+    assert_eq!(platform.request("GET", &transfers, "acme-key", None).status_code, 200);
+    for api_key in ["globex-key", OPERATOR_KEY] {
+        assert_eq!(platform.request("GET", &transfers, api_key, None).status_code, 404, "{}", api_key);
+        let body = json!({ "url": "https://example.com/hooks", "secret": "s3cret", "eventTypes": ["order.confirmed"] });
+        let response = platform.request("POST", &format!("/customer-groups/{}/webhooks", family), api_key, Some(body));
+        assert_eq!(response.status_code, 404, "{}", api_key);
+    }
+}
+
+#[test]
+fn orders_are_refunded_in_part() {
+    let platform = platform();
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order("family", 10.0)), &[]);
+    let path = format!("/services/voice/orders/{}/refunds", json(&response)["id"].as_str().unwrap());
+
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(4.0), None)), &[]);
+    assert_eq!(json(&response), json!({ "success": true, "refunded": 4.0, "balance": 6.0 }));
+
+    // 6 units are left to refund
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(7.0), None)), &[]);
+    assert_eq!(response.status_code, 400);
+    assert_eq!(balance(&platform, "family"), 6.0);
+
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(None, None)), &[]);
+    assert_eq!(json(&response), json!({ "success": true, "refunded": 6.0, "balance": 0.0 }));
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(None, None)), &[]);
+    assert_eq!(response.status_code, 400);
+
+    assert_eq!(ledger(&platform, "family"), entries(&[("order", 10.0), ("refund", 4.0), ("refund", 6.0)]));
+}
+
+#[test]
+fn consumed_units_are_only_charged_back() {
+    let platform = platform();
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order("family", 10.0)), &[]);
+    let path = format!("/services/voice/orders/{}/refunds", json(&response)["id"].as_str().unwrap());
+    let response = platform.vendor_request("POST", "/services/voice/authorizations", &telco, &secret, Some(usage("family", 8.0)), &[]);
+    assert_eq!(json(&response)["authorized"], true);
+
+    // refunds stop at the units left in the bucket
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(5.0), None)), &[]);
+    assert_eq!(json(&response), json!({ "success": true, "refunded": 2.0, "balance": 0.0 }));
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(1.0), None)), &[]);
+    assert_eq!(response.status_code, 400);
+
+    // chargebacks take the consumed units back, the bucket going negative
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(3.0), Some("chargeback"))), &[]);
+    assert_eq!(json(&response), json!({ "success": true, "refunded": 3.0, "balance": -3.0 }));
+
+    assert_eq!(ledger(&platform, "family"), entries(&[("order", 10.0), ("usage", 8.0), ("refund", 2.0), ("refund", 3.0)]));
+}
+
+#[test]
+fn usage_of_unknown_members_is_invoiced_at_the_default_rate() {
+    let platform = platform();
+    platform.keyvalue.insert("config:tax_rules", json!([{ "country": "*", "rate": 20.0 }, { "country": "FR", "rate": 5.5 }]).to_string());
+    let body = json!({ "firstName": "bob", "lastName": "Doe", "email": "bob@example.com", "country": "FR" });
+    let bob = json(&platform.request("POST", "/customers", OPERATOR_KEY, Some(body)))["id"].as_str().unwrap().to_string();
+    let family = group(&platform, OPERATOR_KEY, "family");
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 20.0)), &[]);
+    let bob_usage = json!({ "client": bob, "group": family, "units": 10.0, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } });
+    for body in [usage(&family, 10.0), bob_usage] {
+        let response = platform.vendor_request("POST", "/services/voice/authorizations", &telco, &secret, Some(body), &[]);
+        assert_eq!(json(&response)["authorized"], true);
+    }
+
+    // alice is not a customer : her line is flagged rather than failing the invoice
+    let response = platform.request("POST", &format!("/customer-groups/{}/invoices/2024-01", family), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    let lines: Vec<_> = json(&response)["lines"].as_array().unwrap().iter()
+        .map(|line| (line["member"].as_str().unwrap().to_string(), line["taxes"].as_f64().unwrap(), line["unknownMember"].as_bool().unwrap()))
+        .collect();
+    assert_eq!(lines, vec![(bob, 0.55, false), ("alice".to_string(), 2.0, true)]);
+}
+
+#[test]
+fn consumed_units_are_refunded_with_the_negative_policy() {
+    let platform = platform();
+    platform.keyvalue.insert("config:refund_policy", "negative".to_string());
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order("family", 10.0)), &[]);
+    let path = format!("/services/voice/orders/{}/refunds", json(&response)["id"].as_str().unwrap());
+    platform.vendor_request("POST", "/services/voice/authorizations", &telco, &secret, Some(usage("family", 8.0)), &[]);
+
+    let response = platform.vendor_request("POST", &path, &telco, &secret, Some(refund(Some(5.0), None)), &[]);
+    assert_eq!(json(&response), json!({ "success": true, "refunded": 5.0, "balance": -3.0 }));
+    assert_eq!(balance(&platform, "family"), -3.0);
+
+    assert_eq!(ledger(&platform, "family"), entries(&[("order", 10.0), ("usage", 8.0), ("refund", 5.0)]));
+}
+
+fn transfer(platform: &Platform, from: &str, to: &str, amount: f64) -> HttpResponse {
+    let body = json!({ "to": to, "amount": amount, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } });
+    platform.request("POST", &format!("/customer-groups/{}/transfers", from), OPERATOR_KEY, Some(body))
+}
+
+#[test]
+fn transfers_are_rolled_back_when_a_step_fails() {
+    // debit, credit, then the entries of both ledgers and their periods
+    let steps = ["bucket:{family}", "bucket:{friends}", "ledger:{family}:2024-01", "ledger:{friends}:2024-01", "ledger_periods:{friends}"];
+    for step in steps {
+        let platform = platform();
+        let (family, friends) = (group(&platform, OPERATOR_KEY, "family"), group(&platform, OPERATOR_KEY, "friends"));
+        let step = step.replace("{family}", &family).replace("{friends}", &friends);
+        let (telco, secret) = vendor(&platform, "Telco", "voice");
+        platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+        platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&friends, 5.0)), &[]);
+
+        platform.keyvalue.fail_next_write(&step);
+        assert_eq!(transfer(&platform, &family, &friends, 4.0).status_code, 400, "{}", step);
+
+        assert_eq!((balance(&platform, &family), balance(&platform, &friends)), (10.0, 5.0), "{}", step);
+        assert_eq!(ledger(&platform, &family), entries(&[("order", 10.0)]), "{}", step);
+        assert_eq!(ledger(&platform, &friends), entries(&[("order", 5.0)]), "{}", step);
+        let transfers = json(&platform.request("GET", &format!("/customer-groups/{}/transfers", family), OPERATOR_KEY, None));
+        assert_eq!(transfers[0]["status"], "failed", "{}", step);
+
+        assert_eq!(transfer(&platform, &family, &friends, 4.0).status_code, 200, "{}", step);
+        assert_eq!((balance(&platform, &family), balance(&platform, &friends)), (6.0, 9.0), "{}", step);
+        assert_eq!(ledger(&platform, &family), entries(&[("order", 10.0), ("transfer_out", 4.0)]), "{}", step);
+        assert_eq!(ledger(&platform, &friends), entries(&[("order", 5.0), ("transfer_in", 4.0)]), "{}", step);
+    }
+}
+
+#[test]
+fn transfers_are_rolled_back_when_a_compensation_fails() {
+    let platform = platform();
+    let (family, friends) = (group(&platform, OPERATOR_KEY, "family"), group(&platform, OPERATOR_KEY, "friends"));
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&friends, 5.0)), &[]);
+
+    // the credit fails, then restoring the credited bucket : the other steps are still compensated
+    platform.keyvalue.fail_next_write(&format!("bucket:{}", friends));
+    platform.keyvalue.fail_next_write(&format!("bucket:{}", friends));
+    assert_eq!(transfer(&platform, &family, &friends, 4.0).status_code, 400);
+
+    assert_eq!((balance(&platform, &family), balance(&platform, &friends)), (10.0, 5.0));
+    let transfers = json(&platform.request("GET", &format!("/customer-groups/{}/transfers", family), OPERATOR_KEY, None));
+    assert_eq!(transfers[0]["status"], "failed");
+}
+
+#[test]
+fn units_are_only_transferred_to_a_group_of_the_tenant() {
+    let platform = platform();
+    platform.api_key("acme-key", &Principal {
+        id: "acme-ops".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![OPERATOR_ROLE.to_string()]),
+        tenant: Some("acme".to_string()),
+    });
+    let family = group(&platform, OPERATOR_KEY, "family");
+    let acme = group(&platform, "acme-key", "family");
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+
+    for to in ["unknown", acme.as_str()] {
+        assert_eq!(transfer(&platform, &family, to, 4.0).status_code, 400, "{}", to);
+    }
+    assert_eq!(balance(&platform, &family), 10.0);
+    let transfers = json(&platform.request("GET", &format!("/customer-groups/{}/transfers", family), OPERATOR_KEY, None));
+    assert_eq!(transfers, json!([]));
+}
+
+#[test]
+fn entries_of_unfinished_transfers_are_not_exported() {
+    let platform = platform();
+    let family = group(&platform, OPERATOR_KEY, "family");
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+
+    // a transfer interrupted once its entries were recorded, before it completed
+    let transfer = json!({ "id": "t1", "from": family, "to": "friends", "amount": 4.0, "timestamp": { "sec": TIMESTAMP, "nsec": 0 }, "status": "pending" });
+    platform.keyvalue.insert("transfer:t1", transfer.to_string());
+    let entry = json!({ "kind": "transfer_out", "service": "", "transfer": "t1", "bucketUnits": 4.0, "timestamp": { "sec": TIMESTAMP, "nsec": 0 } });
+    platform.keyvalue.push(&format!("ledger:{}:2024-01", family), entry.to_string());
+
+    let response = platform.request("GET", "/admin/export", OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    let kinds: Vec<String> = std::str::from_utf8(&response.body).unwrap().lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter_map(|record| record["ledgerEntry"]["entry"]["kind"].as_str().map(String::from))
+        .collect();
+    assert_eq!(kinds, vec!["order"]);
+}
+
+/// Ids of the thresholds reached so far
+fn thresholds_reached(platform: &Platform) -> Vec<String> {
+    platform.messages.published().iter()
+        .filter(|message| message.subject == "sharedbucket.events.bucket.threshold_reached")
+        .map(|message| serde_json::from_slice::<serde_json::Value>(&message.body).unwrap())
+        .map(|event| event["threshold"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Replaces the thresholds of a group through the API
+fn set_thresholds(platform: &Platform, group: &str, thresholds: serde_json::Value) {
+    let response = platform.request("PUT", &format!("/customer-groups/{}/thresholds", group), OPERATOR_KEY, Some(thresholds));
+    assert_eq!(response.status_code, 200);
+}
+
+#[test]
+fn thresholds_fire_once_per_period() {
+    let platform = platform();
+    let family = group(&platform, OPERATOR_KEY, "family");
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+    set_thresholds(&platform, &family, json!([{ "id": "half", "usedPercentage": 50.0 }]));
+    let authorize = |usage| {
+        let response = platform.vendor_request("POST", "/services/voice/authorizations", &telco, &secret, Some(usage), &[]);
+        assert_eq!(json(&response)["authorized"], true);
+    };
+
+    authorize(usage(&family, 4.0));
+    assert!(thresholds_reached(&platform).is_empty());
+    authorize(usage(&family, 2.0));
+    assert_eq!(thresholds_reached(&platform), vec!["half"]);
+
+    // crossed again in the same period, by the same actor then by a new one
+    authorize(usage(&family, 1.0));
+    platform.restart_service_vendors();
+    authorize(usage(&family, 1.0));
+    assert_eq!(thresholds_reached(&platform), vec!["half"]);
+
+    // February 2024, then a late usage of January
+    authorize(json!({ "client": "alice", "group": family, "units": 0.5, "timestamp": { "sec": TIMESTAMP + 31 * 86_400, "nsec": 0 } }));
+    assert_eq!(thresholds_reached(&platform), vec!["half", "half"]);
+    authorize(usage(&family, 0.5));
+    assert_eq!(thresholds_reached(&platform), vec!["half", "half"]);
+
+    // the thresholds kept are not fired again by replacing them
+    set_thresholds(&platform, &family, json!([{ "id": "half", "usedPercentage": 50.0 }, { "id": "low", "remaining": 5.0 }]));
+    let response = platform.request("GET", &format!("/customer-groups/{}/thresholds", family), OPERATOR_KEY, None);
+    let fired: Vec<_> = json(&response)["thresholds"].as_array().unwrap().iter()
+        .map(|threshold| (threshold["id"].as_str().unwrap().to_string(), threshold["firedPeriod"].as_str().map(String::from)))
+        .collect();
+    assert_eq!(fired, vec![("half".to_string(), Some("2024-02".to_string())), ("low".to_string(), None)]);
+}
+
+#[test]
+fn thresholds_fire_on_refunds_and_transfers() {
+    let platform = platform();
+    let (family, friends) = (group(&platform, OPERATOR_KEY, "family"), group(&platform, OPERATOR_KEY, "friends"));
+    let (telco, secret) = vendor(&platform, "Telco", "voice");
+    let response = platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+    let refunds = format!("/services/voice/orders/{}/refunds", json(&response)["id"].as_str().unwrap());
+    platform.vendor_request("POST", "/services/voice/orders", &telco, &secret, Some(order(&family, 10.0)), &[]);
+    set_thresholds(&platform, &family, json!([{ "id": "low", "remaining": 15.0 }, { "id": "empty", "remaining": 10.0 }]));
+
+    let response = platform.vendor_request("POST", &refunds, &telco, &secret, Some(refund(Some(5.0), None)), &[]);
+    assert_eq!(response.status_code, 200);
+    assert_eq!(thresholds_reached(&platform), vec!["low"]);
+
+    // the transfer only fires the thresholds of the debited bucket
+    set_thresholds(&platform, &friends, json!([{ "id": "friends", "remaining": 100.0 }]));
+    assert_eq!(transfer(&platform, &family, &friends, 5.0).status_code, 200);
+    assert_eq!(thresholds_reached(&platform), vec!["low", "empty"]);
+}
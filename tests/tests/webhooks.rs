@@ -0,0 +1,243 @@
+use hmac::{Hmac, Mac};
+use integration_tests::{json, Platform};
+use serde_json::json;
+use sha2::Sha256;
+use shared_bucket::policy::OPERATOR_ROLE;
+use shared_bucket::Principal;
+use wasmcloud_interface_httpclient::HttpRequest;
+
+const OPERATOR_KEY: &str = "operator-key";
+
+const URL: &str = "https://example.com/hooks";
+
+struct Vendor {
+    id: String,
+    secret: String,
+}
+
+/// Platform with a vendor of the `voice` service and the family group, returns the group id
+fn platform() -> (Platform, Vendor, String) {
+    let platform = Platform::default();
+    platform.api_key(OPERATOR_KEY, &Principal {
+        id: "ops".to_string(),
+        kind: "service".to_string(),
+        roles: Some(vec![OPERATOR_ROLE.to_string()]),
+        tenant: None,
+    });
+
+    let response = platform.request("POST", "/vendors", OPERATOR_KEY, Some(json!({ "name": "Telco", "services": ["voice"] })));
+    let vendor = json(&response);
+    let vendor = Vendor { id: vendor["id"].as_str().unwrap().to_string(), secret: vendor["secret"].as_str().unwrap().to_string() };
+    let body = json!({ "id": "voice", "name": "Voice", "unit": "minute", "unitPrice": 1.0, "bucketUnit": "credit", "conversionRate": 1.0 });
+    platform.vendor_request("POST", "/services", &vendor.id, &vendor.secret, Some(body), &[]);
+    let response = platform.request("POST", "/customer-groups", OPERATOR_KEY, Some(json!({ "name": "family" })));
+    let family = json(&response)["id"].as_str().unwrap().to_string();
+
+    (platform, vendor, family)
+}
+
+/// Registers a webhook of the orders confirmed for the family group, returns its id
+fn register(platform: &Platform, family: &str) -> String {
+    let body = json!({ "url": URL, "secret": "s3cret", "eventTypes": ["order.confirmed"] });
+    let response = platform.request("POST", &format!("/customer-groups/{}/webhooks", family), OPERATOR_KEY, Some(body));
+    assert_eq!(response.status_code, 200);
+
+    json(&response)["id"].as_str().unwrap().to_string()
+}
+
+/// Orders units for the family group, publishing an `order.confirmed` and handing it to the webhooks
+fn order(platform: &Platform, vendor: &Vendor, family: &str) {
+    let body = json!({ "group": family, "amount": 10.0, "timestamp": { "sec": 1_704_067_200, "nsec": 0 } });
+    let response = platform.vendor_request("POST", "/services/voice/orders", &vendor.id, &vendor.secret, Some(body), &[]);
+    assert_eq!(response.status_code, 200);
+    platform.deliver_events();
+}
+
+/// Attempts and statuses of the delivery log of a webhook
+fn deliveries(platform: &Platform, family: &str, webhook: &str) -> Vec<(u64, String)> {
+    let response = platform.request("GET", &format!("/customer-groups/{}/webhooks/{}/deliveries", family, webhook), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+
+    json(&response).as_array().unwrap().iter()
+        .map(|delivery| (delivery["attempt"].as_u64().unwrap(), delivery["status"].as_str().unwrap().to_string()))
+        .collect()
+}
+
+/// Creates a customer and an API key of theirs, returns both
+fn customer(platform: &Platform, first_name: &str) -> (String, String) {
+    let body = json!({ "firstName": first_name, "lastName": "Doe", "email": format!("{}@example.com", first_name) });
+    let response = platform.request("POST", "/customers", OPERATOR_KEY, Some(body));
+    let id = json(&response)["id"].as_str().unwrap().to_string();
+    let key = format!("{}-key", id);
+    platform.api_key(&key, &Principal { id: id.clone(), kind: "user".to_string(), roles: None, tenant: None });
+
+    (id, key)
+}
+
+fn header<'a>(request: &'a HttpRequest, name: &str) -> &'a str {
+    &request.headers[name][0]
+}
+
+/// Expected signature of a request, of its timestamp and body
+fn signature(request: &HttpRequest) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+    mac.update(format!("{}.", header(request, "X-SharedBucket-Timestamp")).as_bytes());
+    mac.update(&request.body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[test]
+fn events_are_delivered_signed() {
+    let (platform, vendor, family) = platform();
+    let webhook = register(&platform, &family);
+    platform.tick_webhooks_at(1_704_067_260);
+
+    order(&platform, &vendor, &family);
+
+    let requests = platform.requests.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!((requests[0].method.as_str(), requests[0].url.as_str()), ("POST", URL));
+    assert_eq!(header(&requests[0], "X-SharedBucket-Event"), "order.confirmed");
+    assert_eq!(header(&requests[0], "X-SharedBucket-Timestamp"), "1704067260");
+    assert_eq!(header(&requests[0], "X-SharedBucket-Signature"), signature(&requests[0]));
+    let event: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!((event["eventType"].as_str(), event["group"].as_str()), (Some("order.confirmed"), Some(family.as_str())));
+
+    assert_eq!(deliveries(&platform, &family, &webhook), vec![(1, "delivered".to_string())]);
+}
+
+#[test]
+fn retries_are_signed_at_their_tick() {
+    let (platform, vendor, family) = platform();
+    register(&platform, &family);
+    platform.tick_webhooks_at(1_704_067_260);
+    platform.requests.answer_with(500);
+
+    order(&platform, &vendor, &family);
+    platform.tick_webhooks_at(1_704_067_320);
+
+    let requests = platform.requests.requests();
+    let timestamps: Vec<&str> = requests.iter().map(|request| header(request, "X-SharedBucket-Timestamp")).collect();
+    assert_eq!(timestamps, ["1704067260", "1704067320"]);
+    assert!(requests.iter().all(|request| header(request, "X-SharedBucket-Signature") == signature(request)));
+    assert_ne!(header(&requests[0], "X-SharedBucket-Signature"), header(&requests[1], "X-SharedBucket-Signature"));
+}
+
+#[test]
+fn webhooks_of_internal_hosts_are_refused() {
+    let (platform, _, family) = platform();
+    let urls = [
+        "http://localhost:8080/hooks", "http://api.localhost/hooks", "http://127.0.0.1/hooks", "http://2130706433/hooks",
+        "http://10.0.0.1/hooks", "http://172.16.5.4/hooks", "http://192.168.1.1/hooks", "http://169.254.169.254/latest/meta-data",
+        "http://0.0.0.0/hooks", "http://[::1]/hooks", "http://[fe80::1]/hooks", "http://[fd00::1]/hooks", "http://[::ffff:127.0.0.1]/hooks",
+        "ftp://example.com/hooks", "not a url",
+    ];
+    for url in urls {
+        let body = json!({ "url": url, "secret": "s3cret", "eventTypes": ["order.confirmed"] });
+        let response = platform.request("POST", &format!("/customer-groups/{}/webhooks", family), OPERATOR_KEY, Some(body));
+        assert_eq!(response.status_code, 400, "{}", url);
+    }
+
+    for url in ["https://example.com/hooks", "http://93.184.216.34/hooks", "https://[2606:2800:220:1::]/hooks"] {
+        let body = json!({ "url": url, "secret": "s3cret" });
+        let response = platform.request("POST", &format!("/customer-groups/{}/webhooks", family), OPERATOR_KEY, Some(body));
+        assert_eq!(response.status_code, 200, "{}", url);
+    }
+}
+
+#[test]
+fn failed_deliveries_are_retried_with_backoff() {
+    let (platform, vendor, family) = platform();
+    let webhook = register(&platform, &family);
+    platform.requests.answer_with(500);
+
+    // attempts at the ticks 0, 1 and 3
+    order(&platform, &vendor, &family);
+    for _ in 0..3 {
+        platform.tick_webhooks();
+    }
+    assert_eq!(platform.requests.requests().len(), 3);
+
+    platform.requests.answer_with(200);
+    for _ in 0..4 {
+        platform.tick_webhooks();
+    }
+    let requests = platform.requests.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests.iter().all(|request| header(request, "X-SharedBucket-Delivery") == header(&requests[0], "X-SharedBucket-Delivery")));
+
+    let retrying = |attempt| (attempt, "retrying".to_string());
+    assert_eq!(deliveries(&platform, &family, &webhook), vec![retrying(1), retrying(2), retrying(3), (4, "delivered".to_string())]);
+
+    for _ in 0..20 {
+        platform.tick_webhooks();
+    }
+    assert_eq!(platform.requests.requests().len(), 4);
+}
+
+#[test]
+fn deliveries_fail_after_the_last_attempt() {
+    let (platform, vendor, family) = platform();
+    let webhook = register(&platform, &family);
+    platform.requests.answer_with(503);
+
+    order(&platform, &vendor, &family);
+    for _ in 0..40 {
+        platform.tick_webhooks();
+    }
+
+    assert_eq!(platform.requests.requests().len(), 6);
+    let log = deliveries(&platform, &family, &webhook);
+    assert_eq!(log.last(), Some(&(6, "failed".to_string())));
+    assert!(log[..5].iter().all(|(_, status)| status == "retrying"));
+}
+
+#[test]
+fn the_delivery_log_keeps_the_latest_attempts() {
+    let (platform, vendor, family) = platform();
+    let webhook = register(&platform, &family);
+
+    for _ in 0..webhooks::MAX_LOGGED_ATTEMPTS + 5 {
+        order(&platform, &vendor, &family);
+    }
+
+    let response = platform.request("GET", &format!("/customer-groups/{}/webhooks/{}/deliveries", family, webhook), OPERATOR_KEY, None);
+    let logged: Vec<String> = json(&response).as_array().unwrap().iter().map(|delivery| delivery["event"].as_str().unwrap().to_string()).collect();
+    let delivered: Vec<String> = platform.requests.requests().iter()
+        .map(|request| serde_json::from_slice::<serde_json::Value>(&request.body).unwrap()["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(logged.len(), webhooks::MAX_LOGGED_ATTEMPTS as usize);
+    assert_eq!(logged, delivered[5..]);
+
+    let response = platform.request("DELETE", &format!("/customer-groups/{}/webhooks/{}", family, webhook), OPERATOR_KEY, None);
+    assert_eq!(response.status_code, 200);
+    assert!(platform.keyvalue.list(&format!("webhook_deliveries:{}", webhook)).is_empty());
+}
+
+#[test]
+fn webhooks_are_managed_by_the_group_admins() {
+    let (platform, _, _) = platform();
+    let ((alice, alice_key), (bob, bob_key)) = (customer(&platform, "alice"), customer(&platform, "bob"));
+    let response = platform.request("POST", "/customer-groups", OPERATOR_KEY, Some(json!({ "name": "friends", "admins": [alice], "customers": [alice, bob] })));
+    let friends = json(&response)["id"].as_str().unwrap().to_string();
+    let webhooks = format!("/customer-groups/{}/webhooks", friends);
+
+    // the admin registers and lists the webhooks of the group
+    let body = json!({ "url": URL, "secret": "s3cret", "eventTypes": ["order.confirmed"] });
+    let response = platform.request("POST", &webhooks, &alice_key, Some(body.clone()));
+    assert_eq!(response.status_code, 200);
+    let webhook = json(&response)["id"].as_str().unwrap().to_string();
+    let response = platform.request("GET", &webhooks, &alice_key, None);
+    assert_eq!((response.status_code, json(&response)[0]["id"].as_str()), (200, Some(webhook.as_str())));
+
+    // a member who is not an admin can't
+    let response = platform.request("POST", &webhooks, &bob_key, Some(body));
+    assert_eq!(response.status_code, 403);
+    for (method, path) in [("GET", webhooks.clone()), ("GET", format!("{}/{}/deliveries", webhooks, webhook)), ("DELETE", format!("{}/{}", webhooks, webhook))] {
+        let response = platform.request(method, &path, &bob_key, None);
+        assert_eq!(response.status_code, 403, "{} {}", method, path);
+    }
+
+    let response = platform.request("DELETE", &format!("{}/{}", webhooks, webhook), &alice_key, None);
+    assert_eq!(response.status_code, 200);
+}